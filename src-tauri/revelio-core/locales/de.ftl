@@ -0,0 +1,88 @@
+## Errors
+
+error-cpu-info = Beim Abrufen der CPU-Informationen ist ein Fehler aufgetreten: { $reason }
+error-directx-info = Beim Abrufen der DirectX-Informationen ist ein Fehler aufgetreten: { $reason }
+error-metal-info = Beim Abrufen der Metal-Informationen ist ein Fehler aufgetreten: { $reason }
+error-vulkan-info = Beim Abrufen der Vulkan-Informationen ist ein Fehler aufgetreten: { $reason }
+error-opengl-info = Beim Abrufen der OpenGL-Informationen ist ein Fehler aufgetreten: { $reason }
+error-usb-info = Beim Abrufen der USB-Informationen ist ein Fehler aufgetreten: { $reason }
+error-unknown-section = Unbekannter Abschnitt: { $section }
+error-unsupported-section = Der Abschnitt { $section } wird auf dieser Plattform nicht unterstützt
+error-disabled-section = Der Abschnitt { $section } ist in der Konfiguration deaktiviert
+error-generic = Ein Fehler ist aufgetreten: { $reason }
+
+## Field warnings
+
+warning-env-not-set = { $variable } ist nicht gesetzt
+warning-env-not-set-either = Weder { $first } noch { $second } ist gesetzt
+warning-command-failed = { $command } konnte nicht ausgeführt werden: { $reason }
+warning-cpuinfo-missing = /proc/cpuinfo enthält kein { $key }
+warning-unrecognized-arch = Unbekannte Architektur: { $arch }
+warning-uname-failed = Die Maschinenarchitektur konnte nicht abgerufen werden
+warning-unsupported = Auf dieser Plattform nicht unterstützt
+warning-unrecognized-device-type = Unbekannter Gerätetyp: { $type }
+warning-malformed-string = Fehlerhafte Zeichenkette: { $reason }
+warning-layers-failed = Die Layer konnten nicht aufgelistet werden: { $reason }
+
+## Section titles
+
+section-platform = Plattform
+section-cpu = CPU
+section-vulkan = Vulkan
+section-opengl = OpenGL
+section-directx = DirectX
+section-metal = Metal
+section-displays = Bildschirme
+section-disks = Speicher
+section-network = Netzwerk
+section-usb = USB
+
+## Reports and tables
+
+report-title = Systembericht
+report-page-title = Revelio-Systembericht
+report-generated-at = Erstellt am
+report-app-version = Revelio-Version
+report-schema-version = Schemaversion
+report-unavailable = Nicht verfügbar
+report-timings = Laufzeiten
+fleet-hosts = Hosts ({ $count })
+history-metrics = Aufgezeichnete Metriken
+alert-matched = Trifft zu: { $query }
+alert-unmatched = Trifft nicht zu: { $query }
+inventory-changed = Hardware seit dem letzten Start geändert
+inventory-changed-sections = Geändert: { $sections }
+table-field = Feld
+table-value = Wert
+
+## Values
+
+value-yes = Ja
+value-no = Nein
+value-unknown = Unbekannt
+value-unknown-code = Unbekannt ({ $code })
+
+disk-kind-hdd = Festplatte (HDD)
+disk-kind-ssd = Solid-State-Drive (SSD)
+
+usb-speed-low = Low Speed
+usb-speed-full = Full Speed
+usb-speed-high = High Speed
+usb-speed-super = SuperSpeed
+usb-speed-super-plus = SuperSpeed+
+usb-speed-rate = { $speed } ({ $mbps } Mbit/s)
+
+vulkan-device-type-other = Andere
+vulkan-device-type-integrated-gpu = Integrierte GPU
+vulkan-device-type-discrete-gpu = Dedizierte GPU
+vulkan-device-type-virtual-gpu = Virtuelle GPU
+vulkan-device-type-cpu = CPU
+
+metal-location-built-in = Eingebaut
+metal-location-slot = Steckplatz
+metal-location-external = Extern
+metal-location-unspecified = Nicht angegeben
+metal-tier-none = Keine Stufe
+metal-tier-1 = Stufe 1
+metal-tier-2 = Stufe 2
+metal-size = { $product } (Breite: { $width } Höhe: { $height } Tiefe: { $depth })
@@ -0,0 +1,88 @@
+## Errors
+
+error-cpu-info = An error occurred while retrieving CPU information: { $reason }
+error-directx-info = An error occurred while retrieving DirectX information: { $reason }
+error-metal-info = An error occurred while retrieving Metal information: { $reason }
+error-vulkan-info = An error occurred while retrieving Vulkan information: { $reason }
+error-opengl-info = An error occurred while retrieving OpenGL information: { $reason }
+error-usb-info = An error occurred while retrieving USB information: { $reason }
+error-unknown-section = Unknown section: { $section }
+error-unsupported-section = The { $section } section is not supported on this platform
+error-disabled-section = The { $section } section is disabled in the configuration
+error-generic = An error occurred: { $reason }
+
+## Field warnings
+
+warning-env-not-set = { $variable } is not set
+warning-env-not-set-either = Neither { $first } nor { $second } is set
+warning-command-failed = Failed to run { $command }: { $reason }
+warning-cpuinfo-missing = /proc/cpuinfo has no { $key }
+warning-unrecognized-arch = Unrecognized architecture: { $arch }
+warning-uname-failed = Failed to retrieve the machine architecture
+warning-unsupported = Not supported on this platform
+warning-unrecognized-device-type = Unrecognized device type: { $type }
+warning-malformed-string = Malformed string: { $reason }
+warning-layers-failed = Failed to list the layers: { $reason }
+
+## Section titles
+
+section-platform = Platform
+section-cpu = CPU
+section-vulkan = Vulkan
+section-opengl = OpenGL
+section-directx = DirectX
+section-metal = Metal
+section-displays = Displays
+section-disks = Storage
+section-network = Network
+section-usb = USB
+
+## Reports and tables
+
+report-title = System report
+report-page-title = Revelio system report
+report-generated-at = Generated at
+report-app-version = Revelio version
+report-schema-version = Schema version
+report-unavailable = Unavailable
+report-timings = Timings
+fleet-hosts = Hosts ({ $count })
+history-metrics = Recorded metrics
+alert-matched = Matches: { $query }
+alert-unmatched = Does not match: { $query }
+inventory-changed = Hardware changed since the last run
+inventory-changed-sections = Changed: { $sections }
+table-field = Field
+table-value = Value
+
+## Values
+
+value-yes = Yes
+value-no = No
+value-unknown = Unknown
+value-unknown-code = Unknown ({ $code })
+
+disk-kind-hdd = Hard Disk Drive (HDD)
+disk-kind-ssd = Solid State Drive (SSD)
+
+usb-speed-low = Low speed
+usb-speed-full = Full speed
+usb-speed-high = High speed
+usb-speed-super = SuperSpeed
+usb-speed-super-plus = SuperSpeed+
+usb-speed-rate = { $speed } ({ $mbps } Mbit/s)
+
+vulkan-device-type-other = Other
+vulkan-device-type-integrated-gpu = Integrated GPU
+vulkan-device-type-discrete-gpu = Discrete GPU
+vulkan-device-type-virtual-gpu = Virtual GPU
+vulkan-device-type-cpu = CPU
+
+metal-location-built-in = Built-in
+metal-location-slot = Slot
+metal-location-external = External
+metal-location-unspecified = Unspecified
+metal-tier-none = Tier None
+metal-tier-1 = Tier 1
+metal-tier-2 = Tier 2
+metal-size = { $product } (width: { $width } height: { $height } depth: { $depth })
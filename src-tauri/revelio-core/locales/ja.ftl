@@ -0,0 +1,88 @@
+## Errors
+
+error-cpu-info = CPU 情報の取得中にエラーが発生しました: { $reason }
+error-directx-info = DirectX 情報の取得中にエラーが発生しました: { $reason }
+error-metal-info = Metal 情報の取得中にエラーが発生しました: { $reason }
+error-vulkan-info = Vulkan 情報の取得中にエラーが発生しました: { $reason }
+error-opengl-info = OpenGL 情報の取得中にエラーが発生しました: { $reason }
+error-usb-info = USB 情報の取得中にエラーが発生しました: { $reason }
+error-unknown-section = 不明なセクション: { $section }
+error-unsupported-section = { $section } セクションはこのプラットフォームではサポートされていません
+error-disabled-section = { $section } セクションは設定で無効になっています
+error-generic = エラーが発生しました: { $reason }
+
+## Field warnings
+
+warning-env-not-set = { $variable } が設定されていません
+warning-env-not-set-either = { $first } と { $second } のどちらも設定されていません
+warning-command-failed = { $command } の実行に失敗しました: { $reason }
+warning-cpuinfo-missing = /proc/cpuinfo に { $key } がありません
+warning-unrecognized-arch = 認識できないアーキテクチャ: { $arch }
+warning-uname-failed = マシンアーキテクチャを取得できませんでした
+warning-unsupported = このプラットフォームではサポートされていません
+warning-unrecognized-device-type = 認識できないデバイスタイプ: { $type }
+warning-malformed-string = 不正な文字列: { $reason }
+warning-layers-failed = レイヤーを一覧表示できませんでした: { $reason }
+
+## Section titles
+
+section-platform = プラットフォーム
+section-cpu = CPU
+section-vulkan = Vulkan
+section-opengl = OpenGL
+section-directx = DirectX
+section-metal = Metal
+section-displays = ディスプレイ
+section-disks = ストレージ
+section-network = ネットワーク
+section-usb = USB
+
+## Reports and tables
+
+report-title = システムレポート
+report-page-title = Revelio システムレポート
+report-generated-at = 作成日時
+report-app-version = Revelio バージョン
+report-schema-version = スキーマバージョン
+report-unavailable = 利用不可
+report-timings = 所要時間
+fleet-hosts = ホスト ({ $count })
+history-metrics = 記録されるメトリクス
+alert-matched = 条件に一致: { $query }
+alert-unmatched = 条件に不一致: { $query }
+inventory-changed = 前回の起動以降にハードウェアが変更されました
+inventory-changed-sections = 変更: { $sections }
+table-field = 項目
+table-value = 値
+
+## Values
+
+value-yes = はい
+value-no = いいえ
+value-unknown = 不明
+value-unknown-code = 不明 ({ $code })
+
+disk-kind-hdd = ハードディスクドライブ (HDD)
+disk-kind-ssd = ソリッドステートドライブ (SSD)
+
+usb-speed-low = ロースピード
+usb-speed-full = フルスピード
+usb-speed-high = ハイスピード
+usb-speed-super = SuperSpeed
+usb-speed-super-plus = SuperSpeed+
+usb-speed-rate = { $speed } ({ $mbps } Mbit/s)
+
+vulkan-device-type-other = その他
+vulkan-device-type-integrated-gpu = 内蔵 GPU
+vulkan-device-type-discrete-gpu = ディスクリート GPU
+vulkan-device-type-virtual-gpu = 仮想 GPU
+vulkan-device-type-cpu = CPU
+
+metal-location-built-in = 内蔵
+metal-location-slot = スロット
+metal-location-external = 外部
+metal-location-unspecified = 指定なし
+metal-tier-none = ティアなし
+metal-tier-1 = ティア 1
+metal-tier-2 = ティア 2
+metal-size = { $product } (幅: { $width } 高さ: { $height } 奥行き: { $depth })
@@ -0,0 +1,153 @@
+use std::sync::{LazyLock, RwLock};
+
+use fluent_bundle::concurrent::FluentBundle;
+use fluent_bundle::{FluentArgs, FluentResource};
+use unic_langid::LanguageIdentifier;
+
+pub use fluent_bundle::FluentValue;
+
+/// The locale used when no other locale is requested or supported, and for messages missing from
+/// another locale's catalog.
+pub const DEFAULT_LOCALE: &str = "en";
+
+/// The bundled message catalogs, keyed by locale. Every catalog should define the messages of the
+/// default locale's catalog.
+const CATALOGS: &[(&str, &str)] = &[
+    ("en", include_str!("../locales/en.ftl")),
+    ("de", include_str!("../locales/de.ftl")),
+    ("ja", include_str!("../locales/ja.ftl")),
+];
+
+/// The parsed message catalogs, in the same order as [`CATALOGS`].
+static BUNDLES: LazyLock<Vec<FluentBundle<FluentResource>>> = LazyLock::new(|| {
+    CATALOGS
+        .iter()
+        .map(|(locale, source)| {
+            let id: LanguageIdentifier = locale.parse().expect("invalid bundled locale");
+            let resource = FluentResource::try_new(source.to_string())
+                .unwrap_or_else(|_| panic!("invalid {locale} message catalog"));
+
+            let mut bundle = FluentBundle::new_concurrent(vec![id]);
+            // Unicode isolation marks around arguments show up as garbage in terminals.
+            bundle.set_use_isolating(false);
+            bundle
+                .add_resource(resource)
+                .unwrap_or_else(|_| panic!("duplicate messages in the {locale} catalog"));
+            bundle
+        })
+        .collect()
+});
+
+/// The locale messages are currently resolved in, initially the system's.
+static CURRENT: LazyLock<RwLock<&'static str>> = LazyLock::new(|| {
+    RwLock::new(sys_locale::get_locale().map_or(DEFAULT_LOCALE, |locale| negotiate(&locale)))
+});
+
+/// Returns the locales a catalog is bundled for.
+pub fn locales() -> Vec<&'static str> {
+    CATALOGS.iter().map(|(locale, _)| *locale).collect()
+}
+
+/// Returns the bundled locale that best matches a requested one, falling back to
+/// [`DEFAULT_LOCALE`].
+///
+/// # Arguments
+///
+/// * `requested` - A BCP 47 language tag or a POSIX locale name (Eg: `de-AT`, `ja_JP.UTF-8`).
+pub fn negotiate(requested: &str) -> &'static str {
+    let language = requested
+        .split(['-', '_', '.', '@'])
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+
+    CATALOGS
+        .iter()
+        .map(|(locale, _)| *locale)
+        .find(|locale| *locale == language)
+        .unwrap_or(DEFAULT_LOCALE)
+}
+
+/// Returns the locale messages are currently resolved in.
+pub fn locale() -> &'static str {
+    *CURRENT.read().unwrap_or_else(|e| e.into_inner())
+}
+
+/// Sets the locale messages are resolved in. Returns the bundled locale that was picked (see
+/// [`negotiate`]).
+///
+/// # Arguments
+///
+/// * `requested` - A BCP 47 language tag or a POSIX locale name (Eg: `de-AT`, `ja_JP.UTF-8`).
+pub fn set_locale(requested: &str) -> &'static str {
+    let locale = negotiate(requested);
+    *CURRENT.write().unwrap_or_else(|e| e.into_inner()) = locale;
+
+    locale
+}
+
+/// Resolves a message in a given locale, falling back to [`DEFAULT_LOCALE`] and then to the key
+/// itself if the message is missing.
+///
+/// # Arguments
+///
+/// * `locale` - A bundled locale (see [`locales`]).
+/// * `key` - The message key (Eg: `value-unknown`).
+/// * `args` - The (name, value) pairs of the message's arguments.
+pub fn translate(locale: &str, key: &str, args: &[(&str, FluentValue)]) -> String {
+    let bundles = [locale, DEFAULT_LOCALE]
+        .into_iter()
+        .filter_map(|l| CATALOGS.iter().position(|(locale, _)| *locale == l))
+        .map(|i| &BUNDLES[i]);
+
+    for bundle in bundles {
+        let Some(pattern) = bundle.get_message(key).and_then(|m| m.value()) else {
+            continue;
+        };
+
+        let mut fluent_args = FluentArgs::new();
+        for (name, value) in args {
+            fluent_args.set(*name, value.clone());
+        }
+
+        let mut errors = Vec::new();
+        return bundle
+            .format_pattern(pattern, Some(&fluent_args), &mut errors)
+            .into_owned();
+    }
+
+    key.to_string()
+}
+
+/// Resolves a message without arguments in the current locale.
+///
+/// # Arguments
+///
+/// * `key` - The message key (Eg: `value-unknown`).
+pub fn tr(key: &str) -> String {
+    translate(locale(), key, &[])
+}
+
+/// Resolves a message in the current locale.
+///
+/// # Arguments
+///
+/// * `key` - The message key (Eg: `usb-speed-rate`).
+/// * `args` - The (name, value) pairs of the message's arguments.
+pub fn tr_args(key: &str, args: &[(&str, FluentValue)]) -> String {
+    translate(locale(), key, args)
+}
+
+/// Returns the title of a section in the current locale, falling back to its name for sections
+/// without a message (Eg: in reports generated by a newer version).
+///
+/// # Arguments
+///
+/// * `section` - The section name.
+pub fn section_title(section: &str) -> String {
+    let key = format!("section-{section}");
+    match tr(&key) {
+        title if title == key => section.to_string(),
+        title => title,
+    }
+}
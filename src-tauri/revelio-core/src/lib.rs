@@ -0,0 +1,25 @@
+//! The system information collectors of Revelio, usable without the app.
+//!
+//! Each collector is behind a cargo feature named after its module in [`utils`] (Eg: `cpu`,
+//! `usb`, `vulkan`), all of which are enabled by default. Tools that only need some sections can
+//! disable the default features to leave out the dependencies of the others (Eg: the windowing
+//! stack used by `opengl` and `displays`):
+//!
+//! ```toml
+//! revelio-core = { path = "...", default-features = false, features = ["cpu", "usb"] }
+//! ```
+//!
+//! The `monitor` feature, also enabled by default, samples the values that change over time (Eg:
+//! CPU usage, network traffic) with [`monitor::Monitor`].
+//!
+//! The `specta` and `schema` features, which are off by default, derive `specta::Type` and
+//! `schemars::JsonSchema` for the information types, for generating TypeScript bindings and JSON
+//! Schemas of them.
+
+pub mod i18n;
+#[cfg(feature = "monitor")]
+pub mod monitor;
+#[cfg(target_os = "linux")]
+pub mod sysroot;
+pub mod types;
+pub mod utils;
@@ -0,0 +1,304 @@
+use std::collections::HashMap;
+#[cfg(target_os = "linux")]
+use std::fs;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::Serialize;
+use sysinfo::{Components, Disks, Networks, System, MINIMUM_CPU_UPDATE_INTERVAL};
+
+/// How often the system is sampled unless another interval is given.
+pub const DEFAULT_INTERVAL: Duration = Duration::from_secs(1);
+
+/// The shortest interval the system can be sampled at, below which CPU usage can't be measured.
+pub const MINIMUM_INTERVAL: Duration = MINIMUM_CPU_UPDATE_INTERVAL;
+
+/// The usage of a logical CPU core.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct CoreSample {
+    /// The name of the core (Eg: `cpu0`).
+    pub name: String,
+    /// The usage of the core since the previous sample, in percent.
+    pub usage: f32,
+    /// The current frequency of the core, in hertz.
+    pub frequency: u64,
+}
+
+/// The usage of the memory and swap space.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct MemorySample {
+    /// The total amount of memory, in bytes.
+    pub total_memory: u64,
+    /// The amount of memory in use, in bytes.
+    pub used_memory: u64,
+    /// The amount of memory that can be allocated without swapping, in bytes.
+    pub available_memory: u64,
+    /// The total amount of swap space, in bytes.
+    pub total_swap: u64,
+    /// The amount of swap space in use, in bytes.
+    pub used_swap: u64,
+}
+
+/// The traffic of a network interface.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct InterfaceSample {
+    /// The name of the interface.
+    pub itf_name: String,
+    /// The number of bytes received on the interface since it was brought up.
+    pub received_bytes: u64,
+    /// The number of bytes transmitted on the interface since it was brought up.
+    pub transmitted_bytes: u64,
+    /// The number of bytes received per second since the previous sample.
+    pub received_rate: f64,
+    /// The number of bytes transmitted per second since the previous sample.
+    pub transmitted_rate: f64,
+}
+
+/// The space and I/O of a disk.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct DiskSample {
+    /// The name of the disk (Eg: `/dev/nvme0n1p2`).
+    pub name: String,
+    /// The mount point of the disk.
+    pub mount_point: String,
+    /// The total space of the disk, in bytes.
+    pub total_space: u64,
+    /// The available space of the disk, in bytes.
+    pub available_space: u64,
+    /// The number of bytes read from the disk since boot (Linux only).
+    pub read_bytes: Option<u64>,
+    /// The number of bytes written to the disk since boot (Linux only).
+    pub written_bytes: Option<u64>,
+    /// The number of bytes read per second since the previous sample (Linux only).
+    pub read_rate: Option<f64>,
+    /// The number of bytes written per second since the previous sample (Linux only).
+    pub write_rate: Option<f64>,
+}
+
+/// The temperature of a hardware sensor.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct TemperatureSample {
+    /// The label of the sensor (Eg: `coretemp Package id 0`, `nvme Composite`).
+    pub label: String,
+    /// The temperature, in degrees Celsius.
+    pub temperature: f32,
+    /// The temperature the hardware considers critical, in degrees Celsius, if known.
+    pub critical: Option<f32>,
+}
+
+/// The values of the system that change over time, sampled at a single point in time.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(rename_all = "camelCase")]
+pub struct MetricsSample {
+    /// The time the sample was taken, in milliseconds since the Unix epoch.
+    pub sampled_at: u64,
+    /// The usage of each logical CPU core.
+    pub cores: Vec<CoreSample>,
+    /// The usage of the memory and swap space.
+    pub memory: MemorySample,
+    /// The traffic of each network interface.
+    pub interfaces: Vec<InterfaceSample>,
+    /// The space and I/O of each disk.
+    pub disks: Vec<DiskSample>,
+    /// The temperature of each hardware sensor that can be read.
+    pub temperatures: Vec<TemperatureSample>,
+}
+
+/// Samples the changing values of the system, refreshing the same `sysinfo` structures every time
+/// instead of listing the CPU cores, network interfaces, disks and sensors again.
+///
+/// Rates are computed over the time elapsed since the previous sample, so the first sample
+/// reports rates since the monitor was created. Interfaces, disks and sensors that appear after
+/// the monitor was created aren't sampled.
+pub struct Monitor {
+    system: System,
+    networks: Networks,
+    disks: Disks,
+    components: Components,
+    /// The instant of the previous sample.
+    sampled: Instant,
+    /// The bytes read from and written to each disk at the previous sample, by disk name.
+    disk_io: HashMap<String, (u64, u64)>,
+}
+
+impl Default for Monitor {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl Monitor {
+    /// Creates a monitor, listing the CPU cores, network interfaces, disks and hardware sensors of
+    /// the system.
+    pub fn new() -> Self {
+        let mut system = System::new();
+        // CPU usage is measured between two refreshes, so the first sample needs a baseline.
+        system.refresh_cpu_all();
+
+        let disks = Disks::new_with_refreshed_list();
+        let disk_io = disks
+            .iter()
+            .filter_map(|disk| {
+                let name = disk.name().to_string_lossy().into_owned();
+                Some((name.clone(), disk_io(&name)?))
+            })
+            .collect();
+
+        Self {
+            system,
+            networks: Networks::new_with_refreshed_list(),
+            disks,
+            components: Components::new_with_refreshed_list(),
+            sampled: Instant::now(),
+            disk_io,
+        }
+    }
+
+    /// Refreshes the changing values of the system and returns them.
+    pub fn sample(&mut self) -> MetricsSample {
+        self.system.refresh_cpu_all();
+        self.system.refresh_memory();
+        self.networks.refresh();
+        self.disks.refresh();
+        self.components.refresh();
+
+        let elapsed = self.sampled.elapsed().as_secs_f64();
+        self.sampled = Instant::now();
+        let rate = |bytes: u64| {
+            if elapsed > 0.0 {
+                bytes as f64 / elapsed
+            } else {
+                0.0
+            }
+        };
+
+        let cores = self
+            .system
+            .cpus()
+            .iter()
+            .map(|cpu| CoreSample {
+                name: cpu.name().to_string(),
+                usage: cpu.cpu_usage(),
+                // `sysinfo` reports frequencies in megahertz.
+                frequency: cpu.frequency() * 1_000_000,
+            })
+            .collect();
+
+        let memory = MemorySample {
+            total_memory: self.system.total_memory(),
+            used_memory: self.system.used_memory(),
+            available_memory: self.system.available_memory(),
+            total_swap: self.system.total_swap(),
+            used_swap: self.system.used_swap(),
+        };
+
+        let mut interfaces: Vec<_> = self
+            .networks
+            .iter()
+            .map(|(itf_name, network)| InterfaceSample {
+                itf_name: itf_name.clone(),
+                received_bytes: network.total_received(),
+                transmitted_bytes: network.total_transmitted(),
+                received_rate: rate(network.received()),
+                transmitted_rate: rate(network.transmitted()),
+            })
+            .collect();
+        interfaces.sort_by(|a, b| a.itf_name.cmp(&b.itf_name));
+
+        let mut disks = Vec::with_capacity(self.disks.len());
+        for disk in &self.disks {
+            let name = disk.name().to_string_lossy().into_owned();
+            let io = disk_io(&name);
+            let previous = io.and_then(|io| self.disk_io.insert(name.clone(), io));
+            let rates = io
+                .zip(previous)
+                .map(|((read, written), (last_read, last_written))| {
+                    (
+                        rate(read.saturating_sub(last_read)),
+                        rate(written.saturating_sub(last_written)),
+                    )
+                });
+
+            disks.push(DiskSample {
+                name,
+                mount_point: disk.mount_point().to_string_lossy().into_owned(),
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+                read_bytes: io.map(|(read, _)| read),
+                written_bytes: io.map(|(_, written)| written),
+                read_rate: rates.map(|(read, _)| read),
+                write_rate: rates.map(|(_, written)| written),
+            });
+        }
+
+        // Sensors that can't be read report NaN.
+        let temperatures = self
+            .components
+            .iter()
+            .filter(|component| component.temperature().is_finite())
+            .map(|component| TemperatureSample {
+                label: component.label().to_string(),
+                temperature: component.temperature(),
+                critical: component.critical().filter(|critical| critical.is_finite()),
+            })
+            .collect();
+
+        MetricsSample {
+            sampled_at: SystemTime::now()
+                .duration_since(UNIX_EPOCH)
+                .map(|d| d.as_millis() as u64)
+                .unwrap_or_default(),
+            cores,
+            memory,
+            interfaces,
+            disks,
+            temperatures,
+        }
+    }
+}
+
+/// Returns the number of bytes read from and written to a disk since boot, from its statistics in
+/// `/sys/class/block`.
+///
+/// # Arguments
+///
+/// * `name` - The name of the disk (Eg: `/dev/nvme0n1p2`, `/dev/mapper/fedora-root`).
+#[cfg(target_os = "linux")]
+fn disk_io(name: &str) -> Option<(u64, u64)> {
+    // Device-mapper devices are links to their kernel name (Eg: `/dev/dm-0`).
+    let device = fs::canonicalize(name).ok()?;
+    let stat = fs::read_to_string(format!(
+        "/sys/class/block/{}/stat",
+        device.file_name()?.to_str()?
+    ))
+    .ok()?;
+
+    // The sectors read and written are the 3rd and 7th fields, and are always 512 bytes.
+    let fields: Vec<u64> = stat
+        .split_whitespace()
+        .map(|field| field.parse().ok())
+        .collect::<Option<_>>()?;
+
+    Some((*fields.get(2)? * 512, *fields.get(6)? * 512))
+}
+
+/// Returns the number of bytes read from and written to a disk since boot, which is only known on
+/// Linux.
+///
+/// # Arguments
+///
+/// * `name` - The name of the disk.
+#[cfg(not(target_os = "linux"))]
+fn disk_io(_name: &str) -> Option<(u64, u64)> {
+    None
+}
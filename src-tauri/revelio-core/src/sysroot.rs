@@ -0,0 +1,174 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::str::FromStr;
+
+/// The environment variable that points the Linux collectors at a recorded file system tree.
+pub const SYSROOT_ENV: &str = "REVELIO_SYSROOT";
+
+/// A file system root that the Linux collectors read `/etc`, `/proc` and `/sys` from.
+///
+/// The live system is read through `/`, while tests and offline analysis point it at a recorded
+/// directory tree (a "fixture machine") instead.
+#[derive(Debug, Clone)]
+pub struct SysRoot {
+    root: PathBuf,
+}
+
+impl Default for SysRoot {
+    fn default() -> Self {
+        Self::new("/")
+    }
+}
+
+impl SysRoot {
+    /// Creates a root at the given directory.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The directory that stands in for `/`.
+    pub fn new<P>(root: P) -> Self
+    where
+        P: Into<PathBuf>,
+    {
+        Self { root: root.into() }
+    }
+
+    /// Returns the root set through the `REVELIO_SYSROOT` environment variable, if any.
+    pub fn from_env() -> Option<Self> {
+        env::var_os(SYSROOT_ENV)
+            .filter(|root| !root.is_empty())
+            .map(Self::new)
+    }
+
+    /// Returns the root set through the `REVELIO_SYSROOT` environment variable, or the live
+    /// system's if it isn't set. The Linux collectors read everything through this root.
+    pub fn current() -> Self {
+        Self::from_env().unwrap_or_default()
+    }
+
+    /// Checks whether the root is the live system's (`/`) rather than a recorded tree.
+    pub fn is_live(&self) -> bool {
+        self.root == Path::new("/")
+    }
+
+    /// Resolves an absolute path (Eg: `/proc/cpuinfo`) against the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path.
+    pub fn path<P>(&self, path: P) -> PathBuf
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        self.root.join(path.strip_prefix("/").unwrap_or(path))
+    }
+
+    /// Reads a file, returning its contents with surrounding whitespace removed, or `None` if it
+    /// cannot be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path of the file.
+    pub fn read<P>(&self, path: P) -> Option<String>
+    where
+        P: AsRef<Path>,
+    {
+        fs::read_to_string(self.path(path))
+            .ok()
+            .map(|contents| contents.trim().to_string())
+    }
+
+    /// Reads and parses a single-value file (Eg: a sysfs attribute).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path of the file.
+    pub fn read_parsed<T, P>(&self, path: P) -> Option<T>
+    where
+        T: FromStr,
+        P: AsRef<Path>,
+    {
+        self.read(path)?.parse().ok()
+    }
+
+    /// Reads a file containing a hexadecimal number (Eg: a USB `idVendor` attribute).
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path of the file.
+    pub fn read_hex<P>(&self, path: P) -> Option<u32>
+    where
+        P: AsRef<Path>,
+    {
+        let contents = self.read(path)?;
+        u32::from_str_radix(contents.trim_start_matches("0x"), 16).ok()
+    }
+
+    /// Lists the entries of a directory, sorted by name. Returns an empty list if the directory
+    /// cannot be read.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path of the directory.
+    pub fn list<P>(&self, path: P) -> Vec<(String, PathBuf)>
+    where
+        P: AsRef<Path>,
+    {
+        let path = path.as_ref();
+        let Ok(entries) = fs::read_dir(self.path(path)) else {
+            return Vec::new();
+        };
+
+        let mut entries: Vec<(String, PathBuf)> = entries
+            .filter_map(|entry| entry.ok())
+            .filter_map(|entry| entry.file_name().into_string().ok())
+            .map(|name| {
+                let child = path.join(&name);
+                (name, child)
+            })
+            .collect();
+        entries.sort();
+
+        entries
+    }
+
+    /// Checks whether a path exists under the root.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The absolute path.
+    pub fn exists<P>(&self, path: P) -> bool
+    where
+        P: AsRef<Path>,
+    {
+        self.path(path).exists()
+    }
+
+    /// Returns the value of an environment variable of the recorded session, as found in
+    /// `/proc/self/environ`.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The name of the variable.
+    pub fn var(&self, name: &str) -> Option<String> {
+        let environ = fs::read(self.path("/proc/self/environ")).ok()?;
+
+        environ
+            .split(|b| *b == 0)
+            .filter_map(|entry| std::str::from_utf8(entry).ok())
+            .find_map(|entry| {
+                entry
+                    .strip_prefix(name)
+                    .and_then(|rest| rest.strip_prefix('='))
+                    .map(str::to_string)
+            })
+    }
+
+    /// Returns the machine architecture of the recorded system (Eg: `x86_64`).
+    pub fn arch(&self) -> String {
+        self.read("/proc/sys/kernel/arch")
+            .unwrap_or_else(|| env::consts::ARCH.into())
+    }
+}
@@ -0,0 +1,245 @@
+use phf::phf_map;
+use serde::{Serialize, Serializer};
+use std::error::Error;
+use std::fmt::{Display, Formatter};
+
+use crate::i18n;
+
+#[doc = "<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceType.html>"]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum VulkanDeviceType {
+    Other,
+    IntegratedGpu,
+    DiscreteGpu,
+    VirtualGpu,
+    Cpu,
+}
+
+#[doc = "<https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkMemoryHeapFlagBits.html>"]
+#[derive(Serialize)]
+pub enum VulkanMemoryHeapFlags {
+    #[serde(rename = "VK_MEMORY_HEAP_DEVICE_LOCAL_BIT")]
+    DeviceLocal,
+    #[serde(rename = "VK_MEMORY_HEAP_MULTI_INSTANCE_BIT")]
+    MultiInstance,
+}
+
+/// Serializes a value into a JSON string without including double quotes in the result.
+///
+/// # Arguments
+///
+/// * `value` - The value to serialize.
+#[inline]
+fn serialize_without_quotes<S>(value: &S) -> String
+where
+    S: Serialize,
+{
+    serde_json::to_string(value)
+        .unwrap()
+        .to_string()
+        .replace("\"", "")
+}
+
+impl Display for VulkanMemoryHeapFlags {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        write!(f, "{}", serialize_without_quotes(&self))
+    }
+}
+
+/// Lookup map for raw Vulkan device type constant.
+pub static VK_DEVICE_TYPE_MAP: phf::Map<i32, VulkanDeviceType> = phf_map! {
+    0_i32 => VulkanDeviceType::Other,
+    1_i32 => VulkanDeviceType::IntegratedGpu,
+    2_i32 => VulkanDeviceType::DiscreteGpu,
+    3_i32 => VulkanDeviceType::VirtualGpu,
+    4_i32 => VulkanDeviceType::Cpu,
+};
+
+/// Lookup map for raw Vulkan device memory heap flags constant.
+pub static VK_MEMORY_HEAP_FLAGS_MAP: phf::Map<u32, VulkanMemoryHeapFlags> = phf_map! {
+    0_u32 => VulkanMemoryHeapFlags::DeviceLocal,
+    1_u32 => VulkanMemoryHeapFlags::MultiInstance,
+};
+
+/// Describes a field of a section that could not be gathered, while the rest of the section was.
+///
+/// The field itself keeps a placeholder value (Eg: `Unknown`) or is `null`, so that consumers
+/// which don't look at the warnings still get a complete section.
+#[derive(Debug, Clone, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct FieldWarning {
+    /// The path of the field within the section, using the serialized field names and array
+    /// indices (Eg: `graphicsPlatform`, `devices[0].layers[2].layerName`).
+    pub field: String,
+    /// The message key of the reason, which doesn't depend on the locale (Eg:
+    /// `warning-env-not-set`).
+    pub key: String,
+    /// Why the field is unavailable, in the locale that was current when it was gathered.
+    pub reason: String,
+}
+
+impl FieldWarning {
+    /// Creates a warning for a field, resolving its reason in the current locale (see
+    /// [`i18n::locale`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `field` - The path of the field within the section.
+    /// * `key` - The message key of the reason (Eg: `warning-env-not-set`).
+    /// * `args` - The (name, value) pairs of the message's arguments.
+    pub fn new<F>(field: F, key: &str, args: &[(&str, i18n::FluentValue)]) -> Self
+    where
+        F: Into<String>,
+    {
+        Self {
+            field: field.into(),
+            key: key.into(),
+            reason: i18n::tr_args(key, args),
+        }
+    }
+}
+
+/// Custom error type for errors encountered while gathering system information.
+///
+/// Collectors only return an error when the section cannot be gathered at all; fields that are
+/// unavailable on their own are reported with a [`FieldWarning`] instead.
+#[non_exhaustive]
+#[derive(Debug, thiserror::Error)]
+pub enum CoreError {
+    #[error("An error occurred while retrieving CPU information: {0}")]
+    CPUInfoError(Box<str>),
+
+    /// DirectX info retrieval error (Windows only).
+    #[error("An error occurred while retrieving DirectX information: {0}")]
+    DirectXInfoError(Box<str>),
+
+    /// Metal info retrieval error (macOS only).
+    #[error("An error occurred while retrieving Metal information: {0}")]
+    MetalInfoError(Box<str>),
+
+    /// Vulkan info retrieval error.
+    #[error("An error occurred while retrieving Vulkan information: {0}")]
+    VulkanInfoError(Box<str>),
+
+    /// OpenGL info retrieval error.
+    #[error("An error occurred while retrieving OpenGL information: {0}")]
+    OpenGLInfoError(Box<str>),
+
+    /// USB info retrieval error.
+    #[error("An error occurred while retrieving USB information: {0}")]
+    USBInfoError(Box<str>),
+
+    /// The requested section is not known to the collector registry.
+    #[error("Unknown section: {0}")]
+    UnknownSection(Box<str>),
+
+    /// The requested section cannot be gathered on the current platform.
+    #[error("The {0} section is not supported on this platform")]
+    UnsupportedSection(Box<str>),
+
+    /// The requested section is disabled in the configuration.
+    #[error("The {0} section is disabled in the configuration")]
+    DisabledSection(Box<str>),
+
+    /// Generic errors.
+    #[error("An error occurred: {0}")]
+    Error(
+        #[from]
+        #[source]
+        Box<dyn Error + Send + Sync>,
+    ),
+}
+
+impl CoreError {
+    /// Returns the description of the error without the context added by its kind (Eg: the
+    /// reason a section could not be gathered).
+    pub fn reason(&self) -> String {
+        match self {
+            Self::CPUInfoError(reason)
+            | Self::DirectXInfoError(reason)
+            | Self::MetalInfoError(reason)
+            | Self::VulkanInfoError(reason)
+            | Self::OpenGLInfoError(reason)
+            | Self::USBInfoError(reason) => reason.to_string(),
+            Self::UnknownSection(_) | Self::UnsupportedSection(_) | Self::DisabledSection(_) => {
+                self.to_string()
+            }
+            Self::Error(e) => e.to_string(),
+        }
+    }
+
+    /// Returns the error message in the current locale (see [`i18n::locale`]). The `Display`
+    /// implementation is always in English, for logs.
+    pub fn localized(&self) -> String {
+        let (key, name) = match self {
+            Self::CPUInfoError(_) => ("error-cpu-info", "reason"),
+            Self::DirectXInfoError(_) => ("error-directx-info", "reason"),
+            Self::MetalInfoError(_) => ("error-metal-info", "reason"),
+            Self::VulkanInfoError(_) => ("error-vulkan-info", "reason"),
+            Self::OpenGLInfoError(_) => ("error-opengl-info", "reason"),
+            Self::USBInfoError(_) => ("error-usb-info", "reason"),
+            Self::UnknownSection(_) => ("error-unknown-section", "section"),
+            Self::UnsupportedSection(_) => ("error-unsupported-section", "section"),
+            Self::DisabledSection(_) => ("error-disabled-section", "section"),
+            Self::Error(_) => ("error-generic", "reason"),
+        };
+        let value = match self {
+            Self::UnknownSection(section)
+            | Self::UnsupportedSection(section)
+            | Self::DisabledSection(section) => section.to_string(),
+            _ => self.reason(),
+        };
+
+        i18n::tr_args(key, &[(name, value.into())])
+    }
+}
+
+/// An error from the core library, as seen by the frontend.
+#[derive(Debug, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[serde(tag = "kind", content = "message")]
+#[serde(rename_all = "camelCase")]
+#[cfg_attr(feature = "specta", specta(remote = CoreError, rename = "CoreError"))]
+enum CoreErrorKind {
+    // Spelled out so that specta's renaming of acronyms matches serde's.
+    #[serde(rename = "cPUInfoError")]
+    CPUInfoError(String),
+    DirectXInfoError(String),
+    MetalInfoError(String),
+    VulkanInfoError(String),
+    OpenGLInfoError(String),
+    #[serde(rename = "uSBInfoError")]
+    USBInfoError(String),
+    UnknownSection(String),
+    UnsupportedSection(String),
+    DisabledSection(String),
+    Error(String),
+}
+
+impl Serialize for CoreError {
+    fn serialize<S>(&self, serializer: S) -> Result<S::Ok, S::Error>
+    where
+        S: Serializer,
+    {
+        let err_msg = self.localized();
+        let err_kind = match self {
+            Self::CPUInfoError(_) => CoreErrorKind::CPUInfoError(err_msg),
+            Self::DirectXInfoError(_) => CoreErrorKind::DirectXInfoError(err_msg),
+            Self::MetalInfoError(_) => CoreErrorKind::MetalInfoError(err_msg),
+            Self::VulkanInfoError(_) => CoreErrorKind::VulkanInfoError(err_msg),
+            Self::OpenGLInfoError(_) => CoreErrorKind::OpenGLInfoError(err_msg),
+            Self::USBInfoError(_) => CoreErrorKind::USBInfoError(err_msg),
+            Self::UnknownSection(_) => CoreErrorKind::UnknownSection(err_msg),
+            Self::UnsupportedSection(_) => CoreErrorKind::UnsupportedSection(err_msg),
+            Self::DisabledSection(_) => CoreErrorKind::DisabledSection(err_msg),
+            Self::Error(_) => CoreErrorKind::Error(err_msg),
+        };
+
+        err_kind.serialize(serializer)
+    }
+}
@@ -0,0 +1,471 @@
+#[cfg(not(target_os = "linux"))]
+use cpuinfo_rs::CpuInfo as Info;
+use serde::Serialize;
+#[cfg(target_os = "linux")]
+use std::collections::HashMap;
+
+#[cfg(target_os = "linux")]
+use crate::sysroot::SysRoot;
+use crate::types::{CoreError, FieldWarning};
+#[cfg(target_os = "macos")]
+use libc::{uname, utsname};
+
+/// Cache flag set for caches that hold both instructions and data.
+#[cfg(target_os = "linux")]
+const CACHE_UNIFIED: u32 = 0x1;
+
+/// Contains information of a single CPU cache.
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Cache {
+    /// The cache size in bytes.
+    pub size: u32,
+    /// The number of ways of associativity.
+    pub associativity: u32,
+    /// The number of sets.
+    pub sets: u32,
+    /// The number of partitions.
+    pub partitions: u32,
+    /// The line size in bytes.
+    pub line_size: u32,
+    /// The cache flags (Eg: unified, inclusive).
+    pub flags: u32,
+}
+
+/// Contains information of the CPU's cache hierarchy.
+#[derive(Debug, Serialize, Clone, Default)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub struct CacheInfo {
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1i: Option<Cache>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l1d: Option<Cache>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l2: Option<Cache>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l3: Option<Cache>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub l4: Option<Cache>,
+}
+
+#[cfg(not(target_os = "linux"))]
+impl From<&cpuinfo_rs::Cache> for Cache {
+    fn from(cache: &cpuinfo_rs::Cache) -> Self {
+        Self {
+            size: cache.size,
+            associativity: cache.associativity,
+            sets: cache.sets,
+            partitions: cache.partitions,
+            line_size: cache.line_size,
+            flags: cache.flags,
+        }
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl From<&cpuinfo_rs::CacheInfo> for CacheInfo {
+    fn from(info: &cpuinfo_rs::CacheInfo) -> Self {
+        Self {
+            l1i: info.l1i.as_ref().map(Cache::from),
+            l1d: info.l1d.as_ref().map(Cache::from),
+            l2: info.l2.as_ref().map(Cache::from),
+            l3: info.l3.as_ref().map(Cache::from),
+            l4: info.l4.as_ref().map(Cache::from),
+        }
+    }
+}
+
+/// Contains information of a single logical processor.
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Processor {
+    pub smt_id: u32,
+    pub windows_group_id: Option<u16>,
+    pub windows_processor_id: Option<u16>,
+    pub apic_id: u32,
+}
+
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Core {
+    pub id: u32,
+    pub processors_count: u32,
+    pub cpu_id: u32,
+    pub frequency: u64,
+    pub processors: Vec<Processor>,
+}
+
+/// Contains information of the system's CPU.
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct CpuInfo {
+    pub brand: String,
+    pub arch: String,
+    pub op_modes: String,
+    pub vendor_id: String,
+    pub cache_info: CacheInfo,
+    pub processors_count: usize,
+    pub core_count: usize,
+    pub threads_per_core: usize,
+    pub cores: Vec<Core>,
+    /// The fields that could not be gathered.
+    pub warnings: Vec<FieldWarning>,
+}
+
+/// Returns the op-modes supported by CPUs of a machine architecture (Eg: `x86_64`), or `None` if
+/// the architecture isn't known.
+///
+/// # Arguments
+///
+/// * `arch` - The machine architecture, as reported by `uname`.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn op_modes(arch: &str) -> Option<&'static str> {
+    match arch {
+        // x86_64 generally supports both 64-bit and 32-bit modes.
+        "x86_64" => Some("64-bit, 32-bit"),
+        // 32-bit support on aarch64 depends on the OS configuration.
+        "aarch64" => Some("64-bit"),
+        "i386" | "i686" => Some("32-bit"),
+        _ => None,
+    }
+}
+
+#[cfg(not(target_os = "linux"))]
+impl CpuInfo {
+    #[cfg(target_os = "windows")]
+    /// Retrieves the CPU's supported op-modes.
+    fn get_cpu_op_modes() -> Result<String, FieldWarning> {
+        Err(FieldWarning::new("opModes", "warning-unsupported", &[]))
+    }
+
+    #[cfg(target_os = "macos")]
+    /// Retrieves the CPU's supported op-modes.
+    fn get_cpu_op_modes() -> Result<String, FieldWarning> {
+        let machine = unsafe {
+            let mut uname_info: utsname = std::mem::zeroed();
+            if uname(&mut uname_info) != 0 {
+                return Err(FieldWarning::new("opModes", "warning-uname-failed", &[]));
+            }
+
+            std::ffi::CStr::from_ptr(uname_info.machine.as_ptr())
+                .to_string_lossy()
+                .to_string()
+        };
+
+        op_modes(&machine).map(str::to_string).ok_or_else(|| {
+            FieldWarning::new(
+                "opModes",
+                "warning-unrecognized-arch",
+                &[("arch", machine.as_str().into())],
+            )
+        })
+    }
+
+    /// Retrieves information of the system's CPU.
+    pub fn get() -> Result<Self, CoreError> {
+        let info = Info::new();
+        let proc_info = info.processors();
+        let cores_info = info.cores();
+        let brand = &proc_info[0].package.name;
+        let arch = if cfg!(any(target_arch = "x86_64", target_arch = "aarch64")) {
+            std::env::consts::ARCH.to_string()
+        } else {
+            String::new()
+        };
+        let mut warnings = Vec::new();
+        let op_modes = Self::get_cpu_op_modes().unwrap_or_else(|warning| {
+            warnings.push(warning);
+            "Unknown".into()
+        });
+        let vendor = &cores_info[0].vendor.name.to_string();
+        let cache = &proc_info[0].cache;
+        let proc_count = proc_info[0].package.processor_count as usize;
+        let core_count = proc_info[0].package.core_count as usize;
+
+        let mut cores = Vec::with_capacity(core_count);
+
+        for core in cores_info {
+            let mut processors = Vec::new();
+
+            for proc in &proc_info {
+                if proc.core.core_id == core.core_id {
+                    let windows_group_id = None;
+                    let windows_processor_id = None;
+
+                    #[cfg(target_os = "windows")]
+                    {
+                        windows_group_id = proc.windows_group_id;
+                        windows_processor_id = proc.windows_processor_id;
+                    }
+
+                    processors.push(Processor {
+                        smt_id: proc.smt_id,
+                        windows_group_id,
+                        windows_processor_id,
+                        apic_id: proc.apic_id,
+                    })
+                }
+            }
+
+            cores.push(Core {
+                id: core.core_id,
+                processors_count: core.processor_count,
+                cpu_id: core.cpuid,
+                frequency: core.frequency,
+                processors,
+            });
+        }
+
+        Ok(Self {
+            brand: brand.clone(),
+            arch,
+            op_modes,
+            vendor_id: vendor.clone(),
+            cache_info: CacheInfo::from(cache),
+            processors_count: proc_count,
+            core_count,
+            threads_per_core: proc_count / core_count,
+            cores,
+            warnings,
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl CpuInfo {
+    /// Retrieves information of the system's CPU, or of the recorded system set through
+    /// `REVELIO_SYSROOT` (see [`SysRoot::current`]).
+    pub fn get() -> Result<Self, CoreError> {
+        Self::from_sysroot(&SysRoot::current())
+    }
+
+    /// Splits the contents of `/proc/cpuinfo` into the key-value pairs of each logical processor.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of `/proc/cpuinfo`.
+    fn parse_proc_cpuinfo(contents: &str) -> Vec<HashMap<&str, &str>> {
+        contents
+            .split("\n\n")
+            .map(|block| {
+                block
+                    .lines()
+                    .filter_map(|line| line.split_once(':'))
+                    .map(|(key, value)| (key.trim(), value.trim()))
+                    .collect::<HashMap<_, _>>()
+            })
+            .filter(|entry| entry.contains_key("processor"))
+            .collect()
+    }
+
+    /// Parses a sysfs cache size (Eg: `48K`, `2M`) into bytes.
+    ///
+    /// # Arguments
+    ///
+    /// * `size` - The cache size.
+    fn parse_cache_size(size: &str) -> Option<u32> {
+        let (digits, multiplier) = match size.as_bytes().last()? {
+            b'K' => (&size[..size.len() - 1], 1024),
+            b'M' => (&size[..size.len() - 1], 1024 * 1024),
+            b'G' => (&size[..size.len() - 1], 1024 * 1024 * 1024),
+            _ => (size, 1),
+        };
+
+        digits.parse::<u32>().ok()?.checked_mul(multiplier)
+    }
+
+    /// Reads the cache hierarchy of the first processor from
+    /// `/sys/devices/system/cpu/cpu0/cache`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    fn cache_info_from_sysroot(root: &SysRoot) -> CacheInfo {
+        let mut info = CacheInfo::default();
+
+        for (name, dir) in root.list("/sys/devices/system/cpu/cpu0/cache") {
+            if !name.starts_with("index") {
+                continue;
+            }
+
+            let Some(level) = root.read_parsed::<u32, _>(dir.join("level")) else {
+                continue;
+            };
+            let kind = root.read(dir.join("type")).unwrap_or_default();
+            let cache = Cache {
+                size: root
+                    .read(dir.join("size"))
+                    .and_then(|size| Self::parse_cache_size(&size))
+                    .unwrap_or(0),
+                associativity: root
+                    .read_parsed(dir.join("ways_of_associativity"))
+                    .unwrap_or(0),
+                sets: root.read_parsed(dir.join("number_of_sets")).unwrap_or(0),
+                partitions: root
+                    .read_parsed(dir.join("physical_line_partition"))
+                    .unwrap_or(1),
+                line_size: root
+                    .read_parsed(dir.join("coherency_line_size"))
+                    .unwrap_or(0),
+                flags: if kind == "Unified" { CACHE_UNIFIED } else { 0 },
+            };
+
+            match (level, kind.as_str()) {
+                (1, "Instruction") => info.l1i = Some(cache),
+                (1, _) => info.l1d = Some(cache),
+                (2, _) => info.l2 = Some(cache),
+                (3, _) => info.l3 = Some(cache),
+                (4, _) => info.l4 = Some(cache),
+                _ => {}
+            }
+        }
+
+        info
+    }
+
+    /// Computes the CPUID signature (leaf 1, `EAX`) from the family, model and stepping reported
+    /// in `/proc/cpuinfo`.
+    ///
+    /// # Arguments
+    ///
+    /// * `family` - The CPU family.
+    /// * `model` - The CPU model.
+    /// * `stepping` - The CPU stepping.
+    fn cpuid_signature(family: u32, model: u32, stepping: u32) -> u32 {
+        let (base_family, ext_family) = if family >= 0xF {
+            (0xF, family - 0xF)
+        } else {
+            (family, 0)
+        };
+        let (base_model, ext_model) = if family == 0x6 || family >= 0xF {
+            (model & 0xF, model >> 4)
+        } else {
+            (model, 0)
+        };
+
+        (ext_family << 20) | (ext_model << 16) | (base_family << 8) | (base_model << 4) | stepping
+    }
+
+    /// Retrieves information of the CPU from `/proc/cpuinfo` and `/sys/devices/system/cpu` under
+    /// the given root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    pub fn from_sysroot(root: &SysRoot) -> Result<Self, CoreError> {
+        let contents = root
+            .read("/proc/cpuinfo")
+            .ok_or_else(|| CoreError::CPUInfoError("Failed to read /proc/cpuinfo".into()))?;
+        let entries = Self::parse_proc_cpuinfo(&contents);
+        let first = entries.first().ok_or_else(|| {
+            CoreError::CPUInfoError("No processors found in /proc/cpuinfo".into())
+        })?;
+
+        let mut warnings = Vec::new();
+        // Not reported on most ARM systems.
+        let brand = first.get("model name").copied().unwrap_or_else(|| {
+            warnings.push(FieldWarning::new(
+                "brand",
+                "warning-cpuinfo-missing",
+                &[("key", "model name".into())],
+            ));
+            "Unknown"
+        });
+        let vendor_id = match first.get("vendor_id").copied() {
+            Some("GenuineIntel") => "Intel",
+            Some("AuthenticAMD") => "AMD",
+            Some(vendor) => vendor,
+            None => {
+                warnings.push(FieldWarning::new(
+                    "vendorId",
+                    "warning-cpuinfo-missing",
+                    &[("key", "vendor_id".into())],
+                ));
+                "Unknown"
+            }
+        };
+        let arch = root.arch();
+        let op_modes = op_modes(&arch).unwrap_or_else(|| {
+            warnings.push(FieldWarning::new(
+                "opModes",
+                "warning-unrecognized-arch",
+                &[("arch", arch.as_str().into())],
+            ));
+            "Unknown"
+        });
+
+        let mut core_keys: Vec<(&str, &str)> = Vec::new();
+        let mut cores: Vec<Core> = Vec::new();
+
+        for entry in &entries {
+            let processor = entry["processor"];
+            let key = (
+                entry.get("physical id").copied().unwrap_or("0"),
+                entry.get("core id").copied().unwrap_or(processor),
+            );
+            let field = |name: &str| entry.get(name).and_then(|v| v.parse::<u32>().ok());
+
+            let idx = match core_keys.iter().position(|k| *k == key) {
+                Some(idx) => idx,
+                None => {
+                    let frequency = root
+                        .read_parsed::<u64, _>(format!(
+                            "/sys/devices/system/cpu/cpu{processor}/cpufreq/cpuinfo_max_freq"
+                        ))
+                        .map(|khz| khz * 1000)
+                        .unwrap_or(0);
+
+                    core_keys.push(key);
+                    cores.push(Core {
+                        id: cores.len() as u32,
+                        processors_count: 0,
+                        cpu_id: Self::cpuid_signature(
+                            field("cpu family").unwrap_or(0),
+                            field("model").unwrap_or(0),
+                            field("stepping").unwrap_or(0),
+                        ),
+                        frequency,
+                        processors: Vec::new(),
+                    });
+                    cores.len() - 1
+                }
+            };
+
+            let core = &mut cores[idx];
+            core.processors.push(Processor {
+                smt_id: core.processors_count,
+                windows_group_id: None,
+                windows_processor_id: None,
+                apic_id: field("apicid")
+                    .or_else(|| processor.parse().ok())
+                    .unwrap_or(0),
+            });
+            core.processors_count += 1;
+        }
+
+        let processors_count = entries.len();
+        let core_count = cores.len();
+
+        Ok(Self {
+            brand: brand.into(),
+            arch,
+            op_modes: op_modes.into(),
+            vendor_id: vendor_id.into(),
+            cache_info: Self::cache_info_from_sysroot(root),
+            processors_count,
+            core_count,
+            threads_per_core: processors_count / core_count,
+            cores,
+            warnings,
+        })
+    }
+}
@@ -0,0 +1,280 @@
+use serde::Serialize;
+#[cfg(target_os = "linux")]
+use std::ffi::CString;
+#[cfg(target_os = "linux")]
+use std::fs;
+#[cfg(target_os = "linux")]
+use std::os::unix::ffi::OsStrExt;
+#[cfg(target_os = "linux")]
+use std::os::unix::fs::MetadataExt;
+#[cfg(target_os = "linux")]
+use std::path::PathBuf;
+#[cfg(not(target_os = "linux"))]
+use sysinfo::Disks;
+
+#[cfg(target_os = "linux")]
+use crate::sysroot::SysRoot;
+
+/// File systems that don't belong to a disk, and are left out of the disks list (same as
+/// `sysinfo`).
+#[cfg(target_os = "linux")]
+const IGNORED_FILE_SYSTEMS: &[&str] = &[
+    "rootfs",
+    "sysfs",
+    "proc",
+    "devtmpfs",
+    "cgroup",
+    "cgroup2",
+    "pstore",
+    "squashfs",
+    "rpc_pipefs",
+    "iso9660",
+    "tmpfs",
+    "cifs",
+    "nfs",
+    "nfs4",
+];
+
+/// The kind of a disk.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum DiskKind {
+    /// A hard disk drive.
+    Hdd,
+    /// A solid state drive.
+    Ssd,
+    /// An unknown kind of disk, along with the raw value reported by the system.
+    Unknown(isize),
+}
+
+impl From<sysinfo::DiskKind> for DiskKind {
+    fn from(kind: sysinfo::DiskKind) -> Self {
+        match kind {
+            sysinfo::DiskKind::HDD => Self::Hdd,
+            sysinfo::DiskKind::SSD => Self::Ssd,
+            sysinfo::DiskKind::Unknown(n) => Self::Unknown(n),
+        }
+    }
+}
+
+// TODO: include more advanced information (Eg: vendor, etc.).
+/// Represents an individual disk on the system.
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct Disk {
+    /// The name of the disk.
+    pub name: String,
+    /// The file-system of the disk (Eg: `EXT4`, `NTFS`, etc.).
+    pub file_system: String,
+    /// The mount point of the disk.
+    pub mount_point: String,
+    /// The total space/size of the disk.
+    pub total_space: u64,
+    /// The currently available space on the disk.
+    pub available_space: u64,
+
+    /// The kind of disk (HDD, SSD or unknown).
+    pub kind: DiskKind,
+}
+
+/// Contains information of all the disks identified on the system.
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct DisksInfo {
+    /// The total number of disks.
+    pub count: u64,
+    /// The information of each disk.
+    pub disks: Vec<Disk>,
+}
+
+#[cfg(not(target_os = "linux"))]
+impl DisksInfo {
+    /// Retrieves information of all disks identified on the system.
+    pub fn get() -> Self {
+        let disks = Disks::new_with_refreshed_list();
+        let count = disks.list().len();
+        let mut disks_info = Vec::with_capacity(count);
+
+        for disk in disks.list() {
+            let name = disk.name().to_string_lossy().to_string();
+            let file_system = disk.file_system().to_string_lossy().to_string();
+            let mount_point = disk.mount_point().to_string_lossy().to_string();
+
+            disks_info.push(Disk {
+                name,
+                file_system,
+                mount_point,
+                total_space: disk.total_space(),
+                available_space: disk.available_space(),
+                kind: disk.kind().into(),
+            });
+        }
+
+        Self {
+            count: count as u64,
+            disks: disks_info,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl DisksInfo {
+    /// Retrieves information of all disks identified on the system, or on the recorded system set
+    /// through `REVELIO_SYSROOT` (see [`SysRoot::current`]).
+    pub fn get() -> Self {
+        Self::from_sysroot(&SysRoot::current())
+    }
+
+    /// Returns the space available to unprivileged users on a mounted file system, in bytes.
+    ///
+    /// The free space of a file system can't be recorded in a directory tree, so it's only known
+    /// for the live system.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    /// * `mount_point` - The mount point of the file system.
+    fn available_space(root: &SysRoot, mount_point: &str) -> Option<u64> {
+        if !root.is_live() {
+            return None;
+        }
+
+        let path = CString::new(root.path(mount_point).as_os_str().as_bytes()).ok()?;
+        unsafe {
+            let mut stat: libc::statvfs = std::mem::zeroed();
+            if libc::statvfs(path.as_ptr(), &mut stat) != 0 {
+                return None;
+            }
+
+            Some(stat.f_bavail as u64 * stat.f_frsize as u64)
+        }
+    }
+
+    /// Finds the sysfs directory of a block device (Eg: `/sys/block/nvme0n1/nvme0n1p2`), along
+    /// with the name of the disk it belongs to.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    /// * `name` - The name of the block device (Eg: `nvme0n1p2`, `mapper/fedora-root`).
+    fn find_block_device(root: &SysRoot, name: &str) -> Option<(PathBuf, String)> {
+        // Device-mapper devices are listed by their kernel name (Eg: `dm-0`).
+        if let Some(dm_name) = name.strip_prefix("mapper/") {
+            return root.list("/sys/block").into_iter().find_map(|(disk, dir)| {
+                (root.read(dir.join("dm/name")).as_deref() == Some(dm_name)).then_some((dir, disk))
+            });
+        }
+
+        let disk_dir = PathBuf::from(format!("/sys/block/{name}"));
+        if root.exists(&disk_dir) {
+            return Some((disk_dir, name.to_string()));
+        }
+
+        root.list("/sys/block").into_iter().find_map(|(disk, dir)| {
+            let partition_dir = dir.join(name);
+            root.exists(&partition_dir).then_some((partition_dir, disk))
+        })
+    }
+
+    /// Finds the sysfs directory of the block device a file system is mounted from, by the device
+    /// number of its mount point. Used for devices not known by the name they're mounted with (Eg:
+    /// `/dev/root`), and only for the live system.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    /// * `mount_point` - The mount point of the file system.
+    fn find_mounted_device(root: &SysRoot, mount_point: &str) -> Option<(PathBuf, String)> {
+        if !root.is_live() {
+            return None;
+        }
+
+        let device = fs::metadata(root.path(mount_point)).ok()?.dev();
+        let target = fs::read_link(format!(
+            "/sys/dev/block/{}:{}",
+            libc::major(device),
+            libc::minor(device)
+        ))
+        .ok()?;
+
+        Self::find_block_device(root, target.file_name()?.to_str()?)
+    }
+
+    /// Retrieves information of the disks mounted in `/proc/mounts` from `/sys/block` under the
+    /// given root.
+    ///
+    /// The available space of the disks of a recorded tree is reported as `0`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    pub fn from_sysroot(root: &SysRoot) -> Self {
+        let mounts = root.read("/proc/mounts").unwrap_or_default();
+        let mut disks = Vec::new();
+
+        for line in mounts.lines() {
+            let mut fields = line.split_whitespace();
+            let (Some(device), Some(mount_point), Some(file_system)) =
+                (fields.next(), fields.next(), fields.next())
+            else {
+                continue;
+            };
+            let mount_point = mount_point
+                .replace("\\134", "\\")
+                .replace("\\040", " ")
+                .replace("\\011", "\t")
+                .replace("\\012", "\n");
+
+            if IGNORED_FILE_SYSTEMS.contains(&file_system)
+                || mount_point.starts_with("/sys")
+                || mount_point.starts_with("/proc")
+                || (mount_point.starts_with("/run") && !mount_point.starts_with("/run/media"))
+            {
+                continue;
+            }
+
+            let Some(name) = device.strip_prefix("/dev/") else {
+                continue;
+            };
+            let Some((dir, disk)) = Self::find_block_device(root, name)
+                .or_else(|| Self::find_mounted_device(root, &mount_point))
+            else {
+                continue;
+            };
+
+            // The size of block devices is always given in 512-byte sectors.
+            let total_space = root.read_parsed::<u64, _>(dir.join("size")).unwrap_or(0) * 512;
+            if total_space == 0 {
+                continue;
+            }
+
+            let kind = match root.read_parsed(format!("/sys/block/{disk}/queue/rotational")) {
+                Some(1) => DiskKind::Hdd,
+                Some(0) => DiskKind::Ssd,
+                Some(n) => DiskKind::Unknown(n),
+                None => DiskKind::Unknown(-1),
+            };
+
+            let available_space = Self::available_space(root, &mount_point).unwrap_or(0);
+            disks.push(Disk {
+                name: device.to_string(),
+                file_system: file_system.to_string(),
+                mount_point,
+                total_space,
+                available_space,
+                kind,
+            });
+        }
+
+        Self {
+            count: disks.len() as u64,
+            disks,
+        }
+    }
+}
@@ -1,12 +1,15 @@
 use glium::backend::glutin::SimpleWindowBuilder;
 use glium::winit::event_loop::EventLoop;
 use glium::winit::monitor::MonitorHandle;
-use serde::{Serialize, Serializer};
+use serde::Serialize;
 
 use crate::types::CoreError;
 
-/// Represents a display's dimensions (width x height).
-#[derive(Clone)]
+/// Represents a display's dimensions (width x height), in physical pixels.
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
 pub struct Dimensions {
     /// Display width.
     pub width: u32,
@@ -15,7 +18,10 @@ pub struct Dimensions {
 }
 
 /// Represents the position coordinates of the top-left corner of the monitor relative to the larger full screen area (x, y).
-#[derive(Clone)]
+#[derive(Debug, Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
 pub struct Position {
     /// The x coordinate.
     pub x: i32,
@@ -25,17 +31,17 @@ pub struct Position {
 
 /// Represents an individual display attached to the system.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct Display {
     /// The name of the display.
     pub name: String,
     /// The display dimensions (width x height).
-    #[serde(serialize_with = "serialize_dimensions")]
     pub dimensions: Dimensions,
     /// The scale factor of the display.
     pub scale_factor: f64,
     /// The top-left corner position of the monitor relative to the larger full screen area (x, y).
-    #[serde(serialize_with = "serialize_position")]
     pub position: Position,
     /// The display's refresh rate in MHz.
     pub refresh_rate: Option<u32>,
@@ -43,6 +49,8 @@ pub struct Display {
 
 /// Contains information of the displays connected to the system.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DisplaysInfo {
     /// The total number of displays.
@@ -51,24 +59,11 @@ pub struct DisplaysInfo {
     pub displays: Vec<Display>,
 }
 
-fn serialize_dimensions<S>(d: &Dimensions, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serializer.serialize_str(&format!("{}x{}", d.width, d.height))
-}
-
-fn serialize_position<S>(p: &Position, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    serializer.serialize_str(&format!("({}, {})", p.x, p.y))
-}
-
 impl DisplaysInfo {
     /// Retrieves information about the displays connected to the system.
     pub fn get() -> Result<Self, CoreError> {
         // FIXME!: Similar to `crate::utils::gpu::opengl::OpenGLInfo::get()`, the event loop here panics as well when called multiple times. MUST BE FIXED.
+        // The section is gathered in a helper process (see `crate::probe`), which only calls this once.
         let event_loop = EventLoop::new().map_err(|e| CoreError::Error(e.into()))?;
         let (window, _) = SimpleWindowBuilder::new().build(&event_loop);
         let monitors: Vec<MonitorHandle> = window.available_monitors().collect();
@@ -1,15 +1,23 @@
+#[cfg(target_os = "windows")]
 use std::ptr;
+#[cfg(target_os = "windows")]
 use std::mem;
 
 use serde::Serialize;
+#[cfg(target_os = "windows")]
 use winapi::ctypes::c_void;
+#[cfg(target_os = "windows")]
 use winapi::Interface;
+#[cfg(target_os = "windows")]
 use winapi::shared::dxgi::{CreateDXGIFactory, IDXGIAdapter, IDXGIFactory, DXGI_ADAPTER_DESC};
+#[cfg(target_os = "windows")]
 use crate::types::CoreError;
 
 // TODO: add more info.
 /// Contains information of a DirectX device.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DirectXDevice {
     /// Device index; useful for unique identification in mapping functions, but otherwise not bound to the device itself.
@@ -34,6 +42,8 @@ pub struct DirectXDevice {
 
 /// Contains information about DirectX on the system.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct DirectXInfo {
     /// The total number of DirectX capable devices.
@@ -42,6 +52,7 @@ pub struct DirectXInfo {
     pub devices: Vec<DirectXDevice>,
 }
 
+#[cfg(target_os = "windows")]
 impl DirectXInfo {
     /// Retrieves the DirectX information from the system.
     pub fn get() -> Result<Self, CoreError> {
@@ -1,30 +1,77 @@
+#[cfg(target_os = "macos")]
 use metal::{Device, MTLArgumentBuffersTier, MTLDeviceLocation, MTLReadWriteTextureTier, MTLSize};
-use num_format::{Locale, ToFormattedString};
-use serde::{Serialize, Serializer};
+use serde::Serialize;
 
+#[cfg(target_os = "macos")]
 use crate::types::CoreError;
 
 /// Contains information of a Metal counter set.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct MetalCounterSet {
     pub name: String,
 }
 
+/// The location of a Metal device relative to the host system.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum MetalDeviceLocation {
+    BuiltIn,
+    Slot,
+    External,
+    Unspecified,
+}
+
+/// The argument buffers support tier of a Metal device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum MetalArgumentBuffersTier {
+    Tier1,
+    Tier2,
+}
+
+/// The read-write texture support tier of a Metal device.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum MetalReadWriteTextureTier {
+    TierNone,
+    Tier1,
+    Tier2,
+}
+
+/// The dimensions of a Metal grid or threadgroup.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MetalSize {
+    pub width: u64,
+    pub height: u64,
+    pub depth: u64,
+}
+
 /// Contains information of a Metal device.
 #[allow(non_snake_case)]
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct MetalDevice {
     pub index: usize,
     pub device_name: String,
     pub registry_id: u64,
-    #[serde(serialize_with = "serialize_mtl_device_location")]
-    pub location: MTLDeviceLocation,
+    pub location: MetalDeviceLocation,
     pub location_number: u64,
     pub max_threadgroup_memory_length: u64,
-    #[serde(serialize_with = "serialize_mtl_size")]
-    pub max_threads_per_threadgroup: MTLSize,
+    pub max_threads_per_threadgroup: MetalSize,
     pub is_low_power: bool,
     pub is_headless: bool,
     pub is_removable: bool,
@@ -35,11 +82,11 @@ pub struct MetalDevice {
     pub supports_barycentric_coordinates: bool,
     pub supports_function_pointers: bool,
     pub supports_dynamic_libraries: bool,
-    #[serde(serialize_with = "serialize_mtl_argument_buffers_tier")]
-    pub argument_buffers_support: MTLArgumentBuffersTier,
-    #[serde(serialize_with = "serialize_mtl_read_write_texture_tier")]
-    pub read_write_texture_support: MTLReadWriteTextureTier,
+    pub argument_buffers_support: MetalArgumentBuffersTier,
+    pub read_write_texture_support: MetalReadWriteTextureTier,
+    #[serde(rename = "supports32bitFloatFiltering")]
     pub supports_32bit_float_filtering: bool,
+    #[serde(rename = "supports32bitMSAA")]
     pub supports_32bit_MSAA: bool,
     pub supports_query_texture_LOD: bool,
     pub supports_BC_texture_compression: bool,
@@ -52,65 +99,59 @@ pub struct MetalDevice {
 
 /// Contains information about the Metal capable devices identified on the system.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct MetalInfo {
     pub total_devices: usize,
     pub devices: Vec<MetalDevice>,
 }
 
-fn serialize_mtl_device_location<S>(m: &MTLDeviceLocation, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    match m {
-        MTLDeviceLocation::BuiltIn => serializer.serialize_str("Built-in"),
-        MTLDeviceLocation::Slot => serializer.serialize_str("Slot"),
-        MTLDeviceLocation::External => serializer.serialize_str("External"),
-        MTLDeviceLocation::Unspecified => serializer.serialize_str("Unspecified"),
+#[cfg(target_os = "macos")]
+impl From<MTLDeviceLocation> for MetalDeviceLocation {
+    fn from(m: MTLDeviceLocation) -> Self {
+        match m {
+            MTLDeviceLocation::BuiltIn => Self::BuiltIn,
+            MTLDeviceLocation::Slot => Self::Slot,
+            MTLDeviceLocation::External => Self::External,
+            MTLDeviceLocation::Unspecified => Self::Unspecified,
+        }
     }
 }
 
-fn serialize_mtl_size<S>(m: &MTLSize, serializer: S) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    let product = m.depth * m.width * m.height;
-    serializer.serialize_str(&format!(
-        "{} (depth: {} width: {} height: {})",
-        product.to_formatted_string(&Locale::en),
-        m.depth,
-        m.width,
-        m.height
-    ))
+#[cfg(target_os = "macos")]
+impl From<MTLSize> for MetalSize {
+    fn from(m: MTLSize) -> Self {
+        Self {
+            width: m.width,
+            height: m.height,
+            depth: m.depth,
+        }
+    }
 }
 
-fn serialize_mtl_argument_buffers_tier<S>(
-    m: &MTLArgumentBuffersTier,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    match m {
-        MTLArgumentBuffersTier::Tier1 => serializer.serialize_str("Tier 1"),
-        MTLArgumentBuffersTier::Tier2 => serializer.serialize_str("Tier 2"),
+#[cfg(target_os = "macos")]
+impl From<MTLArgumentBuffersTier> for MetalArgumentBuffersTier {
+    fn from(m: MTLArgumentBuffersTier) -> Self {
+        match m {
+            MTLArgumentBuffersTier::Tier1 => Self::Tier1,
+            MTLArgumentBuffersTier::Tier2 => Self::Tier2,
+        }
     }
 }
 
-fn serialize_mtl_read_write_texture_tier<S>(
-    m: &MTLReadWriteTextureTier,
-    serializer: S,
-) -> Result<S::Ok, S::Error>
-where
-    S: Serializer,
-{
-    match m {
-        MTLReadWriteTextureTier::TierNone => serializer.serialize_str("Tier None"),
-        MTLReadWriteTextureTier::Tier1 => serializer.serialize_str("Tier 1"),
-        MTLReadWriteTextureTier::Tier2 => serializer.serialize_str("Tier 2"),
+#[cfg(target_os = "macos")]
+impl From<MTLReadWriteTextureTier> for MetalReadWriteTextureTier {
+    fn from(m: MTLReadWriteTextureTier) -> Self {
+        match m {
+            MTLReadWriteTextureTier::TierNone => Self::TierNone,
+            MTLReadWriteTextureTier::Tier1 => Self::Tier1,
+            MTLReadWriteTextureTier::Tier2 => Self::Tier2,
+        }
     }
 }
 
+#[cfg(target_os = "macos")]
 impl MetalInfo {
     /// Retrieves information about the Metal capable devices identified on the system.
     pub fn get() -> Result<Self, CoreError> {
@@ -132,10 +173,10 @@ impl MetalInfo {
                 index: idx,
                 device_name: device.name().into(),
                 registry_id: device.registry_id(),
-                location: device.location(),
+                location: device.location().into(),
                 location_number: device.location_number(),
                 max_threadgroup_memory_length: device.max_threadgroup_memory_length(),
-                max_threads_per_threadgroup: device.max_threads_per_threadgroup(),
+                max_threads_per_threadgroup: device.max_threads_per_threadgroup().into(),
                 is_low_power: device.is_low_power(),
                 is_headless: device.is_headless(),
                 is_removable: device.is_removable(),
@@ -146,8 +187,8 @@ impl MetalInfo {
                 supports_barycentric_coordinates: device.supports_shader_barycentric_coordinates(),
                 supports_function_pointers: device.supports_function_pointers(),
                 supports_dynamic_libraries: device.supports_dynamic_libraries(),
-                argument_buffers_support: device.argument_buffers_support(),
-                read_write_texture_support: device.read_write_texture_support(),
+                argument_buffers_support: device.argument_buffers_support().into(),
+                read_write_texture_support: device.read_write_texture_support().into(),
                 supports_32bit_float_filtering: device.supports_32bit_float_filtering(),
                 supports_32bit_MSAA: device.supports_32bit_MSAA(),
                 supports_query_texture_LOD: device.supports_query_texture_LOD(),
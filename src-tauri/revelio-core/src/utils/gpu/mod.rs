@@ -0,0 +1,8 @@
+#[cfg(feature = "directx")]
+pub mod directx;
+#[cfg(feature = "metal")]
+pub mod metal;
+#[cfg(feature = "opengl")]
+pub mod opengl;
+#[cfg(feature = "vulkan")]
+pub mod vulkan;
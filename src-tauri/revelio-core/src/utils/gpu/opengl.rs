@@ -8,6 +8,8 @@ use crate::types::CoreError;
 
 /// Contains information about OpenGL on the system.
 #[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
 #[serde(rename_all = "camelCase")]
 pub struct OpenGLInfo {
     /// The OpenGL device vendor.
@@ -22,6 +24,7 @@ pub struct OpenGLInfo {
 
 static OPENGL_INFO: LazyLock<Result<OpenGLInfo, String>> = LazyLock::new(|| {
     // FIXME: This still causes a panic, although it's now a segmentation fault rather than a `RecreationAttempt` error.
+    // The section is gathered in a helper process (see `crate::probe`), so this only takes down the helper.
     let event_loop = EventLoop::new().map_err(|e| format!("failed to create event loop: {}", e))?;
     let (_, display) = SimpleWindowBuilder::new().build(&event_loop);
 
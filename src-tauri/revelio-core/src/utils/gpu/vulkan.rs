@@ -0,0 +1,259 @@
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+use ash::vk::{self, InstanceCreateInfo};
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+use ash::Entry;
+use serde::Serialize;
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+use std::ffi::CStr;
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+use std::fmt::Display;
+
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+use crate::types::{CoreError, VK_DEVICE_TYPE_MAP};
+use crate::types::{FieldWarning, VulkanDeviceType};
+
+/// Contains information of a Vulkan device layer.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct VulkanDeviceLayer {
+    /// The layer's name.
+    pub layer_name: String,
+    /// The layer's Vulkan version.
+    pub vulkan_version: String,
+    /// The layer's version.
+    pub layer_version: u32,
+    /// The layer's description.
+    pub description: String,
+}
+
+// TODO: add limits and sparse properties.
+/// Contains information of a Vulkan device.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct VulkanDevice {
+    /// Device index; useful for unique identification in mapping functions, but otherwise not bound to the device itself.
+    pub index: usize,
+    /// The name of the device.
+    pub device_name: String,
+    /// The device's vendor ID.
+    pub vendor_id: u32,
+    /// The device ID.
+    pub device_id: u32,
+    /// The type of device (Integrated, Discrete, Virtual, etc.), if recognized.
+    pub device_type: Option<VulkanDeviceType>,
+    /// The Vulkan API version the device supports.
+    pub api_version: String,
+    /// The device's driver version.
+    pub driver_version: String,
+    /// The pipeline cache UUID.
+    pub pipeline_cache_uuid: String,
+    /// The device's Vulkan layers.
+    pub layers: Vec<VulkanDeviceLayer>,
+}
+
+/// Contains information about Vulkan on the system.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct VulkanInfo {
+    /// The total number of Vulkan-enabled devices.
+    total_devices: usize,
+    /// The list of Vulkan-enabled devices identified on the system.
+    devices: Vec<VulkanDevice>,
+    /// The fields that could not be gathered.
+    warnings: Vec<FieldWarning>,
+}
+
+/// The PCI vendor ID of NVIDIA.
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+const NVIDIA_VENDOR_ID: u32 = 0x10de;
+/// The PCI vendor ID of Intel.
+#[cfg(target_os = "windows")]
+const INTEL_VENDOR_ID: u32 = 0x8086;
+
+#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+impl VulkanInfo {
+    /// Extracts and forms a Vulkan version string.
+    ///
+    /// # Arguments
+    ///
+    /// * `version` - The Vulkan version value.
+    fn get_version_string(version: u32) -> String {
+        let major = vk::api_version_major(version);
+        let minor = vk::api_version_minor(version);
+        let patch = vk::api_version_patch(version);
+
+        format!("{major}.{minor}.{patch}")
+    }
+
+    /// Extracts and forms a driver version string. The drivers of some vendors don't encode their
+    /// version as a Vulkan version (Eg: NVIDIA's `535.183.1.0`).
+    ///
+    /// # Arguments
+    ///
+    /// * `vendor_id` - The device's vendor ID.
+    /// * `version` - The driver version value.
+    fn get_driver_version_string(vendor_id: u32, version: u32) -> String {
+        match vendor_id {
+            // 10 bits major, 8 bits minor, 8 bits patch and 6 bits build.
+            NVIDIA_VENDOR_ID => format!(
+                "{}.{}.{}.{}",
+                version >> 22,
+                (version >> 14) & 0xff,
+                (version >> 6) & 0xff,
+                version & 0x3f
+            ),
+            // 18 bits major and 14 bits minor.
+            #[cfg(target_os = "windows")]
+            INTEL_VENDOR_ID => format!("{}.{}", version >> 14, version & 0x3fff),
+            _ => Self::get_version_string(version),
+        }
+    }
+
+    /// Decodes a fixed-size string property of a device or layer, falling back to `Unknown` if
+    /// it is malformed.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The result of reading the property.
+    /// * `field` - The path of the field the property is stored in.
+    /// * `warnings` - Collects the warning if the property is malformed.
+    fn decode_str<E>(
+        value: Result<&CStr, E>,
+        field: String,
+        warnings: &mut Vec<FieldWarning>,
+    ) -> String
+    where
+        E: Display,
+    {
+        match value {
+            Ok(value) => value.to_string_lossy().to_string(),
+            Err(e) => {
+                warnings.push(FieldWarning::new(
+                    field,
+                    "warning-malformed-string",
+                    &[("reason", e.to_string().into())],
+                ));
+                "Unknown".into()
+            }
+        }
+    }
+
+    // TODO: split info retrieval into separate functions.
+    /// Retrieves the Vulkan information from the system.
+    pub fn get() -> Result<Self, CoreError> {
+        let entry =
+            unsafe { Entry::load().map_err(|e| CoreError::VulkanInfoError(e.to_string().into()))? };
+        let instance = unsafe {
+            entry
+                .create_instance(
+                    &InstanceCreateInfo {
+                        ..Default::default()
+                    },
+                    None,
+                )
+                .map_err(|e| CoreError::VulkanInfoError(e.to_string().into()))?
+        };
+        let physical_devices = unsafe {
+            instance
+                .enumerate_physical_devices()
+                .map_err(|e| CoreError::VulkanInfoError(e.to_string().into()))?
+        };
+        let devices_count = physical_devices.len();
+        let mut devices = Vec::with_capacity(devices_count);
+        let mut warnings = Vec::new();
+
+        for (idx, device) in physical_devices.iter().enumerate() {
+            let device_props = unsafe { instance.get_physical_device_properties(*device) };
+            let device_name = Self::decode_str(
+                device_props.device_name_as_c_str(),
+                format!("devices[{idx}].deviceName"),
+                &mut warnings,
+            );
+            let vendor_id = device_props.vendor_id;
+            let device_id = device_props.device_id;
+            let api_version = Self::get_version_string(device_props.api_version);
+            let driver_version =
+                Self::get_driver_version_string(vendor_id, device_props.driver_version);
+            let pipeline_cache_uuid = {
+                let uuid = hex::encode(device_props.pipeline_cache_uuid);
+                format!(
+                    "{}-{}-{}-{}-{}",
+                    &uuid[0..8],
+                    &uuid[8..12],
+                    &uuid[12..16],
+                    &uuid[16..20],
+                    &uuid[20..32],
+                )
+            };
+
+            let device_layers = unsafe { instance.enumerate_device_layer_properties(*device) }
+                .unwrap_or_else(|e| {
+                    warnings.push(FieldWarning::new(
+                        format!("devices[{idx}].layers"),
+                        "warning-layers-failed",
+                        &[("reason", e.to_string().into())],
+                    ));
+                    Vec::new()
+                });
+            let mut layers = Vec::with_capacity(device_layers.len());
+
+            for (layer_idx, layer) in device_layers.iter().enumerate() {
+                let layer_name = Self::decode_str(
+                    layer.layer_name_as_c_str(),
+                    format!("devices[{idx}].layers[{layer_idx}].layerName"),
+                    &mut warnings,
+                );
+                let vulkan_version = Self::get_version_string(layer.spec_version);
+                let layer_version = layer.implementation_version;
+                let description = Self::decode_str(
+                    layer.description_as_c_str(),
+                    format!("devices[{idx}].layers[{layer_idx}].description"),
+                    &mut warnings,
+                );
+
+                layers.push(VulkanDeviceLayer {
+                    layer_name,
+                    vulkan_version,
+                    layer_version,
+                    description,
+                });
+            }
+
+            let device_type = match VK_DEVICE_TYPE_MAP.get(&device_props.device_type.as_raw()) {
+                Some(device_type) => Some(*device_type),
+                None => {
+                    warnings.push(FieldWarning::new(
+                        format!("devices[{idx}].deviceType"),
+                        "warning-unrecognized-device-type",
+                        &[("type", device_props.device_type.as_raw().to_string().into())],
+                    ));
+                    None
+                }
+            };
+
+            devices.push(VulkanDevice {
+                index: idx,
+                device_name,
+                vendor_id,
+                device_id,
+                device_type,
+                api_version,
+                driver_version,
+                pipeline_cache_uuid,
+                layers,
+            })
+        }
+
+        Ok(Self {
+            total_devices: devices_count,
+            devices,
+            warnings,
+        })
+    }
+}
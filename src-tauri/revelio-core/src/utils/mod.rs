@@ -0,0 +1,13 @@
+#[cfg(feature = "cpu")]
+pub mod cpu;
+#[cfg(feature = "disks")]
+pub mod disks;
+#[cfg(feature = "displays")]
+pub mod displays;
+pub mod gpu;
+#[cfg(feature = "network")]
+pub mod network;
+#[cfg(feature = "platform")]
+pub mod platform;
+#[cfg(feature = "usb")]
+pub mod usb;
@@ -0,0 +1,256 @@
+use std::fmt::Display;
+#[cfg(target_os = "linux")]
+use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+use serde::{Serialize, Serializer};
+use sysinfo::IpNetwork;
+#[cfg(not(target_os = "linux"))]
+use sysinfo::Networks;
+
+#[cfg(target_os = "linux")]
+use crate::sysroot::SysRoot;
+
+/// Represents an individual network interface on the system.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NetworkInterface {
+    /// The name of the interface.
+    itf_name: String,
+    /// The MAC address of the interface.
+    mac_addr: String,
+
+    /// The IP networks belonging to the interface.
+    #[serde(serialize_with = "serialize_display_vec")]
+    #[cfg_attr(feature = "specta", specta(type = Vec<String>))]
+    #[cfg_attr(
+        feature = "schema",
+        schemars(with = "Vec<String>", inner(pattern(r"^[0-9a-fA-F.:]+/\d{1,3}$")))
+    )]
+    ip_networks: Vec<IpNetwork>,
+    /// The number of bytes received on the interface since it was brought up.
+    received_bytes: u64,
+    /// The number of bytes transmitted on the interface since it was brought up.
+    transmitted_bytes: u64,
+}
+
+/// Contains information of the networks and network interfaces on the system.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct NetworksInfo {
+    /// The total number of network interfaces.
+    pub total_interfaces: usize,
+    /// The list of network interfaces.
+    pub interfaces: Vec<NetworkInterface>,
+}
+
+#[inline]
+fn serialize_display_vec<S, T>(values: &[T], serializer: S) -> Result<S::Ok, S::Error>
+where
+    S: Serializer,
+    T: Display,
+{
+    let disp_strings: Vec<String> = values.iter().map(|v| v.to_string()).collect();
+    disp_strings.serialize(serializer)
+}
+
+#[cfg(not(target_os = "linux"))]
+impl NetworksInfo {
+    /// Retrieves network(s) information from the system.
+    pub fn get() -> Self {
+        let networks = Networks::new_with_refreshed_list();
+        let count = networks.len();
+        let mut interfaces = Vec::with_capacity(count);
+
+        for (itf_name, network) in &networks {
+            let mac_addr = network.mac_address().to_string();
+            let ip_networks = network.ip_networks();
+
+            interfaces.push(NetworkInterface {
+                itf_name: itf_name.clone(),
+                mac_addr,
+                ip_networks: ip_networks.into(),
+                received_bytes: network.total_received(),
+                transmitted_bytes: network.total_transmitted(),
+            })
+        }
+
+        Self {
+            total_interfaces: count,
+            interfaces,
+        }
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl NetworksInfo {
+    /// Retrieves network(s) information from the system, or from the recorded system set through
+    /// `REVELIO_SYSROOT` (see [`SysRoot::current`]).
+    pub fn get() -> Self {
+        Self::from_sysroot(&SysRoot::current())
+    }
+
+    /// Parses the directly connected IPv4 networks listed in `/proc/net/route`, as the interface
+    /// name, network address and prefix length of each.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of `/proc/net/route`.
+    fn parse_route(contents: &str) -> Vec<(String, Ipv4Addr, u8)> {
+        // Addresses are in network byte order, printed as little-endian numbers.
+        let address = |field: &str| {
+            u32::from_str_radix(field, 16)
+                .ok()
+                .map(|n| Ipv4Addr::from(n.swap_bytes()))
+        };
+
+        contents
+            .lines()
+            .skip(1)
+            .filter_map(|line| {
+                // <interface> <destination> <gateway> <flags> <refcnt> <use> <metric> <mask> ...
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [name, destination, gateway, _, _, _, _, mask, ..] = fields.as_slice() else {
+                    return None;
+                };
+                let mask = address(mask)?;
+                if address(gateway)? != Ipv4Addr::UNSPECIFIED || mask == Ipv4Addr::UNSPECIFIED {
+                    return None;
+                }
+
+                Some((
+                    name.to_string(),
+                    address(destination)?,
+                    u32::from(mask).count_ones() as u8,
+                ))
+            })
+            .collect()
+    }
+
+    /// Parses the local IPv4 addresses listed in `/proc/net/fib_trie`.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of `/proc/net/fib_trie`.
+    fn parse_fib_trie(contents: &str) -> Vec<Ipv4Addr> {
+        let mut addresses = Vec::new();
+        let mut last = None;
+
+        // Each address (`|-- 192.168.1.20`) is followed by its routes (`/32 host LOCAL`).
+        for line in contents.lines().map(str::trim) {
+            if let Some(address) = line.strip_prefix("|-- ") {
+                last = address.parse::<Ipv4Addr>().ok();
+            } else if line.starts_with("/32 host LOCAL") {
+                if let Some(address) = last.filter(|address| !addresses.contains(address)) {
+                    addresses.push(address);
+                }
+            }
+        }
+
+        addresses
+    }
+
+    /// Finds the IPv4 networks of each interface, keyed by interface name, from the local
+    /// addresses in `/proc/net/fib_trie` and the networks they belong to in `/proc/net/route`.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    fn ipv4_networks(root: &SysRoot) -> Vec<(String, IpNetwork)> {
+        let routes = Self::parse_route(&root.read("/proc/net/route").unwrap_or_default());
+
+        Self::parse_fib_trie(&root.read("/proc/net/fib_trie").unwrap_or_default())
+            .into_iter()
+            .filter_map(|addr| {
+                // The loopback network isn't in the main routing table.
+                let (name, prefix) = if addr.is_loopback() {
+                    ("lo".to_string(), 8)
+                } else {
+                    routes.iter().find_map(|(name, network, prefix)| {
+                        let mask = u32::MAX.checked_shl(32 - u32::from(*prefix)).unwrap_or(0);
+                        (u32::from(addr) & mask == u32::from(*network))
+                            .then(|| (name.clone(), *prefix))
+                    })?
+                };
+
+                Some((
+                    name,
+                    IpNetwork {
+                        addr: IpAddr::V4(addr),
+                        prefix,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Parses the IPv6 networks listed in `/proc/net/if_inet6`, keyed by interface name.
+    ///
+    /// # Arguments
+    ///
+    /// * `contents` - The contents of `/proc/net/if_inet6`.
+    fn parse_if_inet6(contents: &str) -> Vec<(String, IpNetwork)> {
+        contents
+            .lines()
+            .filter_map(|line| {
+                // <address> <index> <prefix length> <scope> <flags> <interface name>
+                let fields: Vec<&str> = line.split_whitespace().collect();
+                let [address, _, prefix, _, _, name] = fields.as_slice() else {
+                    return None;
+                };
+                let addr = Ipv6Addr::from(u128::from_str_radix(address, 16).ok()?);
+                let prefix = u8::from_str_radix(prefix, 16).ok()?;
+
+                Some((
+                    name.to_string(),
+                    IpNetwork {
+                        addr: IpAddr::V6(addr),
+                        prefix,
+                    },
+                ))
+            })
+            .collect()
+    }
+
+    /// Retrieves network interface information from `/sys/class/net` and `/proc/net` under the
+    /// given root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    pub fn from_sysroot(root: &SysRoot) -> Self {
+        let mut networks = Self::ipv4_networks(root);
+        networks.extend(Self::parse_if_inet6(
+            &root.read("/proc/net/if_inet6").unwrap_or_default(),
+        ));
+        let interfaces: Vec<NetworkInterface> = root
+            .list("/sys/class/net")
+            .into_iter()
+            .map(|(itf_name, dir)| NetworkInterface {
+                mac_addr: root
+                    .read(dir.join("address"))
+                    .unwrap_or_else(|| "00:00:00:00:00:00".into()),
+                ip_networks: networks
+                    .iter()
+                    .filter(|(name, _)| *name == itf_name)
+                    .map(|(_, network)| *network)
+                    .collect(),
+                received_bytes: root
+                    .read_parsed(dir.join("statistics/rx_bytes"))
+                    .unwrap_or(0),
+                transmitted_bytes: root
+                    .read_parsed(dir.join("statistics/tx_bytes"))
+                    .unwrap_or(0),
+                itf_name,
+            })
+            .collect();
+
+        Self {
+            total_interfaces: interfaces.len(),
+            interfaces,
+        }
+    }
+}
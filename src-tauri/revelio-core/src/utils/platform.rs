@@ -0,0 +1,396 @@
+#[cfg(target_os = "linux")]
+use os_release::OsRelease;
+use serde::Serialize;
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+use std::env;
+#[cfg(target_os = "macos")]
+use std::process::Command;
+#[cfg(not(target_os = "linux"))]
+use sysinfo::System;
+
+#[cfg(target_os = "linux")]
+use crate::sysroot::SysRoot;
+use crate::types::{CoreError, FieldWarning};
+
+/// Represents the current platform.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum Platform {
+    #[serde(rename = "Windows")]
+    Windows,
+    #[serde(rename = "macOS")]
+    MacOS,
+    #[serde(rename = "Linux")]
+    Linux,
+    #[serde(rename = "Unknown")]
+    Unknown,
+}
+
+/// Returns the type of the current operating system, as the frontend distinguishes them (`Windows`,
+/// `MacIntel`, `MacSilicon` or `Linux`).
+pub fn os_type() -> &'static str {
+    #[cfg(target_os = "windows")]
+    return "Windows";
+
+    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
+    return "MacIntel";
+
+    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+    return "MacSilicon";
+
+    #[cfg(target_os = "linux")]
+    #[allow(clippy::needless_return)]
+    return "Linux";
+}
+
+/// Represents the chip type of a Mac (Intel/Apple Silicon).
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum ChipType {
+    #[serde(rename = "x86_64")]
+    Intel,
+    #[serde(rename = "Apple Silicon")]
+    AppleSilicon,
+}
+
+/// Represents the current Linux graphics platform (X11/Wayland).
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+pub enum GraphicsPlatform {
+    #[serde(rename = "X11")]
+    X11,
+    #[serde(rename = "Wayland")]
+    Wayland,
+    #[serde(rename = "Unknown")]
+    Unknown,
+}
+
+/// Contains information about the current Windows installation.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct WindowsInfo {}
+
+/// Contains information about the current macOS installation.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct MacOSInfo {
+    /// The macOS version, if known.
+    #[serde(rename = "macOSVersion")]
+    macos_version: Option<String>,
+    /// The macOS chip type.
+    chip_type: ChipType,
+    /// The current user's shell, if known.
+    shell: Option<String>,
+}
+
+/// Contains information about the current Linux distribution.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct LinuxInfo {
+    /// An identifier that describes the distribution's release.
+    id: String,
+    /// Identifier of the original upstream OS that this distribution is derived from.
+    id_like: String,
+    /// The name of this release, without the version string.
+    name: String,
+    /// The name of this release, with the version string.
+    pretty_name: String,
+    /// The version of this OS release.
+    version: String,
+    /// The version of this OS release, along with additional details about the release.
+    version_id: String,
+    ///  The codename of this version.
+    version_codename: String,
+    /// The current graphics platform (X11/Wayland).
+    graphics_platform: GraphicsPlatform,
+    /// The current Desktop environment, if known.
+    desktop: Option<String>,
+    /// The current user's shell, if known.
+    shell: Option<String>,
+    /// The bug report URL.
+    bug_report_url: String,
+    /// The support URL.
+    support_url: String,
+    /// The home URL.
+    home_url: String,
+    /// The privacy policy URL.
+    privacy_policy_url: String,
+    /// The documentation URL.
+    documentation_url: String,
+}
+
+/// Contains the information specific to each platform, flattened into [`PlatformInfo`].
+///
+/// The type is defined on every platform so that the generated TypeScript bindings are the same
+/// regardless of where they're generated.
+// Only the current platform's variant is ever built, so its size doesn't matter.
+#[allow(clippy::large_enum_variant)]
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(untagged)]
+pub enum OsInfo {
+    Windows(WindowsInfo),
+    MacOS(MacOSInfo),
+    Linux(LinuxInfo),
+}
+
+// Serde flattens untagged enums of structs into the parent's fields, which specta can't verify
+// at compile-time.
+#[cfg(feature = "specta")]
+impl specta::Flatten for OsInfo {}
+
+/// Contains information of the current platform.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct PlatformInfo {
+    /// The current platform (Windows/macOS/Linux).
+    platform: Platform,
+    /// The hostname of the system.
+    hostname: String,
+    /// The OS' architecture.
+    os_arch: String,
+    /// The current kernel version.
+    kernel: String,
+    /// Information specific to the current platform.
+    #[serde(flatten)]
+    os_info: OsInfo,
+    /// The fields that could not be gathered.
+    warnings: Vec<FieldWarning>,
+}
+
+/// Returns the name of a shell from its path (Eg: `bash` for `/bin/bash`), or `None` if it is
+/// unknown.
+///
+/// # Arguments
+///
+/// * `path` - The path of the shell, as found in `SHELL`.
+/// * `warnings` - Collects the warning if the shell is unknown.
+#[cfg(any(target_os = "macos", target_os = "linux"))]
+fn shell_name(path: Option<String>, warnings: &mut Vec<FieldWarning>) -> Option<String> {
+    match path.as_deref().and_then(|path| path.rsplit('/').next()) {
+        Some(name) if !name.is_empty() => Some(name.into()),
+        _ => {
+            warnings.push(FieldWarning::new(
+                "shell",
+                "warning-env-not-set",
+                &[("variable", "SHELL".into())],
+            ));
+            None
+        }
+    }
+}
+
+#[cfg(target_os = "windows")]
+impl WindowsInfo {
+    /// Retrieves the Windows OS information.
+    pub fn get() -> Result<Self, CoreError> {
+        Ok(Self {})
+    }
+}
+
+#[cfg(target_os = "macos")]
+impl MacOSInfo {
+    /// Retrieves the macOS OS information.
+    ///
+    /// # Arguments
+    ///
+    /// * `warnings` - Collects the fields that could not be gathered.
+    pub fn get(warnings: &mut Vec<FieldWarning>) -> Result<Self, CoreError> {
+        let output = Command::new("sw_vers").arg("-productVersion").output();
+        let macos_version = match output {
+            Ok(output) => Some(String::from_utf8_lossy(&output.stdout).trim().to_string()),
+            Err(e) => {
+                warnings.push(FieldWarning::new(
+                    "macOSVersion",
+                    "warning-command-failed",
+                    &[
+                        ("command", "sw_vers".into()),
+                        ("reason", e.to_string().into()),
+                    ],
+                ));
+                None
+            }
+        };
+
+        #[cfg(target_arch = "x86_64")]
+        let chip_type = ChipType::Intel;
+
+        #[cfg(target_arch = "aarch64")]
+        let chip_type = ChipType::AppleSilicon;
+
+        let shell = shell_name(env::var("SHELL").ok(), warnings);
+
+        Ok(Self {
+            macos_version,
+            chip_type,
+            shell,
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl LinuxInfo {
+    /// Builds the Linux distribution information from an os-release file and the session's
+    /// environment.
+    ///
+    /// # Arguments
+    ///
+    /// * `info` - The parsed os-release file.
+    /// * `var` - Looks up an environment variable of the session.
+    /// * `warnings` - Collects the fields that could not be gathered.
+    fn from_parts<F>(mut info: OsRelease, var: F, warnings: &mut Vec<FieldWarning>) -> Self
+    where
+        F: Fn(&str) -> Option<String>,
+    {
+        // Not set over SSH, on a TTY and under some display managers.
+        let graphics_platform = match var("XDG_SESSION_TYPE").as_deref() {
+            Some("x11") => GraphicsPlatform::X11,
+            Some("wayland") => GraphicsPlatform::Wayland,
+            Some(_) => GraphicsPlatform::Unknown,
+            None => {
+                warnings.push(FieldWarning::new(
+                    "graphicsPlatform",
+                    "warning-env-not-set",
+                    &[("variable", "XDG_SESSION_TYPE".into())],
+                ));
+                GraphicsPlatform::Unknown
+            }
+        };
+        let desktop = var("XDG_CURRENT_DESKTOP").or_else(|| var("DESKTOP_SESSION"));
+        if desktop.is_none() {
+            warnings.push(FieldWarning::new(
+                "desktop",
+                "warning-env-not-set-either",
+                &[
+                    ("first", "XDG_CURRENT_DESKTOP".into()),
+                    ("second", "DESKTOP_SESSION".into()),
+                ],
+            ));
+        }
+        let shell = shell_name(var("SHELL"), warnings);
+
+        Self {
+            id: info.id,
+            id_like: info.id_like,
+            name: info.name,
+            pretty_name: info.pretty_name,
+            version: info.version,
+            version_id: info.version_id,
+            version_codename: info.version_codename,
+            graphics_platform,
+            desktop,
+            shell,
+            bug_report_url: info.bug_report_url,
+            support_url: info.support_url,
+            home_url: info.home_url,
+            privacy_policy_url: info.privacy_policy_url,
+            documentation_url: info
+                .extra
+                .remove("DOCUMENTATION_URL")
+                .unwrap_or(String::with_capacity(0))
+                .replace("\"", ""),
+        }
+    }
+
+    /// Retrieves the Linux distribution information from `/etc/os-release` under the given root,
+    /// and the session's environment: the live process' own, or the one recorded in
+    /// `/proc/self/environ` under a recorded root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    /// * `warnings` - Collects the fields that could not be gathered.
+    pub fn from_sysroot(
+        root: &SysRoot,
+        warnings: &mut Vec<FieldWarning>,
+    ) -> Result<Self, CoreError> {
+        let path = if root.exists("/etc/os-release") {
+            root.path("/etc/os-release")
+        } else {
+            root.path("/usr/lib/os-release")
+        };
+        let info = OsRelease::new_from(path).map_err(|e| CoreError::Error(e.into()))?;
+
+        if root.is_live() {
+            return Ok(Self::from_parts(info, |name| env::var(name).ok(), warnings));
+        }
+
+        Ok(Self::from_parts(info, |name| root.var(name), warnings))
+    }
+}
+
+impl PlatformInfo {
+    /// Retrieves the platform information.
+    #[cfg(not(target_os = "linux"))]
+    pub fn get() -> Result<Self, CoreError> {
+        let hostname = System::host_name().unwrap_or_default();
+        let os_arch = std::env::consts::ARCH.to_string();
+        let kernel = System::kernel_version().unwrap_or(String::with_capacity(0));
+        let platform = match std::env::consts::OS {
+            "windows" => Platform::Windows,
+            "macos" => Platform::MacOS,
+            "linux" => Platform::Linux,
+            _ => Platform::Unknown,
+        };
+
+        let mut warnings = Vec::new();
+
+        #[cfg(target_os = "windows")]
+        let os_info = OsInfo::Windows(WindowsInfo::get()?);
+
+        #[cfg(target_os = "macos")]
+        let os_info = OsInfo::MacOS(MacOSInfo::get(&mut warnings)?);
+
+        Ok(Self {
+            platform,
+            hostname,
+            os_arch,
+            kernel,
+            os_info,
+            warnings,
+        })
+    }
+
+    /// Retrieves the platform information, or that of the recorded system set through
+    /// `REVELIO_SYSROOT` (see [`SysRoot::current`]).
+    #[cfg(target_os = "linux")]
+    pub fn get() -> Result<Self, CoreError> {
+        Self::from_sysroot(&SysRoot::current())
+    }
+
+    /// Retrieves the platform information from the kernel parameters in `/proc/sys/kernel` and
+    /// the distribution information under the given root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    #[cfg(target_os = "linux")]
+    pub fn from_sysroot(root: &SysRoot) -> Result<Self, CoreError> {
+        let mut warnings = Vec::new();
+        let os_info = OsInfo::Linux(LinuxInfo::from_sysroot(root, &mut warnings)?);
+
+        Ok(Self {
+            platform: Platform::Linux,
+            hostname: root
+                .read("/proc/sys/kernel/hostname")
+                .or_else(|| root.read("/etc/hostname"))
+                .unwrap_or_default(),
+            os_arch: root.arch(),
+            kernel: root.read("/proc/sys/kernel/osrelease").unwrap_or_default(),
+            os_info,
+            warnings,
+        })
+    }
+}
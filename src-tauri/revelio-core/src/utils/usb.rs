@@ -0,0 +1,244 @@
+use nusb::Speed;
+use serde::Serialize;
+
+#[cfg(target_os = "linux")]
+use crate::sysroot::SysRoot;
+use crate::types::CoreError;
+
+/// Represents an interface belonging to a USB device.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct USBInterface {
+    pub interface_number: u8,
+    pub class: u8,
+    pub subclass: u8,
+    pub protocol: u8,
+    pub interface_string: Option<String>,
+}
+
+/// Represents a USB device on the system.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct USBDevice {
+    pub index: usize,
+    pub bus_number: u8,
+    pub device_address: u8,
+    pub vendor_id: u16,
+    pub product_id: u16,
+    pub device_version: u16,
+    pub class: u8,
+    pub subclass: u8,
+    pub protocol: u8,
+    pub speed: Option<USBSpeed>,
+    pub manufacturer_string: Option<String>,
+    pub product_string: Option<String>,
+    pub serial_number: Option<String>,
+    pub interfaces: Vec<USBInterface>,
+}
+
+/// The USB speed classes a device can operate at.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub enum USBSpeedClass {
+    /// Low speed (1.5 Mbit/s).
+    Low,
+    /// Full speed (12 Mbit/s).
+    Full,
+    /// High speed (480 Mbit/s).
+    High,
+    /// SuperSpeed (5 Gbit/s).
+    Super,
+    /// SuperSpeed+ (10 Gbit/s or more).
+    SuperPlus,
+    /// A speed class not known to Revelio.
+    Unknown,
+}
+
+/// Represents the speed a USB device operates at.
+#[derive(Debug, Clone, Copy, Serialize)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct USBSpeed {
+    /// The speed class.
+    pub class: USBSpeedClass,
+    /// The signalling rate in Mbit/s, if known.
+    pub mbps: Option<f64>,
+}
+
+impl From<Speed> for USBSpeed {
+    fn from(speed: Speed) -> Self {
+        let (class, mbps) = match speed {
+            Speed::Low => (USBSpeedClass::Low, Some(1.5)),
+            Speed::Full => (USBSpeedClass::Full, Some(12.0)),
+            Speed::High => (USBSpeedClass::High, Some(480.0)),
+            Speed::Super => (USBSpeedClass::Super, Some(5000.0)),
+            Speed::SuperPlus => (USBSpeedClass::SuperPlus, Some(10000.0)),
+            _ => (USBSpeedClass::Unknown, None),
+        };
+
+        Self { class, mbps }
+    }
+}
+
+/// Contains information about the USB devices on the system.
+#[derive(Serialize, Clone)]
+#[cfg_attr(feature = "specta", derive(specta::Type))]
+#[cfg_attr(feature = "schema", derive(schemars::JsonSchema))]
+#[serde(rename_all = "camelCase")]
+pub struct USBInfo {
+    total_devices: usize,
+    devices: Vec<USBDevice>,
+}
+
+#[cfg(not(target_os = "linux"))]
+impl USBInfo {
+    /// Retrieves information about the USB devices detected on the system.
+    pub fn get() -> Result<Self, CoreError> {
+        let devices_iter =
+            nusb::list_devices().map_err(|e| CoreError::USBInfoError(e.to_string().into()))?;
+        let mut total_devices = 0;
+        let (lower, upper) = devices_iter.size_hint();
+        let mut devices: Vec<USBDevice> = Vec::with_capacity(upper.unwrap_or(lower));
+
+        for (idx, device) in devices_iter.enumerate() {
+            let interfaces_iter = device.interfaces();
+            let (lower, upper) = interfaces_iter.size_hint();
+            let mut interfaces = Vec::with_capacity(upper.unwrap_or(lower));
+
+            for itf in interfaces_iter {
+                interfaces.push(USBInterface {
+                    interface_number: itf.interface_number(),
+                    class: itf.class(),
+                    subclass: itf.subclass(),
+                    protocol: itf.protocol(),
+                    interface_string: itf.interface_string().map(str::to_string),
+                })
+            }
+
+            devices.push(USBDevice {
+                index: idx,
+                bus_number: device.bus_number(),
+                device_address: device.device_address(),
+                vendor_id: device.vendor_id(),
+                product_id: device.product_id(),
+                device_version: device.device_version(),
+                class: device.class(),
+                subclass: device.subclass(),
+                protocol: device.protocol(),
+                speed: device.speed().map(USBSpeed::from),
+                manufacturer_string: device.manufacturer_string().map(str::to_string),
+                product_string: device.product_string().map(str::to_string),
+                serial_number: device.serial_number().map(str::to_string),
+                interfaces,
+            });
+            total_devices += 1;
+        }
+
+        Ok(USBInfo {
+            total_devices,
+            devices,
+        })
+    }
+}
+
+#[cfg(target_os = "linux")]
+impl USBInfo {
+    /// Retrieves information about the USB devices detected on the system, or on the recorded
+    /// system set through `REVELIO_SYSROOT` (see [`SysRoot::current`]).
+    pub fn get() -> Result<Self, CoreError> {
+        Self::from_sysroot(&SysRoot::current())
+    }
+
+    /// Parses the speed of a device, as reported by sysfs in Mbit/s.
+    ///
+    /// # Arguments
+    ///
+    /// * `speed` - The `speed` attribute of the device.
+    fn parse_speed(speed: &str) -> Option<USBSpeed> {
+        let mbps: f64 = speed.parse().ok()?;
+        let class = match speed {
+            "1.5" => USBSpeedClass::Low,
+            "12" => USBSpeedClass::Full,
+            "480" => USBSpeedClass::High,
+            "5000" => USBSpeedClass::Super,
+            "10000" | "20000" => USBSpeedClass::SuperPlus,
+            _ => USBSpeedClass::Unknown,
+        };
+
+        Some(USBSpeed {
+            class,
+            mbps: Some(mbps),
+        })
+    }
+
+    /// Retrieves information about the USB devices from `/sys/bus/usb/devices` under the given
+    /// root.
+    ///
+    /// # Arguments
+    ///
+    /// * `root` - The file system root.
+    pub fn from_sysroot(root: &SysRoot) -> Result<Self, CoreError> {
+        if !root.exists("/sys/bus/usb/devices") {
+            return Err(CoreError::USBInfoError(
+                "/sys/bus/usb/devices does not exist".into(),
+            ));
+        }
+
+        let entries = root.list("/sys/bus/usb/devices");
+        let mut devices = Vec::new();
+
+        // Interfaces are listed alongside devices, as `<device>:<configuration>.<interface>`.
+        for (idx, (name, dir)) in entries.iter().filter(|(n, _)| !n.contains(':')).enumerate() {
+            let hex = |attr: &str| root.read_hex(dir.join(attr)).unwrap_or(0);
+            let interfaces = entries
+                .iter()
+                .filter(|(n, _)| {
+                    n.split_once(':')
+                        .is_some_and(|(device, _)| device == name.as_str())
+                })
+                .map(|(_, itf_dir)| {
+                    let hex = |attr: &str| root.read_hex(itf_dir.join(attr)).unwrap_or(0) as u8;
+
+                    USBInterface {
+                        interface_number: hex("bInterfaceNumber"),
+                        class: hex("bInterfaceClass"),
+                        subclass: hex("bInterfaceSubClass"),
+                        protocol: hex("bInterfaceProtocol"),
+                        interface_string: root.read(itf_dir.join("interface")),
+                    }
+                })
+                .collect();
+
+            devices.push(USBDevice {
+                index: idx,
+                bus_number: root.read_parsed(dir.join("busnum")).unwrap_or(0),
+                device_address: root.read_parsed(dir.join("devnum")).unwrap_or(0),
+                vendor_id: hex("idVendor") as u16,
+                product_id: hex("idProduct") as u16,
+                device_version: hex("bcdDevice") as u16,
+                class: hex("bDeviceClass") as u8,
+                subclass: hex("bDeviceSubClass") as u8,
+                protocol: hex("bDeviceProtocol") as u8,
+                speed: root
+                    .read(dir.join("speed"))
+                    .and_then(|speed| Self::parse_speed(&speed)),
+                manufacturer_string: root.read(dir.join("manufacturer")),
+                product_string: root.read(dir.join("product")),
+                serial_number: root.read(dir.join("serial")),
+                interfaces,
+            });
+        }
+
+        Ok(Self {
+            total_devices: devices.len(),
+            devices,
+        })
+    }
+}
@@ -0,0 +1,85 @@
+//! Snapshot tests of the Linux collectors, run against the recorded machines in `tests/fixtures`.
+//! On Linux, `get()` reads `/` through the same parsers, so these cover the live collectors too;
+//! what only a live system has (e.g. the available space of mounted disks) is checked in `live.rs`.
+//!
+//! After an intended change to a collector's output, review and accept the new snapshots with
+//! `cargo insta review`.
+#![cfg(target_os = "linux")]
+
+use std::path::Path;
+
+use revelio_core::i18n::{self, DEFAULT_LOCALE};
+use revelio_core::sysroot::SysRoot;
+use revelio_core::types::CoreError;
+use revelio_core::utils::cpu::CpuInfo;
+use revelio_core::utils::disks::DisksInfo;
+use revelio_core::utils::network::NetworksInfo;
+use revelio_core::utils::platform::PlatformInfo;
+use revelio_core::utils::usb::USBInfo;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// The recorded machines, one directory each under `tests/fixtures`.
+const MACHINES: &[&str] = &["alpine-container", "fedora-server", "ubuntu-desktop"];
+
+/// Returns the root of a recorded machine. The warnings of its sections are resolved in the
+/// default locale, whatever the locale of the system running the tests.
+fn sysroot(machine: &str) -> SysRoot {
+    i18n::set_locale(DEFAULT_LOCALE);
+    SysRoot::new(
+        Path::new(env!("CARGO_MANIFEST_DIR"))
+            .join("tests/fixtures")
+            .join(machine),
+    )
+}
+
+/// Serializes a collector's result, recording errors as `{ "error": <message> }`.
+fn to_value<T>(result: Result<T, CoreError>) -> Value
+where
+    T: Serialize,
+{
+    match result {
+        Ok(info) => serde_json::to_value(info).unwrap(),
+        Err(e) => json!({ "error": e.to_string() }),
+    }
+}
+
+#[test]
+fn platform() {
+    for machine in MACHINES {
+        let info = to_value(PlatformInfo::from_sysroot(&sysroot(machine)));
+        insta::assert_json_snapshot!(format!("{machine}__platform"), info);
+    }
+}
+
+#[test]
+fn cpu() {
+    for machine in MACHINES {
+        let info = to_value(CpuInfo::from_sysroot(&sysroot(machine)));
+        insta::assert_json_snapshot!(format!("{machine}__cpu"), info);
+    }
+}
+
+#[test]
+fn disks() {
+    for machine in MACHINES {
+        let info = to_value(Ok(DisksInfo::from_sysroot(&sysroot(machine))));
+        insta::assert_json_snapshot!(format!("{machine}__disks"), info);
+    }
+}
+
+#[test]
+fn network() {
+    for machine in MACHINES {
+        let info = to_value(Ok(NetworksInfo::from_sysroot(&sysroot(machine))));
+        insta::assert_json_snapshot!(format!("{machine}__network"), info);
+    }
+}
+
+#[test]
+fn usb() {
+    for machine in MACHINES {
+        let info = to_value(USBInfo::from_sysroot(&sysroot(machine)));
+        insta::assert_json_snapshot!(format!("{machine}__usb"), info);
+    }
+}
@@ -0,0 +1,82 @@
+//! Checks the bundled message catalogs and how locales are picked.
+
+use std::fs;
+use std::path::Path;
+
+use revelio_core::i18n::{self, DEFAULT_LOCALE};
+use revelio_core::types::FieldWarning;
+
+/// Returns the message keys defined in a bundled catalog, in order.
+fn keys(locale: &str) -> Vec<String> {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("locales")
+        .join(format!("{locale}.ftl"));
+
+    fs::read_to_string(path)
+        .unwrap()
+        .lines()
+        .filter_map(|line| line.split_once(" = "))
+        .map(|(key, _)| key.to_string())
+        .collect()
+}
+
+#[test]
+fn every_catalog_defines_every_message() {
+    let expected = keys(DEFAULT_LOCALE);
+
+    for locale in i18n::locales() {
+        assert_eq!(keys(locale), expected, "{locale}");
+
+        for key in &expected {
+            let message = i18n::translate(locale, key, &[]);
+            assert_ne!(&message, key, "{locale}: {key} does not resolve");
+        }
+    }
+}
+
+#[test]
+fn locales_are_negotiated_by_language() {
+    assert_eq!(i18n::negotiate("de"), "de");
+    assert_eq!(i18n::negotiate("de-AT"), "de");
+    assert_eq!(i18n::negotiate("ja_JP.UTF-8"), "ja");
+    assert_eq!(i18n::negotiate("fr-FR"), DEFAULT_LOCALE);
+    assert_eq!(i18n::negotiate("C"), DEFAULT_LOCALE);
+}
+
+#[test]
+fn messages_are_resolved_with_arguments() {
+    let args = [("speed", "High speed".into()), ("mbps", 480.0.into())];
+    assert_eq!(
+        i18n::translate("en", "usb-speed-rate", &args),
+        "High speed (480 Mbit/s)"
+    );
+    assert_eq!(
+        i18n::translate("de", "value-unknown-code", &[("code", (-1).into())]),
+        "Unbekannt (-1)"
+    );
+}
+
+#[test]
+fn missing_messages_fall_back() {
+    assert_eq!(
+        i18n::translate("xx", "value-unknown", &[]),
+        i18n::translate(DEFAULT_LOCALE, "value-unknown", &[])
+    );
+    assert_eq!(
+        i18n::translate("ja", "no-such-message", &[]),
+        "no-such-message"
+    );
+}
+
+#[test]
+fn warnings_are_resolved_in_the_current_locale() {
+    i18n::set_locale("de");
+    let warning = FieldWarning::new(
+        "shell",
+        "warning-env-not-set",
+        &[("variable", "SHELL".into())],
+    );
+
+    assert_eq!(warning.key, "warning-env-not-set");
+    assert_eq!(warning.reason, "SHELL ist nicht gesetzt");
+}
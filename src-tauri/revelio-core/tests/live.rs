@@ -0,0 +1,33 @@
+//! Checks the collectors against the live system. On Linux, they read `/` through the same parsers
+//! as the recorded machines of the snapshot tests, so these only check what can't be recorded.
+#![cfg(target_os = "linux")]
+
+use revelio_core::utils::disks::DisksInfo;
+use revelio_core::utils::network::NetworksInfo;
+use serde_json::Value;
+
+#[test]
+fn loopback_networks_are_listed() {
+    let info = serde_json::to_value(NetworksInfo::get()).unwrap();
+    let lo = info["interfaces"]
+        .as_array()
+        .unwrap()
+        .iter()
+        .find(|itf| itf["itfName"] == "lo")
+        .expect("no loopback interface");
+
+    assert!(lo["ipNetworks"]
+        .as_array()
+        .unwrap()
+        .contains(&Value::from("127.0.0.1/8")));
+}
+
+#[test]
+fn available_space_is_read_from_mounted_disks() {
+    let disks = DisksInfo::get().disks;
+    for disk in &disks {
+        assert!(disk.available_space <= disk.total_space, "{disk:?}");
+    }
+    // A recorded tree reports no available space, a mounted disk usually has some.
+    assert!(disks.is_empty() || disks.iter().any(|disk| disk.available_space > 0));
+}
@@ -0,0 +1,59 @@
+//! Checks the sampling of the values of the running system that change over time.
+
+use std::thread;
+
+use revelio_core::monitor::{MetricsSample, Monitor, MINIMUM_INTERVAL};
+
+/// Returns the names of the cores, interfaces and disks of a sample, in order.
+///
+/// # Arguments
+///
+/// * `sample` - The sample.
+fn devices(sample: &MetricsSample) -> (Vec<&str>, Vec<&str>, Vec<&str>) {
+    (
+        sample.cores.iter().map(|core| core.name.as_str()).collect(),
+        sample
+            .interfaces
+            .iter()
+            .map(|itf| itf.itf_name.as_str())
+            .collect(),
+        sample.disks.iter().map(|disk| disk.name.as_str()).collect(),
+    )
+}
+
+#[test]
+fn consecutive_samples_refresh_the_same_devices() {
+    let mut monitor = Monitor::new();
+    let first = monitor.sample();
+    thread::sleep(MINIMUM_INTERVAL);
+    let second = monitor.sample();
+
+    assert!(second.sampled_at > first.sampled_at);
+
+    // The devices listed when the monitor was created are refreshed rather than listed again.
+    assert_eq!(devices(&first), devices(&second));
+
+    assert!(!second.cores.is_empty());
+    for core in &second.cores {
+        assert!(!core.name.is_empty());
+        assert!((0.0..=100.0).contains(&core.usage), "{core:?}");
+    }
+
+    assert!(second.memory.used_memory <= second.memory.total_memory);
+
+    let mut names: Vec<_> = second.interfaces.iter().map(|itf| &itf.itf_name).collect();
+    assert!(names.windows(2).all(|pair| pair[0] < pair[1]));
+    names.dedup();
+    assert_eq!(names.len(), second.interfaces.len());
+    for (old, new) in first.interfaces.iter().zip(&second.interfaces) {
+        assert!(new.received_bytes >= old.received_bytes, "{new:?}");
+        assert!(new.transmitted_bytes >= old.transmitted_bytes, "{new:?}");
+        assert!(new.received_rate >= 0.0 && new.transmitted_rate >= 0.0);
+    }
+
+    // Disk I/O is compared with the counts kept from the previous sample.
+    for disk in &second.disks {
+        assert_eq!(disk.read_rate.is_some(), disk.read_bytes.is_some());
+        assert_eq!(disk.write_rate.is_some(), disk.written_bytes.is_some());
+    }
+}
@@ -0,0 +1,44 @@
+//! Checks that the Linux collectors read the root set through `REVELIO_SYSROOT`.
+//!
+//! Setting the variable affects every test running in the process, so this test has a binary of
+//! its own.
+#![cfg(target_os = "linux")]
+
+use std::env;
+use std::path::Path;
+
+use revelio_core::sysroot::{SysRoot, SYSROOT_ENV};
+use revelio_core::types::CoreError;
+use revelio_core::utils::cpu::CpuInfo;
+use revelio_core::utils::usb::USBInfo;
+use serde::Serialize;
+use serde_json::{json, Value};
+
+/// Serializes a collector's result, recording errors as `{ "error": <message> }`.
+fn to_value<T>(result: Result<T, CoreError>) -> Value
+where
+    T: Serialize,
+{
+    match result {
+        Ok(info) => serde_json::to_value(info).unwrap(),
+        Err(e) => json!({ "error": e.to_string() }),
+    }
+}
+
+#[test]
+fn get_reads_sysroot_from_env() {
+    let root =
+        SysRoot::new(Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fixtures/ubuntu-desktop"));
+    env::set_var(SYSROOT_ENV, root.path("/"));
+
+    assert_eq!(
+        to_value(CpuInfo::get()),
+        to_value(CpuInfo::from_sysroot(&root))
+    );
+    assert_eq!(
+        to_value(USBInfo::get()),
+        to_value(USBInfo::from_sysroot(&root))
+    );
+
+    env::remove_var(SYSROOT_ENV);
+}
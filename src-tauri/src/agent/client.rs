@@ -0,0 +1,118 @@
+use std::time::Duration;
+
+use reqwest::Client;
+use serde::de::DeserializeOwned;
+use serde_json::Value;
+
+use super::{AgentInfo, API_PREFIX};
+use crate::report::SystemReport;
+use crate::state::SectionSnapshot;
+use crate::types::CoreError;
+
+/// How long a request to an agent may take. Generous, since the agent may have to gather a slow
+/// section (Eg: in a helper process) before it can respond.
+const TIMEOUT: Duration = Duration::from_secs(60);
+
+/// Client of the API of an agent (see [`super::server::AgentServer`]), used to inspect another
+/// host from the desktop app.
+#[derive(Clone)]
+pub struct AgentClient {
+    /// The base URL of the agent, without a trailing slash (Eg: `http://10.0.0.2:7878`).
+    url: String,
+    /// The bearer token sent with every request, if any.
+    token: Option<String>,
+    /// The HTTP client.
+    http: Client,
+}
+
+impl AgentClient {
+    /// Creates a client for an agent. No request is made until the agent is queried.
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The base URL of the agent (Eg: `http://10.0.0.2:7878`).
+    /// * `token` - The bearer token the agent requires, if any.
+    pub fn new(url: &str, token: Option<String>) -> Result<Self, CoreError> {
+        let url = url.trim().trim_end_matches('/');
+        if !url.starts_with("http://") && !url.starts_with("https://") {
+            return Err(CoreError::Error(
+                format!("Not an HTTP or HTTPS URL: {url}").into(),
+            ));
+        }
+
+        let http = Client::builder()
+            .timeout(TIMEOUT)
+            .build()
+            .map_err(|e| CoreError::Error(e.into()))?;
+
+        Ok(Self {
+            url: url.to_string(),
+            token: token.filter(|token| !token.is_empty()),
+            http,
+        })
+    }
+
+    /// Returns the base URL of the agent.
+    pub fn url(&self) -> &str {
+        &self.url
+    }
+
+    /// Requests a path of the agent's API and deserializes the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path, relative to the API prefix (Eg: `usb?refresh=true`).
+    async fn get<T: DeserializeOwned>(&self, path: &str) -> Result<T, CoreError> {
+        let url = format!("{}{API_PREFIX}/{path}", self.url);
+        let error = |message: String| CoreError::Error(format!("{url}: {message}").into());
+
+        let mut request = self.http.get(&url);
+        if let Some(token) = &self.token {
+            request = request.bearer_auth(token);
+        }
+
+        let response = request.send().await.map_err(|e| error(e.to_string()))?;
+        let status = response.status();
+        let body = response.text().await.map_err(|e| error(e.to_string()))?;
+
+        if !status.is_success() {
+            // Failed requests carry the serialized `CoreError`, whose message is already localized.
+            let message = serde_json::from_str::<Value>(&body)
+                .ok()
+                .and_then(|value| Some(value.get("message")?.as_str()?.to_string()))
+                .unwrap_or_else(|| status.to_string());
+            return Err(error(message));
+        }
+
+        serde_json::from_str(&body).map_err(|e| error(e.to_string()))
+    }
+
+    /// Describes the agent and the system it runs on.
+    pub async fn info(&self) -> Result<AgentInfo, CoreError> {
+        self.get("agent").await
+    }
+
+    /// Returns the snapshot of a section of the agent's system.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section name.
+    /// * `refresh` - Whether the agent should gather the section again instead of serving it from
+    ///   its cache.
+    pub async fn section(
+        &self,
+        section: &str,
+        refresh: bool,
+    ) -> Result<SectionSnapshot, CoreError> {
+        if refresh {
+            self.get(&format!("{section}?refresh=true")).await
+        } else {
+            self.get(section).await
+        }
+    }
+
+    /// Returns a report of every section available on the agent's system.
+    pub async fn report(&self) -> Result<SystemReport, CoreError> {
+        self.get("report").await
+    }
+}
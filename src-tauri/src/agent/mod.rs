@@ -0,0 +1,41 @@
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::collectors::REGISTRY;
+use crate::utils::platform::os_type;
+
+pub mod client;
+pub mod server;
+
+/// The prefix of every path of the agent's API. Bumped whenever a change to the API could break
+/// its consumers.
+pub const API_PREFIX: &str = "/v1";
+
+/// The path Prometheus scrapes the agent's metrics from.
+pub const METRICS_PATH: &str = "/metrics";
+
+/// The address the agent listens on unless another one is given.
+pub const DEFAULT_BIND: &str = "127.0.0.1:7878";
+
+/// Describes an agent and the system it runs on. Served at `/v1/agent`.
+#[derive(Debug, Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct AgentInfo {
+    /// The version of Revelio the agent runs.
+    pub app_version: String,
+    /// The type of the agent's operating system (Eg: `Linux`).
+    pub os_type: String,
+    /// The sections enabled on the agent's platform.
+    pub sections: Vec<String>,
+}
+
+impl AgentInfo {
+    /// Describes the current system.
+    pub fn current() -> Self {
+        Self {
+            app_version: env!("CARGO_PKG_VERSION").into(),
+            os_type: os_type().into(),
+            sections: REGISTRY.enabled().map(|c| c.name().to_string()).collect(),
+        }
+    }
+}
@@ -0,0 +1,315 @@
+use std::net::SocketAddr;
+use std::sync::Arc;
+use std::thread;
+
+use serde::Serialize;
+use sha2::{Digest, Sha256};
+use subtle::ConstantTimeEq;
+use tiny_http::{Header, Method, Request, Response, Server};
+
+use super::{AgentInfo, API_PREFIX, METRICS_PATH};
+use crate::collectors::REGISTRY;
+use crate::config;
+use crate::metrics;
+use crate::redact::Redactor;
+use crate::report::SystemReport;
+use crate::state::{SectionCache, SectionSnapshot};
+use crate::types::CoreError;
+
+/// The number of requests that are handled concurrently. Each section has its own lock, so a slow
+/// section only holds up the workers waiting for it.
+const WORKERS: usize = 8;
+
+/// A response of the agent's API.
+struct Reply {
+    /// The HTTP status code.
+    status: u16,
+    /// The content type of the body.
+    content_type: &'static str,
+    /// The body.
+    body: String,
+}
+
+impl Reply {
+    /// Creates a response with a JSON body.
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The HTTP status code.
+    /// * `value` - The value to serialize as the body.
+    fn json<T: Serialize>(status: u16, value: &T) -> Self {
+        match serde_json::to_string(value) {
+            Ok(body) => Self {
+                status,
+                content_type: "application/json",
+                body,
+            },
+            Err(e) => Self::error(500, &CoreError::Error(e.into())),
+        }
+    }
+
+    /// Creates a response describing an error, with the error serialized as the frontend sees it
+    /// (`{ "kind": ..., "message": ... }`).
+    ///
+    /// # Arguments
+    ///
+    /// * `status` - The HTTP status code.
+    /// * `error` - The error.
+    fn error(status: u16, error: &CoreError) -> Self {
+        Self {
+            status,
+            content_type: "application/json",
+            body: serde_json::to_string(error).unwrap_or_default(),
+        }
+    }
+}
+
+/// Returns the HTTP status code of a failed request for a section.
+///
+/// # Arguments
+///
+/// * `error` - The error the request failed with.
+fn status_of(error: &CoreError) -> u16 {
+    match error {
+        CoreError::UnknownSection(_)
+        | CoreError::UnsupportedSection(_)
+        | CoreError::DisabledSection(_) => 404,
+        _ => 500,
+    }
+}
+
+/// What the workers of an agent share.
+struct AgentState {
+    /// The cache the sections are served from.
+    cache: SectionCache,
+    /// The bearer token requests must carry, if any.
+    token: Option<String>,
+    /// The redactor applied to everything that is served, if any.
+    redactor: Option<Redactor>,
+}
+
+impl AgentState {
+    /// Checks the `Authorization` header of a request against the agent's token.
+    ///
+    /// # Arguments
+    ///
+    /// * `authorization` - The value of the request's `Authorization` header, if any.
+    fn is_authorized(&self, authorization: Option<&str>) -> bool {
+        let Some(token) = &self.token else {
+            return true;
+        };
+        let Some(given) = authorization.and_then(|value| value.strip_prefix("Bearer ")) else {
+            return false;
+        };
+
+        // Digests have the same length whatever the tokens', and are compared in constant time so
+        // that the time taken doesn't depend on how much of the token matched.
+        Sha256::digest(given.as_bytes())
+            .ct_eq(&Sha256::digest(token.as_bytes()))
+            .into()
+    }
+
+    /// Returns the snapshot of a section, redacted if redaction is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section name.
+    /// * `refresh` - Whether to gather the section again instead of serving it from the cache.
+    fn section(&self, section: &str, refresh: bool) -> Result<SectionSnapshot, CoreError> {
+        let collector = REGISTRY.get(section)?;
+        let mut snapshot = if refresh {
+            self.cache.refresh(collector)
+        } else {
+            self.cache.get_or_collect(collector)
+        }?;
+
+        if let Some(redactor) = &self.redactor {
+            redactor.redact_snapshot(section, &mut snapshot);
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Returns a report of every section enabled on the agent's platform.
+    ///
+    /// # Arguments
+    ///
+    /// * `refresh` - Whether to gather the sections again instead of serving them from the cache.
+    fn report(&self, refresh: bool) -> SystemReport {
+        SystemReport::from_sections(
+            REGISTRY
+                .enabled()
+                .map(|c| (c.name(), self.section(c.name(), refresh))),
+        )
+    }
+
+    /// Returns a report of the enabled sections metrics are derived from, gathering the volatile
+    /// ones again (see [`metrics::VOLATILE_SECTIONS`]).
+    fn metrics_report(&self) -> SystemReport {
+        let config = config::current();
+        let sections = metrics::sections()
+            .into_iter()
+            .filter(|section| config.is_enabled(section));
+
+        SystemReport::from_sections(sections.map(|section| {
+            let refresh = metrics::VOLATILE_SECTIONS.contains(&section);
+            (section, self.section(section, refresh))
+        }))
+    }
+
+    /// Handles a request of the agent's API.
+    ///
+    /// # Arguments
+    ///
+    /// * `method` - The request method.
+    /// * `url` - The request path, along with its query string.
+    /// * `authorization` - The value of the request's `Authorization` header, if any.
+    fn handle(&self, method: &Method, url: &str, authorization: Option<&str>) -> Reply {
+        if !self.is_authorized(authorization) {
+            return Reply::error(
+                401,
+                &CoreError::Error("Missing or invalid bearer token".into()),
+            );
+        }
+        if *method != Method::Get {
+            return Reply::error(
+                405,
+                &CoreError::Error("Only GET requests are supported".into()),
+            );
+        }
+
+        let (path, query) = url.split_once('?').unwrap_or((url, ""));
+        let refresh = query
+            .split('&')
+            .any(|param| matches!(param, "refresh" | "refresh=true" | "refresh=1"));
+        if path == METRICS_PATH {
+            return Reply {
+                status: 200,
+                content_type: metrics::CONTENT_TYPE,
+                body: metrics::render(&self.metrics_report()),
+            };
+        }
+        let Some(route) = path
+            .strip_prefix(API_PREFIX)
+            .and_then(|route| route.strip_prefix('/'))
+        else {
+            return Reply::error(
+                404,
+                &CoreError::Error(format!("No such path: {path}").into()),
+            );
+        };
+
+        match route {
+            "agent" => Reply::json(200, &AgentInfo::current()),
+            "report" => Reply::json(200, &self.report(refresh)),
+            "diagnostics" => Reply::json(200, &self.cache.timings()),
+            section => match self.section(section, refresh) {
+                Ok(snapshot) => Reply::json(200, &snapshot),
+                Err(e) => Reply::error(status_of(&e), &e),
+            },
+        }
+    }
+
+    /// Handles a request and sends the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `request` - The request.
+    fn respond(&self, request: Request) {
+        let authorization = request
+            .headers()
+            .iter()
+            .find(|header| header.field.equiv("Authorization"))
+            .map(|header| header.value.as_str().to_string());
+        let reply = self.handle(request.method(), request.url(), authorization.as_deref());
+
+        let response = Response::from_string(reply.body)
+            .with_status_code(reply.status)
+            .with_header(
+                Header::from_bytes(&b"Content-Type"[..], reply.content_type.as_bytes())
+                    .expect("valid header"),
+            );
+        let response = if reply.status == 401 {
+            response.with_header(
+                Header::from_bytes(&b"WWW-Authenticate"[..], &b"Bearer"[..]).expect("valid header"),
+            )
+        } else {
+            response
+        };
+
+        // The client may have gone away, in which case there is nobody left to tell.
+        let _ = request.respond(response);
+    }
+}
+
+/// Serves the information of the current system as a JSON API over HTTP:
+///
+/// - `/v1/agent` describes the agent (see [`AgentInfo`]).
+/// - `/v1/<section>` returns the snapshot of a section (Eg: `/v1/usb`).
+/// - `/v1/report` returns a report of every section.
+/// - `/v1/diagnostics` returns the timing of the last request for each section.
+/// - `/metrics` exposes metrics derived from the sections for Prometheus (see [`metrics::render`]).
+///
+/// Sections are served from a [`SectionCache`]; adding `?refresh=true` gathers them again.
+/// Errors are returned with a 4xx or 5xx status and the error serialized as a `CoreError`.
+pub struct AgentServer {
+    /// The HTTP server.
+    server: Server,
+    /// What the workers share.
+    state: AgentState,
+}
+
+impl AgentServer {
+    /// Binds an agent to an address.
+    ///
+    /// # Arguments
+    ///
+    /// * `addr` - The address to listen on (Eg: `127.0.0.1:7878`).
+    /// * `cache` - The cache to serve the sections from.
+    /// * `token` - The bearer token requests must carry, or `None` to accept every request.
+    /// * `redactor` - The redactor to apply to everything that is served, if any.
+    pub fn bind(
+        addr: &str,
+        cache: SectionCache,
+        token: Option<String>,
+        redactor: Option<Redactor>,
+    ) -> Result<Self, CoreError> {
+        let server = Server::http(addr).map_err(CoreError::Error)?;
+
+        Ok(Self {
+            server,
+            state: AgentState {
+                cache,
+                token,
+                redactor,
+            },
+        })
+    }
+
+    /// Returns the address the agent listens on.
+    pub fn local_addr(&self) -> Option<SocketAddr> {
+        self.server.server_addr().to_ip()
+    }
+
+    /// Serves requests until the process exits.
+    pub fn run(self) {
+        let server = Arc::new(self.server);
+        let state = Arc::new(self.state);
+
+        let workers: Vec<_> = (0..WORKERS)
+            .map(|_| {
+                let server = Arc::clone(&server);
+                let state = Arc::clone(&state);
+                thread::spawn(move || {
+                    for request in server.incoming_requests() {
+                        state.respond(request);
+                    }
+                })
+            })
+            .collect();
+
+        for worker in workers {
+            let _ = worker.join();
+        }
+    }
+}
@@ -0,0 +1,173 @@
+use serde_json::{Map, Value};
+
+use crate::collectors::REGISTRY;
+use crate::config::AlertRule;
+use crate::fleet::{self, Query};
+use crate::i18n;
+use crate::types::CoreError;
+
+/// The name the changing values sampled while monitoring are queried under, as if they were a
+/// section (Eg: `metrics.memory.usedSwap > 0`).
+pub const METRICS: &str = "metrics";
+
+/// An alert that started or stopped firing.
+#[derive(Debug, Clone, PartialEq)]
+pub struct AlertEvent {
+    /// The name of the alert's rule.
+    pub name: String,
+    /// The body of the alert's notification.
+    pub message: String,
+    /// Whether the alert started firing, rather than stopped.
+    pub firing: bool,
+}
+
+/// A parsed alert rule, along with whether it is firing.
+struct Rule {
+    /// The name of the rule.
+    name: String,
+    /// The query the information is checked against.
+    query: Query,
+    /// Whether the alert fires while the query doesn't match, rather than while it does.
+    negated: bool,
+    /// The body of the alert's notification.
+    message: String,
+    /// The sections the query is about, without duplicates.
+    sections: Vec<String>,
+    /// Whether the alert is firing.
+    firing: bool,
+}
+
+impl Rule {
+    /// Parses an alert rule, checking that it has exactly one query and that its query is about
+    /// known sections.
+    ///
+    /// # Arguments
+    ///
+    /// * `rule` - The rule, as configured.
+    fn parse(rule: &AlertRule) -> Result<Self, CoreError> {
+        let invalid = |reason: &str| {
+            CoreError::Error(format!("Invalid alert `{}`: {reason}", rule.name).into())
+        };
+
+        let (query, negated) = match (&rule.when, &rule.unless) {
+            (Some(when), None) => (when, false),
+            (None, Some(unless)) => (unless, true),
+            _ => return Err(invalid("expected exactly one of `when` and `unless`")),
+        };
+        let parsed = Query::parse(query).map_err(|e| invalid(&e.reason()))?;
+
+        let mut sections: Vec<String> = Vec::new();
+        for section in parsed.sections() {
+            if section != METRICS && !REGISTRY.iter().any(|c| c.name() == section) {
+                return Err(CoreError::UnknownSection(section.into()));
+            }
+            if !sections.iter().any(|s| s == section) {
+                sections.push(section.to_string());
+            }
+        }
+        if sections.is_empty() {
+            return Err(invalid("empty query"));
+        }
+
+        let message = rule.message.clone().unwrap_or_else(|| {
+            let key = if negated {
+                "alert-unmatched"
+            } else {
+                "alert-matched"
+            };
+            i18n::tr_args(key, &[("query", query.as_str().into())])
+        });
+
+        Ok(Self {
+            name: rule.name.clone(),
+            query: parsed,
+            negated,
+            message,
+            sections,
+            firing: false,
+        })
+    }
+}
+
+/// Evaluates alert rules against the latest information of each section.
+///
+/// A rule is evaluated whenever a section its query is about is updated, once every section it
+/// is about has been gathered, so that a rule about a device going missing doesn't fire before
+/// the devices are known. Only the changes of an alert are reported: it fires once when its rule
+/// starts holding, and is resolved once it stops.
+pub struct Alerts {
+    rules: Vec<Rule>,
+    /// The latest information of each section, with its computed fields (Eg:
+    /// `availablePercent`), keyed by section name.
+    info: Value,
+}
+
+impl Default for Alerts {
+    fn default() -> Self {
+        Self {
+            rules: Vec::new(),
+            info: Value::Object(Map::new()),
+        }
+    }
+}
+
+impl Alerts {
+    /// Parses alert rules.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The rules, as configured.
+    pub fn new(rules: &[AlertRule]) -> Result<Self, CoreError> {
+        let mut alerts = Self::default();
+        alerts.set_rules(rules)?;
+
+        Ok(alerts)
+    }
+
+    /// Replaces the alert rules, keeping the latest information of each section. None of the new
+    /// rules are firing until they are next evaluated.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The rules, as configured.
+    pub fn set_rules(&mut self, rules: &[AlertRule]) -> Result<(), CoreError> {
+        self.rules = rules.iter().map(Rule::parse).collect::<Result<_, _>>()?;
+
+        Ok(())
+    }
+
+    /// Updates the information of a section and evaluates the rules about it, returning the
+    /// alerts that started or stopped firing.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section name, or [`METRICS`] for a sample of the changing values.
+    /// * `data` - The section's information.
+    pub fn update(&mut self, section: &str, data: &Value) -> Vec<AlertEvent> {
+        let mut data = data.clone();
+        fleet::derive_fields(section, &mut data);
+        if let Some(info) = self.info.as_object_mut() {
+            info.insert(section.to_string(), data);
+        }
+
+        let info = &self.info;
+        self.rules
+            .iter_mut()
+            .filter(|rule| rule.sections.iter().any(|s| s == section))
+            .filter(|rule| rule.sections.iter().all(|s| info.get(s).is_some()))
+            .filter_map(|rule| {
+                let firing = rule.query.matches(info) != rule.negated;
+                if firing == rule.firing {
+                    return None;
+                }
+                rule.firing = firing;
+
+                Some(AlertEvent {
+                    name: rule.name.clone(),
+                    message: rule.message.clone(),
+                    firing,
+                })
+            })
+            .collect()
+    }
+}
@@ -0,0 +1,3 @@
+fn main() {
+    std::process::exit(revelio_lib::cli::run())
+}
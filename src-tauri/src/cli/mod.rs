@@ -0,0 +1,847 @@
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::thread;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use clap::builder::PossibleValuesParser;
+use clap::{Parser, Subcommand};
+use serde_json::Value;
+
+use crate::agent::server::AgentServer;
+use crate::agent::DEFAULT_BIND;
+use crate::collectors::{Collector, REGISTRY};
+use crate::config;
+use crate::diff::{ChangeKind, ReportDiff};
+use crate::export::{self, ExportFormat};
+use crate::fleet::{Fleet, Query};
+use crate::history::{self, History};
+use crate::i18n;
+use crate::inventory::{self, InventoryFile};
+use crate::monitor::{Monitor, MINIMUM_INTERVAL};
+use crate::probe;
+use crate::redact::{self, Redactor};
+use crate::report::SystemReport;
+use crate::schema;
+use crate::state::{SectionCache, SectionSnapshot, SectionTiming};
+use crate::types::CoreError;
+
+mod table;
+
+/// Headless interface to the Revelio collectors.
+#[derive(Parser)]
+#[command(name = "revelio", version, about)]
+struct Cli {
+    /// Print the output as JSON instead of human-readable tables.
+    #[arg(long, global = true)]
+    json: bool,
+
+    /// Print how long each section took to be gathered to stderr.
+    #[arg(long, global = true)]
+    timings: bool,
+
+    /// How long each section may take to be gathered, in seconds. Defaults to each section's
+    /// configured or own timeout.
+    #[arg(long, global = true, value_name = "SECS")]
+    timeout: Option<u64>,
+
+    /// Hash values that identify the machine or its devices (hostname, serial numbers, MAC and
+    /// IP addresses, ...) with a salt unique to this run. Implied if redaction is enabled in the
+    /// configuration.
+    #[arg(long, global = true)]
+    redact: bool,
+
+    /// Redact values with the rules in a JSON or YAML file instead of the configured or default
+    /// ones.
+    #[arg(long, global = true, value_name = "PATH")]
+    redact_rules: Option<PathBuf>,
+
+    /// The language of labels and error messages (Eg: `de`, `ja`). Defaults to the system's.
+    #[arg(long, global = true, value_name = "LOCALE")]
+    locale: Option<String>,
+
+    #[command(subcommand)]
+    command: Command,
+}
+
+#[derive(Subcommand)]
+enum Command {
+    /// Show CPU information.
+    Cpu,
+    /// Show disk(s) information.
+    Disks,
+    /// Show information about the connected displays.
+    Displays,
+    /// Show network(s) information.
+    Network,
+    /// Show information about the connected USB devices.
+    Usb,
+    /// Show platform information.
+    Platform,
+    /// Show Vulkan information.
+    Vulkan,
+    /// Show OpenGL information.
+    #[command(name = "opengl")]
+    OpenGL,
+    /// Show DirectX information (Windows only).
+    #[command(name = "directx")]
+    DirectX,
+    /// Show Metal information (macOS only).
+    Metal,
+    /// Show every section enabled on the current platform.
+    All {
+        /// Write the report to a file instead of printing it.
+        #[arg(long, value_name = "PATH")]
+        export: Option<PathBuf>,
+
+        /// The format of the exported report. Inferred from the file extension if omitted, or
+        /// taken from the configuration.
+        #[arg(long, value_enum, requires = "export")]
+        format: Option<ExportFormat>,
+    },
+    /// Compare two saved reports. Exits with 0 if they are the same, 1 if they differ and 2 on
+    /// errors.
+    Diff {
+        /// The old report (JSON or YAML).
+        old: PathBuf,
+
+        /// The new report (JSON or YAML). The current system is used if omitted.
+        new: Option<PathBuf>,
+    },
+    /// Search a directory of saved reports, one per machine. Exits with 0 if any host matches, 1
+    /// if none do and 2 on errors.
+    Fleet {
+        /// The directory of JSON or YAML reports.
+        dir: PathBuf,
+
+        /// The conditions hosts must match, joined by `and` (Eg:
+        /// `platform.graphicsPlatform = Wayland`, `disks.disks[].usedPercent > 90`). Every host
+        /// is listed if omitted.
+        query: Option<String>,
+    },
+    /// Print the JSON Schema of a section's information or of a complete report.
+    Schema {
+        /// The schema to print. Every schema is written if omitted.
+        #[arg(value_parser = PossibleValuesParser::new(schema::names()), required_unless_present = "out_dir")]
+        name: Option<String>,
+
+        /// Write the schemas to `<name>.schema.json` files in a directory instead of printing them.
+        #[arg(long, value_name = "DIR")]
+        out_dir: Option<PathBuf>,
+    },
+    /// Serve the information of this system as a JSON API over HTTP (Eg: `/v1/cpu`, `/v1/report`),
+    /// for dashboards or another Revelio window to inspect it.
+    Serve {
+        /// The address to listen on.
+        #[arg(long, value_name = "ADDR", default_value = DEFAULT_BIND)]
+        bind: String,
+
+        /// Require requests to carry this bearer token.
+        #[arg(
+            long,
+            value_name = "TOKEN",
+            env = "REVELIO_AGENT_TOKEN",
+            hide_env_values = true
+        )]
+        token: Option<String>,
+    },
+    /// Record the CPU load, memory usage, network traffic and disk space and I/O of this system
+    /// to the metrics history until the process exits (see the `[history]` configuration).
+    Record,
+    /// Print the values of a metric recorded in the metrics history. Exits with 0 if any values
+    /// were recorded in the time range, 1 if none were and 2 on errors.
+    History {
+        /// The metric to print. The recorded metrics are listed if omitted.
+        #[arg(value_parser = PossibleValuesParser::new(history::names()))]
+        metric: Option<String>,
+
+        /// Only print the values of one subject (Eg: a mount point or network interface).
+        #[arg(long, value_name = "SUBJECT")]
+        subject: Option<String>,
+
+        /// The start of the time range: a UTC time (Eg: `2026-10-17 22:00:00`) or how long ago
+        /// (Eg: `12h`, `3days`).
+        #[arg(long, value_name = "TIME", default_value = "24h")]
+        since: String,
+
+        /// The end of the time range, in the same format. Defaults to now.
+        #[arg(long, value_name = "TIME")]
+        until: Option<String>,
+    },
+    /// Compare the USB devices, disks, displays, Vulkan devices, CPU, kernel and distribution of
+    /// this system with those seen by the last run, then save them for the next one. Exits with 0
+    /// if nothing changed, 1 if anything did and 2 on errors.
+    Inventory,
+}
+
+impl Command {
+    /// Returns the name of the section in the collector registry, or `None` if the command
+    /// doesn't print a single section.
+    fn section(&self) -> Option<&'static str> {
+        match self {
+            Self::Cpu => Some("cpu"),
+            Self::Disks => Some("disks"),
+            Self::Displays => Some("displays"),
+            Self::Network => Some("network"),
+            Self::Usb => Some("usb"),
+            Self::Platform => Some("platform"),
+            Self::Vulkan => Some("vulkan"),
+            Self::OpenGL => Some("opengl"),
+            Self::DirectX => Some("directx"),
+            Self::Metal => Some("metal"),
+            Self::All { .. }
+            | Self::Diff { .. }
+            | Self::Fleet { .. }
+            | Self::Schema { .. }
+            | Self::Serve { .. }
+            | Self::Record
+            | Self::History { .. }
+            | Self::Inventory => None,
+        }
+    }
+}
+
+/// The exit code of a command that succeeded.
+const EXIT_SUCCESS: i32 = 0;
+/// The exit code of a command that ran but whose outcome is negative (Eg: the reports differ, no
+/// host matches, some sections couldn't be gathered).
+const EXIT_FAILURE: i32 = 1;
+/// The exit code of a command that couldn't run (Eg: a report couldn't be loaded).
+const EXIT_ERROR: i32 = 2;
+
+/// The options shared by every command, along with what is recorded while running it.
+struct Session {
+    /// Whether to print the output as JSON.
+    json: bool,
+    /// How long each section may take to be gathered, or `None` for their defaults.
+    timeout: Option<Duration>,
+    /// The redactor applied to the gathered information, if any.
+    redactor: Option<Redactor>,
+    /// How long each gathered section took.
+    timings: Vec<SectionTiming>,
+}
+
+impl Session {
+    /// Creates a session from the parsed command-line arguments, after loading the configuration
+    /// file (see [`config::path`]).
+    ///
+    /// # Arguments
+    ///
+    /// * `cli` - The parsed command-line arguments.
+    fn new(cli: &Cli) -> Result<Self, CoreError> {
+        let config = config::load()?;
+        let rules = match &cli.redact_rules {
+            Some(path) => Some(redact::load_rules(path)?),
+            None if cli.redact || config.redaction.enabled => Some(config.redaction_rules()?),
+            None => None,
+        };
+        config::set(config);
+
+        Ok(Self {
+            json: cli.json,
+            timeout: cli.timeout.map(Duration::from_secs),
+            redactor: rules.map(Redactor::new).transpose()?,
+            timings: Vec::new(),
+        })
+    }
+
+    /// Gathers a single section, recording how long it took and redacting it.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The collector of the section.
+    fn collect_section(
+        &mut self,
+        collector: &'static dyn Collector,
+    ) -> Result<SectionSnapshot, CoreError> {
+        let timeout = self
+            .timeout
+            .unwrap_or_else(|| config::current().timeout(collector));
+        let (result, timing) = SectionSnapshot::collect_timed(collector, timeout);
+        self.timings.push(timing);
+
+        let mut snapshot = result?;
+        if let Some(redactor) = &self.redactor {
+            redactor.redact_snapshot(collector.name(), &mut snapshot);
+        }
+
+        Ok(snapshot)
+    }
+
+    /// Gathers every section enabled on the current platform, recording how long each took and
+    /// redacting the report.
+    fn collect_report(&mut self) -> SystemReport {
+        let (mut report, timings) = SystemReport::collect_timed(self.timeout);
+        self.timings.extend(timings);
+        self.redact(&mut report);
+
+        report
+    }
+
+    /// Redacts a report, if redaction is enabled.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - The system report.
+    fn redact(&self, report: &mut SystemReport) {
+        if let Some(redactor) = &self.redactor {
+            redactor.redact_report(report);
+        }
+    }
+}
+
+/// Prints a single section to stdout.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `collector` - The collector of the section to print.
+fn print_section(
+    session: &mut Session,
+    collector: &'static dyn Collector,
+) -> Result<(), CoreError> {
+    let value = session.collect_section(collector)?.data;
+
+    if session.json {
+        let output =
+            serde_json::to_string_pretty(&value).map_err(|e| CoreError::Error(e.into()))?;
+        println!("{output}");
+    } else {
+        print!(
+            "{}",
+            table::render(
+                collector.name(),
+                &i18n::section_title(collector.name()),
+                &value
+            )
+        );
+    }
+
+    Ok(())
+}
+
+/// Prints a report of every section enabled on the current platform to stdout, continuing past
+/// sections that fail.
+///
+/// Returns `true` if all sections were gathered successfully.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+fn print_all(session: &mut Session) -> bool {
+    let report = session.collect_report();
+    let ok = report.sections.values().all(|s| s.error.is_none());
+
+    if session.json {
+        return match serde_json::to_string_pretty(&report) {
+            Ok(output) => {
+                println!("{output}");
+                ok
+            }
+            Err(e) => {
+                eprintln!("{e}");
+                false
+            }
+        };
+    }
+
+    for collector in REGISTRY.enabled() {
+        let Some(section) = report.sections.get(collector.name()) else {
+            continue;
+        };
+
+        let title = i18n::section_title(collector.name());
+        if let Some(value) = &section.data {
+            println!("{}", table::render(collector.name(), &title, value));
+        }
+        if let Some(e) = &section.error {
+            eprintln!("{title}: {}", e.message);
+        }
+    }
+
+    ok
+}
+
+/// Gathers a report of every section enabled on the current platform and writes it to a file.
+///
+/// Returns `true` if all sections were gathered successfully.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `path` - The path of the file to write.
+/// * `format` - The export format, or `None` to infer it from the file extension or the
+///   configuration.
+fn export_report(
+    session: &mut Session,
+    path: &Path,
+    format: Option<ExportFormat>,
+) -> Result<bool, CoreError> {
+    let format = format
+        .or_else(|| ExportFormat::from_path(path))
+        .or(config::current().export.format)
+        .unwrap_or(ExportFormat::Json);
+    let report = session.collect_report();
+    export::export(&report, format, path)?;
+
+    for section in report.sections.values() {
+        if let Some(e) = &section.error {
+            eprintln!("{}", e.message);
+        }
+    }
+
+    Ok(report.sections.values().all(|s| s.error.is_none()))
+}
+
+/// Formats a value of a report diff for display.
+///
+/// # Arguments
+///
+/// * `value` - The value, if any.
+fn format_diff_value(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(v) => v.to_string(),
+        None => String::new(),
+    }
+}
+
+/// Compares two reports and prints the differences to stdout. When redacting, both reports are
+/// redacted with the same salt, so that devices are still matched between them.
+///
+/// Returns `true` if the reports are the same.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `old` - The path of the old report.
+/// * `new` - The path of the new report, or `None` to compare against the current system.
+fn print_diff(session: &mut Session, old: &Path, new: Option<&Path>) -> Result<bool, CoreError> {
+    let mut old = SystemReport::load(old)?;
+    session.redact(&mut old);
+    let new = match new {
+        Some(path) => {
+            let mut new = SystemReport::load(path)?;
+            session.redact(&mut new);
+            new
+        }
+        None => session.collect_report(),
+    };
+    let diff = ReportDiff::new(&old, &new);
+
+    write_diff(session, &diff)?;
+
+    Ok(diff.is_empty())
+}
+
+/// Prints the differences between two reports to stdout.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `diff` - The differences.
+fn write_diff(session: &Session, diff: &ReportDiff) -> Result<(), CoreError> {
+    if session.json {
+        let output = serde_json::to_string_pretty(diff).map_err(|e| CoreError::Error(e.into()))?;
+        println!("{output}");
+        return Ok(());
+    }
+
+    for (name, section) in &diff.sections {
+        println!("{}", i18n::section_title(name));
+
+        if let Some(reason) = &section.skipped {
+            println!("  ! {reason}");
+        }
+        for change in &section.changes {
+            let path = if change.path.is_empty() {
+                "(section)"
+            } else {
+                change.path.as_str()
+            };
+
+            match change.kind {
+                ChangeKind::Added => {
+                    println!("  + {path}: {}", format_diff_value(change.new.as_ref()))
+                }
+                ChangeKind::Removed => {
+                    println!("  - {path}: {}", format_diff_value(change.old.as_ref()))
+                }
+                ChangeKind::Changed => println!(
+                    "  ~ {path}: {} -> {}",
+                    format_diff_value(change.old.as_ref()),
+                    format_diff_value(change.new.as_ref())
+                ),
+            }
+        }
+        println!();
+    }
+
+    Ok(())
+}
+
+/// Compares the hardware inventory of the current system with the one saved by the last run and
+/// prints the differences to stdout, then saves it for the next run. The inventory is never
+/// redacted, as redacted values differ from one run to the next.
+///
+/// Returns `true` if nothing changed since the last run, or if no inventory was saved yet.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+fn print_inventory(session: &mut Session) -> Result<bool, CoreError> {
+    let path =
+        inventory::path().ok_or_else(|| CoreError::Error("No data directory was found".into()))?;
+    let mut file = InventoryFile::open(&path)?;
+
+    let sections = REGISTRY
+        .enabled()
+        .filter(|collector| inventory::sections().any(|section| section == collector.name()))
+        .map(|collector| {
+            let timeout = session
+                .timeout
+                .unwrap_or_else(|| config::current().timeout(collector));
+            let (result, timing) = SectionSnapshot::collect_timed(collector, timeout);
+            session.timings.push(timing);
+            (collector.name(), result)
+        })
+        .collect::<Vec<_>>();
+    let report = SystemReport::from_sections(sections);
+
+    let Some(diff) = file.check(&report)? else {
+        eprintln!(
+            "No inventory was saved by a previous run, saved the current one to {}",
+            path.display()
+        );
+        return Ok(true);
+    };
+
+    write_diff(session, &diff)?;
+    if diff.is_empty() && !session.json {
+        eprintln!("Nothing changed since the last run");
+    }
+
+    Ok(diff.is_empty())
+}
+
+/// Loads a directory of reports and prints the hosts matching a query to stdout. Reports that
+/// can't be loaded are reported on stderr.
+///
+/// Returns `true` if any host matches.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `dir` - The directory of reports.
+/// * `query` - The query (see [`Query`]).
+fn print_fleet(session: &Session, dir: &Path, query: &str) -> Result<bool, CoreError> {
+    let query = Query::parse(query)?;
+    let fleet = Fleet::load(dir)?;
+    for skipped in fleet.skipped() {
+        eprintln!("{}: {}", skipped.path.display(), skipped.reason);
+    }
+
+    let hosts = fleet.query(&query);
+    if session.json {
+        let output =
+            serde_json::to_string_pretty(&hosts).map_err(|e| CoreError::Error(e.into()))?;
+        println!("{output}");
+    } else {
+        print!("{}", table::render_hosts(&hosts));
+    }
+
+    Ok(!hosts.is_empty())
+}
+
+/// Prints a JSON Schema to stdout, or writes one or all schemas to a directory.
+///
+/// # Arguments
+///
+/// * `name` - The name of the schema, or `None` for every schema.
+/// * `out_dir` - The directory to write `<name>.schema.json` files to, or `None` to print the
+///   schema.
+fn write_schemas(name: Option<&str>, out_dir: Option<&Path>) -> Result<(), CoreError> {
+    let names: Vec<&str> = match name {
+        Some(name) => vec![name],
+        None => schema::names().collect(),
+    };
+
+    if let Some(dir) = out_dir {
+        fs::create_dir_all(dir).map_err(|e| CoreError::Error(e.into()))?;
+    }
+
+    for name in names {
+        let output = serde_json::to_string_pretty(&schema::schema(name)?)
+            .map_err(|e| CoreError::Error(e.into()))?;
+
+        match out_dir {
+            Some(dir) => {
+                let path = dir.join(format!("{name}.schema.json"));
+                fs::write(&path, output + "\n").map_err(|e| CoreError::Error(e.into()))?;
+                eprintln!("{}", path.display());
+            }
+            None => println!("{output}"),
+        }
+    }
+
+    Ok(())
+}
+
+/// Prints how long each section took to be gathered to stderr.
+///
+/// # Arguments
+///
+/// * `timings` - The timings of the gathered sections.
+/// * `json` - Whether to print the timings as JSON.
+fn print_timings(timings: &[SectionTiming], json: bool) {
+    if json {
+        match serde_json::to_string_pretty(timings) {
+            Ok(output) => eprintln!("{output}"),
+            Err(e) => eprintln!("{e}"),
+        }
+    } else {
+        eprint!("{}", table::render_timings(timings));
+    }
+}
+
+/// Serves the information of the current system over HTTP until the process exits.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `bind` - The address to listen on (Eg: `127.0.0.1:7878`).
+/// * `token` - The bearer token requests must carry, if any.
+fn serve(session: &mut Session, bind: &str, token: Option<String>) -> Result<(), CoreError> {
+    let cache = SectionCache::default();
+    config::current().apply(&cache);
+    if session.timeout.is_some() {
+        for collector in REGISTRY.iter() {
+            cache.set_timeout(collector, session.timeout);
+        }
+    }
+
+    let has_token = token.is_some();
+    let server = AgentServer::bind(bind, cache, token, session.redactor.take())?;
+    match server.local_addr() {
+        Some(addr) => {
+            // The agent only speaks plain HTTP, so a token is sent in cleartext as well.
+            if !addr.ip().is_loopback() {
+                if has_token {
+                    eprintln!(
+                        "warning: {addr} is reachable from other hosts and the token is sent \
+                         unencrypted; serve it behind a TLS proxy or an SSH tunnel"
+                    );
+                } else {
+                    eprintln!(
+                        "warning: {addr} is reachable from other hosts and no token is required"
+                    );
+                }
+            }
+            eprintln!("Serving on http://{addr}");
+        }
+        None => eprintln!("Serving on {bind}"),
+    }
+
+    server.run();
+
+    Ok(())
+}
+
+/// How often the metrics history is downsampled while recording.
+const DOWNSAMPLE_INTERVAL: Duration = Duration::from_secs(60);
+
+/// Records the changing values of the current system to the metrics history until the process
+/// exits, downsampling it as it goes. Samples that can't be written are reported on stderr and
+/// skipped.
+fn record() -> Result<(), CoreError> {
+    let config = config::current();
+    let path = config
+        .history_path()
+        .ok_or_else(|| CoreError::Error("No data directory was found".into()))?;
+    let mut history = History::open(&path)?;
+    let interval = Duration::from_secs(config.history.interval).max(MINIMUM_INTERVAL);
+    eprintln!("Recording to {}", path.display());
+
+    let mut monitor = Monitor::new();
+    let mut downsampled: Option<Instant> = None;
+    loop {
+        thread::sleep(interval);
+        let sample = monitor.sample();
+        if let Err(e) = history.record(&sample) {
+            eprintln!("{}", e.localized());
+        }
+
+        if downsampled.map_or(true, |at| at.elapsed() >= DOWNSAMPLE_INTERVAL) {
+            if let Err(e) = history.downsample(&config.history, sample.sampled_at) {
+                eprintln!("{}", e.localized());
+            }
+            downsampled = Some(Instant::now());
+        }
+    }
+}
+
+/// Parses a time given on the command line, in milliseconds since the Unix epoch.
+///
+/// # Arguments
+///
+/// * `value` - A UTC time (Eg: `2026-10-17 22:00:00`) or how long ago (Eg: `12h`).
+fn parse_time(value: &str) -> Result<u64, CoreError> {
+    let time = match humantime::parse_duration(value) {
+        Ok(ago) => SystemTime::now().checked_sub(ago).unwrap_or(UNIX_EPOCH),
+        Err(_) => humantime::parse_rfc3339_weak(value)
+            .map_err(|e| CoreError::Error(format!("{value}: {e}").into()))?,
+    };
+
+    Ok(time
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default())
+}
+
+/// Prints the values of a metric recorded in the metrics history to stdout, or the recorded
+/// metrics if no metric is given.
+///
+/// Returns `true` if any values were recorded in the time range.
+///
+/// # Arguments
+///
+/// * `session` - The command-line session.
+/// * `metric` - The name of the metric (Eg: `disk.used`), if any.
+/// * `subject` - The only subject to print the values of (Eg: `/home`), if any.
+/// * `since` - The start of the time range (see [`parse_time`]).
+/// * `until` - The end of the time range, or `None` for now.
+fn print_history(
+    session: &Session,
+    metric: Option<&str>,
+    subject: Option<&str>,
+    since: &str,
+    until: Option<&str>,
+) -> Result<bool, CoreError> {
+    let Some(metric) = metric else {
+        let metrics = history::metrics();
+        if session.json {
+            let output =
+                serde_json::to_string_pretty(&metrics).map_err(|e| CoreError::Error(e.into()))?;
+            println!("{output}");
+        } else {
+            print!("{}", table::render_metrics(&metrics));
+        }
+
+        return Ok(true);
+    };
+
+    let from = parse_time(since)?;
+    let to = until.map(parse_time).transpose()?.unwrap_or(u64::MAX);
+    let path = config::current()
+        .history_path()
+        .ok_or_else(|| CoreError::Error("No data directory was found".into()))?;
+    let series = History::open_read_only(&path)?.query(metric, subject, from, to)?;
+
+    if session.json {
+        let output =
+            serde_json::to_string_pretty(&series).map_err(|e| CoreError::Error(e.into()))?;
+        println!("{output}");
+    } else {
+        print!("{}", table::render_series(&series));
+    }
+
+    Ok(!series.is_empty())
+}
+
+/// Checks whether the process was started with command-line arguments (Eg: `revelio cpu`), in
+/// which case the desktop app runs the command-line interface instead of opening its window. The
+/// process serial number macOS passes to apps opened from the Finder isn't counted.
+pub fn requested() -> bool {
+    env::args_os()
+        .skip(1)
+        .any(|arg| !arg.to_string_lossy().starts_with("-psn_"))
+}
+
+/// Runs the command-line interface and returns the process exit code.
+pub fn run() -> i32 {
+    if let Some(section) = probe::requested_section() {
+        return probe::run(&section);
+    }
+
+    let cli = Cli::parse();
+    if let Some(locale) = &cli.locale {
+        i18n::set_locale(locale);
+    }
+    let mut session = match Session::new(&cli) {
+        Ok(session) => session,
+        Err(e) => {
+            eprintln!("{}", e.localized());
+            return EXIT_ERROR;
+        }
+    };
+    let code = run_command(&cli.command, &mut session);
+
+    if cli.timings {
+        print_timings(&session.timings, session.json);
+    }
+
+    code
+}
+
+/// Runs a parsed command and returns the process exit code.
+///
+/// # Arguments
+///
+/// * `command` - The parsed command.
+/// * `session` - The command-line session.
+fn run_command(command: &Command, session: &mut Session) -> i32 {
+    let result = match command {
+        Command::Cpu
+        | Command::Disks
+        | Command::Displays
+        | Command::Network
+        | Command::Usb
+        | Command::Platform
+        | Command::Vulkan
+        | Command::OpenGL
+        | Command::DirectX
+        | Command::Metal => {
+            let section = command.section().expect("section commands name a section");
+            REGISTRY
+                .get(section)
+                .and_then(|collector| print_section(session, collector))
+                .map(|()| true)
+        }
+        Command::All {
+            export: Some(path),
+            format,
+        } => export_report(session, path, *format),
+        Command::All { export: None, .. } => Ok(print_all(session)),
+        Command::Diff { old, new } => print_diff(session, old, new.as_deref()),
+        Command::Fleet { dir, query } => {
+            print_fleet(session, dir, query.as_deref().unwrap_or_default())
+        }
+        Command::Schema { name, out_dir } => {
+            write_schemas(name.as_deref(), out_dir.as_deref()).map(|()| true)
+        }
+        Command::Serve { bind, token } => serve(session, bind, token.clone()).map(|()| true),
+        Command::Record => record().map(|()| true),
+        Command::History {
+            metric,
+            subject,
+            since,
+            until,
+        } => print_history(
+            session,
+            metric.as_deref(),
+            subject.as_deref(),
+            since,
+            until.as_deref(),
+        ),
+        Command::Inventory => print_inventory(session),
+    };
+
+    match result {
+        Ok(true) => EXIT_SUCCESS,
+        Ok(false) => EXIT_FAILURE,
+        Err(e) => {
+            eprintln!("{}", e.localized());
+            EXIT_ERROR
+        }
+    }
+}
@@ -0,0 +1,169 @@
+use std::fmt::Write;
+use std::time::{Duration, UNIX_EPOCH};
+
+use serde_json::Value;
+
+use crate::export::tables::{self, Table};
+use crate::fleet::FleetHost;
+use crate::history::{HistoryMetric, MetricSeries};
+use crate::i18n;
+use crate::state::SectionTiming;
+
+/// Writes a bordered two-column table.
+///
+/// # Arguments
+///
+/// * `out` - The output buffer.
+/// * `table` - The table to write.
+fn write_table(out: &mut String, table: &Table) {
+    let key_width = table
+        .rows
+        .iter()
+        .map(|(k, _)| k.chars().count())
+        .max()
+        .unwrap_or(0);
+    let value_width = table
+        .rows
+        .iter()
+        .map(|(_, v)| v.chars().count())
+        .max()
+        .unwrap_or(0);
+    let border = format!(
+        "+-{}-+-{}-+",
+        "-".repeat(key_width),
+        "-".repeat(value_width)
+    );
+
+    let _ = writeln!(out, "{}", table.title);
+    let _ = writeln!(out, "{border}");
+    for (key, value) in &table.rows {
+        let _ = writeln!(out, "| {key:<key_width$} | {value:<value_width$} |");
+    }
+    let _ = writeln!(out, "{border}");
+    let _ = writeln!(out);
+}
+
+/// Renders a section's JSON value as human-readable tables.
+///
+/// # Arguments
+///
+/// * `section` - The section name.
+/// * `title` - The section title.
+/// * `value` - The section's JSON value.
+pub fn render(section: &str, title: &str, value: &Value) -> String {
+    let mut out = String::new();
+    for table in tables::flatten(section, title, value) {
+        write_table(&mut out, &table);
+    }
+
+    out
+}
+
+/// Renders how long each section took to be gathered as a human-readable table.
+///
+/// # Arguments
+///
+/// * `timings` - The timings of the gathered sections.
+pub fn render_timings(timings: &[SectionTiming]) -> String {
+    let rows = timings
+        .iter()
+        .map(|timing| {
+            let mut value = format!("{:.1} ms", timing.duration_ms);
+            if let Some(e) = &timing.error {
+                let _ = write!(value, " ({})", e.message);
+            }
+
+            (i18n::section_title(timing.section), value)
+        })
+        .collect();
+
+    let mut out = String::new();
+    write_table(
+        &mut out,
+        &Table {
+            title: i18n::tr("report-timings"),
+            rows,
+        },
+    );
+
+    out
+}
+
+/// Renders the hosts of a fleet as a human-readable table of their hostnames and report files.
+///
+/// # Arguments
+///
+/// * `hosts` - The hosts.
+pub fn render_hosts(hosts: &[FleetHost]) -> String {
+    let rows = hosts
+        .iter()
+        .map(|host| (host.hostname.clone(), host.path.display().to_string()))
+        .collect();
+
+    let mut out = String::new();
+    write_table(
+        &mut out,
+        &Table {
+            title: i18n::tr_args("fleet-hosts", &[("count", hosts.len().into())]),
+            rows,
+        },
+    );
+
+    out
+}
+
+/// Renders the metrics recorded in the history as a human-readable table of their names and
+/// units.
+///
+/// # Arguments
+///
+/// * `metrics` - The metrics.
+pub fn render_metrics(metrics: &[HistoryMetric]) -> String {
+    let rows = metrics
+        .iter()
+        .map(|metric| (metric.name.clone(), metric.unit.clone()))
+        .collect();
+
+    let mut out = String::new();
+    write_table(
+        &mut out,
+        &Table {
+            title: i18n::tr("history-metrics"),
+            rows,
+        },
+    );
+
+    out
+}
+
+/// Renders recorded series of a metric as human-readable tables of their times and values, one
+/// per subject.
+///
+/// # Arguments
+///
+/// * `series` - The series.
+pub fn render_series(series: &[MetricSeries]) -> String {
+    let mut out = String::new();
+    for series in series {
+        let title = if series.subject.is_empty() {
+            format!("{} ({})", series.metric, series.unit)
+        } else {
+            format!("{} {} ({})", series.metric, series.subject, series.unit)
+        };
+        let rows = series
+            .points
+            .iter()
+            .map(|point| {
+                let time = UNIX_EPOCH + Duration::from_millis(point.time);
+                (
+                    humantime::format_rfc3339_seconds(time).to_string(),
+                    format!("{:.1}", point.value),
+                )
+            })
+            .collect();
+
+        write_table(&mut out, &Table { title, rows });
+    }
+
+    out
+}
@@ -0,0 +1,468 @@
+use std::sync::mpsc::{self, RecvTimeoutError};
+use std::sync::LazyLock;
+use std::thread;
+use std::time::Duration;
+
+use schemars::{Schema, SchemaGenerator};
+use serde::Serialize;
+use serde_json::Value;
+
+use crate::config;
+use crate::probe::{self, PROBE_TIMEOUT};
+use crate::types::CoreError;
+use crate::utils::cpu::CpuInfo;
+use crate::utils::disks::DisksInfo;
+use crate::utils::displays::DisplaysInfo;
+use crate::utils::gpu::directx::DirectXInfo;
+use crate::utils::gpu::metal::MetalInfo;
+use crate::utils::gpu::opengl::OpenGLInfo;
+use crate::utils::gpu::vulkan::VulkanInfo;
+use crate::utils::network::NetworksInfo;
+use crate::utils::platform::PlatformInfo;
+use crate::utils::usb::USBInfo;
+
+/// How long a section may take to be gathered by default.
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(10);
+
+/// A source of system information (a "section") that can be gathered on demand.
+pub trait Collector: Send + Sync {
+    /// The unique name of the section (Eg: `cpu`, `usb`).
+    fn name(&self) -> &'static str;
+
+    /// The human-readable title of the section.
+    fn title(&self) -> &'static str;
+
+    /// Checks whether the section can be gathered on the current platform.
+    fn is_available(&self) -> bool {
+        true
+    }
+
+    /// Checks whether the section must be gathered on the main thread (Eg: when it creates a
+    /// windowing event loop).
+    fn requires_main_thread(&self) -> bool {
+        false
+    }
+
+    /// Checks whether the section must be gathered in a helper process, because gathering it can
+    /// crash or hang the process (Eg: in a graphics driver).
+    fn is_isolated(&self) -> bool {
+        false
+    }
+
+    /// How long the section may take to be gathered by default.
+    fn timeout(&self) -> Duration {
+        DEFAULT_TIMEOUT
+    }
+
+    /// Creates the error reported when the section cannot be gathered.
+    ///
+    /// # Arguments
+    ///
+    /// * `reason` - Why the section cannot be gathered.
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::Error(reason.into())
+    }
+
+    /// Gathers the section's information from the system, in the current process.
+    ///
+    /// Use [`collect`] instead to respect [`Collector::is_isolated`].
+    fn collect(&self) -> Result<Value, CoreError>;
+
+    /// Returns the JSON Schema of the section's information, registering the types it refers to
+    /// with the generator.
+    ///
+    /// # Arguments
+    ///
+    /// * `generator` - The generator to register the section's types with.
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema;
+}
+
+/// Gathers a section's information from the system, giving up once the timeout has elapsed.
+///
+/// Isolated sections are gathered in a helper process, which is killed on timeout. Other sections
+/// are gathered on a separate thread, which is abandoned on timeout since it can't be stopped;
+/// sections that must be gathered on the main thread are gathered without a timeout.
+///
+/// # Arguments
+///
+/// * `collector` - The collector of the section.
+/// * `timeout` - How long the section may take to be gathered.
+pub fn collect(collector: &'static dyn Collector, timeout: Duration) -> Result<Value, CoreError> {
+    if collector.is_isolated() {
+        return probe::collect(collector, timeout);
+    }
+
+    if collector.requires_main_thread() {
+        return collector.collect();
+    }
+
+    let (tx, rx) = mpsc::channel();
+    thread::Builder::new()
+        .name(format!("collector-{}", collector.name()))
+        .spawn(move || {
+            let _ = tx.send(collector.collect());
+        })
+        .map_err(|e| collector.error(e.to_string()))?;
+
+    match rx.recv_timeout(timeout) {
+        Ok(result) => result,
+        Err(RecvTimeoutError::Timeout) => {
+            Err(collector.error(format!("the collector did not finish within {timeout:?}")))
+        }
+        Err(RecvTimeoutError::Disconnected) => {
+            Err(collector.error("the collector panicked".into()))
+        }
+    }
+}
+
+/// Serializes the information of a section into a JSON value.
+///
+/// # Arguments
+///
+/// * `info` - The section information.
+#[inline]
+fn to_value<T>(info: T) -> Result<Value, CoreError>
+where
+    T: Serialize,
+{
+    serde_json::to_value(info).map_err(|e| CoreError::Error(e.into()))
+}
+
+pub struct PlatformCollector;
+
+impl Collector for PlatformCollector {
+    fn name(&self) -> &'static str {
+        "platform"
+    }
+
+    fn title(&self) -> &'static str {
+        "Platform"
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(PlatformInfo::get()?)
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<PlatformInfo>()
+    }
+}
+
+pub struct CpuCollector;
+
+impl Collector for CpuCollector {
+    fn name(&self) -> &'static str {
+        "cpu"
+    }
+
+    fn title(&self) -> &'static str {
+        "CPU"
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::CPUInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(CpuInfo::get()?)
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<CpuInfo>()
+    }
+}
+
+pub struct VulkanCollector;
+
+impl Collector for VulkanCollector {
+    fn name(&self) -> &'static str {
+        "vulkan"
+    }
+
+    fn title(&self) -> &'static str {
+        "Vulkan"
+    }
+
+    fn is_available(&self) -> bool {
+        cfg!(not(all(target_os = "macos", target_arch = "aarch64")))
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::VulkanInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+        #[allow(clippy::needless_return)]
+        return to_value(VulkanInfo::get()?);
+
+        #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
+        Err(CoreError::UnsupportedSection(self.name().into()))
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<VulkanInfo>()
+    }
+}
+
+pub struct OpenGLCollector;
+
+impl Collector for OpenGLCollector {
+    fn name(&self) -> &'static str {
+        "opengl"
+    }
+
+    fn title(&self) -> &'static str {
+        "OpenGL"
+    }
+
+    fn requires_main_thread(&self) -> bool {
+        true
+    }
+
+    fn is_isolated(&self) -> bool {
+        true
+    }
+
+    fn timeout(&self) -> Duration {
+        PROBE_TIMEOUT
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::OpenGLInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(OpenGLInfo::get()?)
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<OpenGLInfo>()
+    }
+}
+
+pub struct DirectXCollector;
+
+impl Collector for DirectXCollector {
+    fn name(&self) -> &'static str {
+        "directx"
+    }
+
+    fn title(&self) -> &'static str {
+        "DirectX"
+    }
+
+    fn is_available(&self) -> bool {
+        cfg!(target_os = "windows")
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::DirectXInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        #[cfg(target_os = "windows")]
+        #[allow(clippy::needless_return)]
+        return to_value(DirectXInfo::get()?);
+
+        #[cfg(not(target_os = "windows"))]
+        Err(CoreError::UnsupportedSection(self.name().into()))
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<DirectXInfo>()
+    }
+}
+
+pub struct MetalCollector;
+
+impl Collector for MetalCollector {
+    fn name(&self) -> &'static str {
+        "metal"
+    }
+
+    fn title(&self) -> &'static str {
+        "Metal"
+    }
+
+    fn is_available(&self) -> bool {
+        cfg!(target_os = "macos")
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::MetalInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        #[cfg(target_os = "macos")]
+        #[allow(clippy::needless_return)]
+        return to_value(MetalInfo::get()?);
+
+        #[cfg(not(target_os = "macos"))]
+        Err(CoreError::UnsupportedSection(self.name().into()))
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<MetalInfo>()
+    }
+}
+
+pub struct DisplaysCollector;
+
+impl Collector for DisplaysCollector {
+    fn name(&self) -> &'static str {
+        "displays"
+    }
+
+    fn title(&self) -> &'static str {
+        "Displays"
+    }
+
+    fn requires_main_thread(&self) -> bool {
+        true
+    }
+
+    fn is_isolated(&self) -> bool {
+        true
+    }
+
+    fn timeout(&self) -> Duration {
+        PROBE_TIMEOUT
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(DisplaysInfo::get()?)
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<DisplaysInfo>()
+    }
+}
+
+pub struct DisksCollector;
+
+impl Collector for DisksCollector {
+    fn name(&self) -> &'static str {
+        "disks"
+    }
+
+    fn title(&self) -> &'static str {
+        "Storage"
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(DisksInfo::get())
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<DisksInfo>()
+    }
+}
+
+pub struct NetworkCollector;
+
+impl Collector for NetworkCollector {
+    fn name(&self) -> &'static str {
+        "network"
+    }
+
+    fn title(&self) -> &'static str {
+        "Network"
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(NetworksInfo::get())
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<NetworksInfo>()
+    }
+}
+
+pub struct USBCollector;
+
+impl Collector for USBCollector {
+    fn name(&self) -> &'static str {
+        "usb"
+    }
+
+    fn title(&self) -> &'static str {
+        "USB"
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::USBInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        to_value(USBInfo::get()?)
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<USBInfo>()
+    }
+}
+
+/// The set of all known collectors, in display order.
+pub struct Registry {
+    collectors: Vec<Box<dyn Collector>>,
+}
+
+impl Registry {
+    /// Creates a registry containing every known collector.
+    fn new() -> Self {
+        Self {
+            collectors: vec![
+                Box::new(PlatformCollector),
+                Box::new(CpuCollector),
+                Box::new(VulkanCollector),
+                Box::new(OpenGLCollector),
+                Box::new(DirectXCollector),
+                Box::new(MetalCollector),
+                Box::new(DisplaysCollector),
+                Box::new(DisksCollector),
+                Box::new(NetworkCollector),
+                Box::new(USBCollector),
+            ],
+        }
+    }
+
+    /// Returns an iterator over all collectors, including ones unavailable on the current platform.
+    pub fn iter(&self) -> impl Iterator<Item = &dyn Collector> {
+        self.collectors.iter().map(|c| c.as_ref())
+    }
+
+    /// Returns an iterator over the collectors available on the current platform.
+    pub fn available(&self) -> impl Iterator<Item = &dyn Collector> {
+        self.iter().filter(|c| c.is_available())
+    }
+
+    /// Returns an iterator over the collectors available on the current platform and enabled in
+    /// the configuration (see [`config::current`]).
+    pub fn enabled(&self) -> impl Iterator<Item = &dyn Collector> {
+        let config = config::current();
+        self.available()
+            .filter(move |c| config.is_enabled(c.name()))
+    }
+
+    /// Looks up an available and enabled collector by its section name.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The section name.
+    pub fn get(&self, name: &str) -> Result<&dyn Collector, CoreError> {
+        match self.iter().find(|c| c.name() == name) {
+            Some(collector) if !collector.is_available() => {
+                Err(CoreError::UnsupportedSection(collector.name().into()))
+            }
+            Some(collector) if !config::current().is_enabled(collector.name()) => {
+                Err(CoreError::DisabledSection(collector.name().into()))
+            }
+            Some(collector) => Ok(collector),
+            None => Err(CoreError::UnknownSection(name.into())),
+        }
+    }
+}
+
+/// The global collector registry.
+pub static REGISTRY: LazyLock<Registry> = LazyLock::new(Registry::new);
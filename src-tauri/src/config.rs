@@ -0,0 +1,351 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, LazyLock, RwLock};
+use std::time::Duration;
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::alerts::Alerts;
+use crate::collectors::{Collector, REGISTRY};
+use crate::export::ExportFormat;
+use crate::redact::{self, RedactionRule};
+use crate::state::SectionCache;
+use crate::types::CoreError;
+
+/// The environment variable that overrides the path of the configuration file.
+pub const CONFIG_ENV: &str = "REVELIO_CONFIG";
+
+/// The configuration in effect, which is the default one until another is set. It is replaced
+/// rather than modified, so that readers can keep it without holding the lock.
+static CURRENT: LazyLock<RwLock<Arc<Config>>> = LazyLock::new(Default::default);
+
+/// The settings of a single section.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct SectionConfig {
+    /// Whether the section is gathered. Disabled sections are left out of reports, and requests
+    /// for them fail (Eg: to skip OpenGL on drivers known to crash).
+    pub enabled: bool,
+    /// How long the section's information is cached for by the app and the agent, in seconds. The
+    /// information is cached indefinitely if omitted.
+    pub ttl: Option<u64>,
+    /// How long the section may take to be gathered, in seconds. Defaults to the section's own
+    /// timeout.
+    pub timeout: Option<u64>,
+}
+
+impl Default for SectionConfig {
+    fn default() -> Self {
+        Self {
+            enabled: true,
+            ttl: None,
+            timeout: None,
+        }
+    }
+}
+
+/// The redaction settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct RedactionConfig {
+    /// Whether reports are redacted unless asked otherwise.
+    pub enabled: bool,
+    /// A JSON or YAML file of redaction rules to use instead of the default ones.
+    pub rules: Option<PathBuf>,
+}
+
+/// The export settings.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct ExportConfig {
+    /// The format reports are exported in when none is given and it can't be inferred from the
+    /// file extension. Defaults to JSON.
+    pub format: Option<ExportFormat>,
+    /// The directory the app suggests exporting reports to.
+    pub dir: Option<PathBuf>,
+}
+
+/// The most verbose level of the messages that are logged.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Type)]
+#[serde(rename_all = "lowercase")]
+pub enum LogLevel {
+    Off,
+    Error,
+    Warn,
+    Info,
+    Debug,
+    Trace,
+}
+
+impl From<LogLevel> for log::LevelFilter {
+    fn from(level: LogLevel) -> Self {
+        match level {
+            LogLevel::Off => Self::Off,
+            LogLevel::Error => Self::Error,
+            LogLevel::Warn => Self::Warn,
+            LogLevel::Info => Self::Info,
+            LogLevel::Debug => Self::Debug,
+            LogLevel::Trace => Self::Trace,
+        }
+    }
+}
+
+/// The logging settings of the app. They take effect on the next launch.
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct LogConfig {
+    /// The most verbose level logged. Defaults to `trace` in debug builds and `warn` otherwise.
+    pub level: Option<LogLevel>,
+    /// A file to write logs to in addition to stderr. Its extension is always `.log`.
+    pub file: Option<PathBuf>,
+}
+
+/// The settings of the metrics history recorded by `revelio-cli record`. Samples are kept as
+/// recorded for a while, then averaged per minute and later per hour, so that the history stays
+/// small while covering a long time.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct HistoryConfig {
+    /// How often metrics are recorded, in seconds.
+    pub interval: u64,
+    /// How long samples are kept as recorded, in hours, before being averaged per minute.
+    pub raw: u64,
+    /// How long per-minute averages are kept, in days, before being averaged per hour.
+    pub minutes: u64,
+    /// How long per-hour averages are kept, in days, before being deleted.
+    pub hours: u64,
+    /// The SQLite database the history is stored in. Defaults to `revelio/history.sqlite` in the
+    /// platform's local data directory.
+    pub file: Option<PathBuf>,
+}
+
+impl Default for HistoryConfig {
+    fn default() -> Self {
+        Self {
+            interval: 10,
+            raw: 24,
+            minutes: 7,
+            hours: 90,
+            file: None,
+        }
+    }
+}
+
+/// A rule that raises an alert while the information of the system matches, or doesn't match, a
+/// query (see [`crate::fleet::Query`]). Exactly one of `when` and `unless` is given.
+///
+/// Queries are about the sections gathered by the app, and about the changing values sampled
+/// while monitoring under `metrics` (Eg: `metrics.temperatures[].temperature > 90`).
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize, Type)]
+pub struct AlertRule {
+    /// The name of the alert, which titles its notification (Eg: `Disk almost full`).
+    pub name: String,
+    /// The query that raises the alert while it matches (Eg:
+    /// `disks.disks[].availablePercent < 10`).
+    pub when: Option<String>,
+    /// The query that raises the alert while it doesn't match (Eg: `usb.devices[].vendorId =
+    /// 0x1050 and usb.devices[].productId = 0x0407`).
+    pub unless: Option<String>,
+    /// The body of the alert's notification. Describes the query if omitted.
+    pub message: Option<String>,
+}
+
+/// The persistent configuration of the app and the command-line interface, read from
+/// `revelio/config.toml` in the platform's configuration directory (Eg: `~/.config` on Linux).
+///
+/// ```toml
+/// [sections.opengl]
+/// enabled = false
+///
+/// [sections.usb]
+/// ttl = 30
+///
+/// [redaction]
+/// enabled = true
+///
+/// [log]
+/// level = "info"
+///
+/// [history]
+/// interval = 30
+///
+/// [[alerts]]
+/// name = "Disk almost full"
+/// when = "disks.disks[].availablePercent < 10"
+/// ```
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize, Type)]
+#[serde(default)]
+pub struct Config {
+    /// The settings of each section, by section name. Sections that are omitted are enabled and
+    /// use their defaults.
+    pub sections: BTreeMap<String, SectionConfig>,
+    /// The redaction settings.
+    pub redaction: RedactionConfig,
+    /// The export settings.
+    pub export: ExportConfig,
+    /// The logging settings.
+    pub log: LogConfig,
+    /// The metrics history settings.
+    pub history: HistoryConfig,
+    /// The alert rules, evaluated by the app whenever a section is gathered or the system is
+    /// sampled.
+    pub alerts: Vec<AlertRule>,
+}
+
+impl Config {
+    /// Loads the configuration from a TOML file, or returns the default configuration if the file
+    /// doesn't exist.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the configuration file.
+    pub fn load(path: &Path) -> Result<Self, CoreError> {
+        let contents = match fs::read_to_string(path) {
+            Ok(contents) => contents,
+            Err(e) if e.kind() == ErrorKind::NotFound => return Ok(Self::default()),
+            Err(e) => return Err(CoreError::Error(e.into())),
+        };
+
+        let config: Self = toml::from_str(&contents)
+            .map_err(|e| CoreError::Error(format!("{}: {e}", path.display()).into()))?;
+        config.validate()?;
+
+        Ok(config)
+    }
+
+    /// Writes the configuration to a TOML file, creating its directory if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the configuration file.
+    pub fn save(&self, path: &Path) -> Result<(), CoreError> {
+        let contents = toml::to_string_pretty(self).map_err(|e| CoreError::Error(e.into()))?;
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| CoreError::Error(e.into()))?;
+        }
+
+        fs::write(path, contents).map_err(|e| CoreError::Error(e.into()))
+    }
+
+    /// Checks that every configured section is known to the collector registry, and that the
+    /// alert rules are valid.
+    pub fn validate(&self) -> Result<(), CoreError> {
+        if let Some(name) = self
+            .sections
+            .keys()
+            .find(|name| !REGISTRY.iter().any(|c| c.name() == name.as_str()))
+        {
+            return Err(CoreError::UnknownSection(name.as_str().into()));
+        }
+
+        Alerts::new(&self.alerts).map(drop)
+    }
+
+    /// Returns the settings of a section.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The section name.
+    pub fn section(&self, name: &str) -> SectionConfig {
+        self.sections.get(name).cloned().unwrap_or_default()
+    }
+
+    /// Checks whether a section is gathered.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The section name.
+    pub fn is_enabled(&self, name: &str) -> bool {
+        self.sections
+            .get(name)
+            .map_or(true, |section| section.enabled)
+    }
+
+    /// Returns how long a section may take to be gathered.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    pub fn timeout(&self, collector: &dyn Collector) -> Duration {
+        self.section(collector.name())
+            .timeout
+            .map_or_else(|| collector.timeout(), Duration::from_secs)
+    }
+
+    /// Sets the time-to-live and timeout of every section of a cache, replacing any set before.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The section cache.
+    pub fn apply(&self, cache: &SectionCache) {
+        for collector in REGISTRY.iter() {
+            let section = self.section(collector.name());
+            cache.set_ttl(collector, section.ttl.map(Duration::from_secs));
+            cache.set_timeout(collector, section.timeout.map(Duration::from_secs));
+        }
+    }
+
+    /// Returns the rules reports are redacted with: the ones in the configured rules file, or the
+    /// default ones.
+    pub fn redaction_rules(&self) -> Result<Vec<RedactionRule>, CoreError> {
+        match &self.redaction.rules {
+            Some(path) => redact::load_rules(path),
+            None => Ok(redact::default_rules()),
+        }
+    }
+
+    /// Returns the path of the metrics history database: the configured one, or
+    /// `revelio/history.sqlite` in the platform's local data directory. Returns `None` if the
+    /// platform has no local data directory.
+    pub fn history_path(&self) -> Option<PathBuf> {
+        match &self.history.file {
+            Some(path) => Some(path.clone()),
+            None => dirs::data_local_dir().map(|dir| dir.join("revelio").join("history.sqlite")),
+        }
+    }
+
+    /// Returns the most verbose level of the messages that are logged.
+    pub fn log_level(&self) -> log::LevelFilter {
+        match self.log.level {
+            Some(level) => level.into(),
+            None if cfg!(debug_assertions) => log::LevelFilter::Trace,
+            None => log::LevelFilter::Warn,
+        }
+    }
+}
+
+/// Returns the path of the configuration file: the one in [`CONFIG_ENV`] if set, or
+/// `revelio/config.toml` in the platform's configuration directory. Returns `None` if the platform
+/// has no configuration directory.
+pub fn path() -> Option<PathBuf> {
+    match std::env::var_os(CONFIG_ENV) {
+        Some(path) => Some(path.into()),
+        None => dirs::config_dir().map(|dir| dir.join("revelio").join("config.toml")),
+    }
+}
+
+/// Loads the configuration from [`path`], or returns the default configuration if there is no
+/// configuration file.
+pub fn load() -> Result<Config, CoreError> {
+    match path() {
+        Some(path) => Config::load(&path),
+        None => Ok(Config::default()),
+    }
+}
+
+/// Returns the configuration in effect. Setting another one doesn't affect the returned one.
+pub fn current() -> Arc<Config> {
+    CURRENT.read().unwrap().clone()
+}
+
+/// Sets the configuration in effect.
+///
+/// # Arguments
+///
+/// * `config` - The configuration.
+pub fn set(config: Config) {
+    *CURRENT.write().unwrap() = Arc::new(config);
+}
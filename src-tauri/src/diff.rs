@@ -0,0 +1,449 @@
+use std::collections::{BTreeMap, HashMap};
+
+use serde::Serialize;
+use serde_json::{Map, Value};
+use specta::Type;
+
+use crate::report::{SectionReport, SystemReport};
+
+/// Fields that identify the elements of arrays of devices across reports, keyed by section name
+/// and array path (`[]` stands for any array element).
+///
+/// Arrays of objects not listed here are compared element by element, in order.
+const STABLE_KEYS: &[(&str, &str, &[&str])] = &[
+    ("cpu", "cores", &["id"]),
+    ("cpu", "cores[].processors", &["apicId"]),
+    ("vulkan", "devices", &["pipelineCacheUuid"]),
+    ("vulkan", "devices[].layers", &["layerName"]),
+    ("directx", "devices", &["vendorId", "deviceId", "subSysId"]),
+    ("metal", "devices", &["registryId"]),
+    ("displays", "displays", &["name"]),
+    ("disks", "disks", &["mountPoint"]),
+    ("network", "interfaces", &["itfName"]),
+    ("usb", "devices", &["vendorId", "productId", "serialNumber"]),
+    ("usb", "devices[].interfaces", &["interfaceNumber"]),
+];
+
+/// Fields of keyed array elements that are ignored, as they only reflect enumeration order.
+const IGNORED_FIELDS: &[&str] = &["index"];
+
+/// The kind of difference between two reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub enum ChangeKind {
+    /// The value is only present in the new report.
+    Added,
+    /// The value is only present in the old report.
+    Removed,
+    /// The value is present in both reports, but differs.
+    Changed,
+}
+
+/// A single difference within a section.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct Change {
+    /// The kind of difference.
+    pub kind: ChangeKind,
+    /// The path of the value within the section (Eg: `disks[/home].availableSpace`), or
+    /// an empty string for the whole section.
+    pub path: String,
+    /// The value in the old report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub old: Option<Value>,
+    /// The value in the new report.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub new: Option<Value>,
+}
+
+/// The differences within a single section.
+#[derive(Debug, Clone, Default, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionDiff {
+    /// The differences, in the order they were found.
+    pub changes: Vec<Change>,
+    /// The reason the section could not be compared (Eg: it failed to be gathered in one of the
+    /// reports), if any.
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub skipped: Option<String>,
+}
+
+impl SectionDiff {
+    /// Checks whether the section is the same in both reports.
+    pub fn is_empty(&self) -> bool {
+        self.changes.is_empty() && self.skipped.is_none()
+    }
+}
+
+/// The differences between two system reports.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct ReportDiff {
+    /// The time the old report was generated, in milliseconds since the Unix epoch.
+    pub old_generated_at: u64,
+    /// The time the new report was generated, in milliseconds since the Unix epoch.
+    pub new_generated_at: u64,
+    /// The sections that differ, keyed by section name.
+    pub sections: BTreeMap<String, SectionDiff>,
+}
+
+impl ReportDiff {
+    /// Compares two system reports.
+    ///
+    /// # Arguments
+    ///
+    /// * `old` - The old report.
+    /// * `new` - The new report.
+    pub fn new(old: &SystemReport, new: &SystemReport) -> Self {
+        let mut names: Vec<&String> = old.sections.keys().chain(new.sections.keys()).collect();
+        names.sort();
+        names.dedup();
+
+        let sections = names
+            .into_iter()
+            .map(|name| {
+                let diff = diff_section(name, old.sections.get(name), new.sections.get(name));
+                (name.clone(), diff)
+            })
+            .filter(|(_, diff)| !diff.is_empty())
+            .collect();
+
+        Self {
+            old_generated_at: old.generated_at,
+            new_generated_at: new.generated_at,
+            sections,
+        }
+    }
+
+    /// Checks whether both reports are the same.
+    pub fn is_empty(&self) -> bool {
+        self.sections.is_empty()
+    }
+}
+
+/// Compares the outcomes of gathering a section in two reports.
+///
+/// # Arguments
+///
+/// * `name` - The section name.
+/// * `old` - The section in the old report, if present.
+/// * `new` - The section in the new report, if present.
+fn diff_section(
+    name: &str,
+    old: Option<&SectionReport>,
+    new: Option<&SectionReport>,
+) -> SectionDiff {
+    let mut diff = SectionDiff::default();
+
+    match (old, new) {
+        (Some(old), Some(new)) => match (&old.data, &new.data) {
+            (Some(old), Some(new)) => {
+                let mut differ = Differ {
+                    section: name,
+                    changes: &mut diff.changes,
+                };
+                differ.diff_values(String::new(), String::new(), old, new);
+            }
+            (None, None) => {}
+            (None, Some(_)) => {
+                diff.skipped = Some(unavailable_reason("old", old));
+            }
+            (Some(_), None) => {
+                diff.skipped = Some(unavailable_reason("new", new));
+            }
+        },
+        (None, Some(new)) => diff.changes.push(Change {
+            kind: ChangeKind::Added,
+            path: String::new(),
+            old: None,
+            new: new.data.clone(),
+        }),
+        (Some(old), None) => diff.changes.push(Change {
+            kind: ChangeKind::Removed,
+            path: String::new(),
+            old: old.data.clone(),
+            new: None,
+        }),
+        (None, None) => {}
+    }
+
+    diff
+}
+
+/// Describes why a section is missing from one of the reports.
+///
+/// # Arguments
+///
+/// * `which` - Which report the section is missing from (`old` or `new`).
+/// * `section` - The section in that report.
+fn unavailable_reason(which: &str, section: &SectionReport) -> String {
+    match &section.error {
+        Some(e) => format!("Not available in the {which} report: {}", e.message),
+        None => format!("Not available in the {which} report"),
+    }
+}
+
+/// Joins a parent path and a field name.
+///
+/// # Arguments
+///
+/// * `parent` - The parent path.
+/// * `key` - The field name.
+fn join(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+/// Formats the value of a key field.
+///
+/// # Arguments
+///
+/// * `value` - The field value, if present.
+fn format_key(value: Option<&Value>) -> String {
+    match value {
+        Some(Value::String(s)) => s.clone(),
+        Some(Value::Null) | None => "-".into(),
+        Some(v) => v.to_string(),
+    }
+}
+
+/// Walks two JSON values side by side, recording their differences.
+struct Differ<'a> {
+    /// The name of the section being compared.
+    section: &'a str,
+    /// The differences found so far.
+    changes: &'a mut Vec<Change>,
+}
+
+impl Differ<'_> {
+    /// Records a difference.
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of difference.
+    /// * `path` - The path of the value.
+    /// * `old` - The old value, if any.
+    /// * `new` - The new value, if any.
+    fn push(&mut self, kind: ChangeKind, path: String, old: Option<&Value>, new: Option<&Value>) {
+        self.changes.push(Change {
+            kind,
+            path,
+            old: old.cloned(),
+            new: new.cloned(),
+        });
+    }
+
+    /// Returns the stable key fields of the elements of an array, if it has any.
+    ///
+    /// # Arguments
+    ///
+    /// * `pattern` - The array path, with `[]` in place of element keys.
+    fn stable_key(&self, pattern: &str) -> Option<&'static [&'static str]> {
+        STABLE_KEYS
+            .iter()
+            .find(|(section, path, _)| *section == self.section && *path == pattern)
+            .map(|(_, _, fields)| *fields)
+    }
+
+    /// Compares two values.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the values.
+    /// * `pattern` - The path of the values, with `[]` in place of element keys.
+    /// * `old` - The old value.
+    /// * `new` - The new value.
+    fn diff_values(&mut self, path: String, pattern: String, old: &Value, new: &Value) {
+        match (old, new) {
+            (Value::Object(old), Value::Object(new)) => {
+                self.diff_objects(&path, &pattern, old, new, &[]);
+            }
+            (Value::Array(old_items), Value::Array(new_items)) => {
+                if old_items.iter().chain(new_items).all(is_scalar) {
+                    self.diff_sets(path, old_items, new_items);
+                } else if let Some(fields) = self.stable_key(&pattern) {
+                    self.diff_keyed(&path, &pattern, old_items, new_items, fields);
+                } else {
+                    self.diff_indexed(&path, &pattern, old_items, new_items);
+                }
+            }
+            _ if old != new => self.push(ChangeKind::Changed, path, Some(old), Some(new)),
+            _ => {}
+        }
+    }
+
+    /// Compares two objects field by field.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the objects.
+    /// * `pattern` - The path of the objects, with `[]` in place of element keys.
+    /// * `old` - The old object.
+    /// * `new` - The new object.
+    /// * `ignored` - Fields to leave out of the comparison.
+    fn diff_objects(
+        &mut self,
+        path: &str,
+        pattern: &str,
+        old: &Map<String, Value>,
+        new: &Map<String, Value>,
+        ignored: &[&str],
+    ) {
+        for (key, old_value) in old {
+            if ignored.contains(&key.as_str()) {
+                continue;
+            }
+
+            match new.get(key) {
+                Some(new_value) => {
+                    self.diff_values(join(path, key), join(pattern, key), old_value, new_value)
+                }
+                None => self.push(ChangeKind::Removed, join(path, key), Some(old_value), None),
+            }
+        }
+
+        for (key, new_value) in new {
+            if !old.contains_key(key) && !ignored.contains(&key.as_str()) {
+                self.push(ChangeKind::Added, join(path, key), None, Some(new_value));
+            }
+        }
+    }
+
+    /// Compares two arrays of scalars as unordered sets.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the arrays.
+    /// * `old` - The old elements.
+    /// * `new` - The new elements.
+    fn diff_sets(&mut self, path: String, old: &[Value], new: &[Value]) {
+        for value in old.iter().filter(|v| !new.contains(v)) {
+            self.push(ChangeKind::Removed, path.clone(), Some(value), None);
+        }
+        for value in new.iter().filter(|v| !old.contains(v)) {
+            self.push(ChangeKind::Added, path.clone(), None, Some(value));
+        }
+    }
+
+    /// Compares two arrays of objects, matching elements by their stable key.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the arrays.
+    /// * `pattern` - The path of the arrays, with `[]` in place of element keys.
+    /// * `old` - The old elements.
+    /// * `new` - The new elements.
+    /// * `fields` - The fields forming the stable key.
+    fn diff_keyed(
+        &mut self,
+        path: &str,
+        pattern: &str,
+        old: &[Value],
+        new: &[Value],
+        fields: &[&str],
+    ) {
+        let old_keyed = key_elements(old, fields);
+        let new_keyed = key_elements(new, fields);
+        let new_index: HashMap<&str, &Value> =
+            new_keyed.iter().map(|(k, v)| (k.as_str(), *v)).collect();
+        let element_pattern = format!("{pattern}[]");
+
+        for (key, old_value) in &old_keyed {
+            let element_path = format!("{path}[{key}]");
+
+            match new_index.get(key.as_str()) {
+                Some(new_value) => match (old_value, new_value) {
+                    (Value::Object(old), Value::Object(new)) => {
+                        self.diff_objects(&element_path, &element_pattern, old, new, IGNORED_FIELDS)
+                    }
+                    _ => self.diff_values(
+                        element_path,
+                        element_pattern.clone(),
+                        old_value,
+                        new_value,
+                    ),
+                },
+                None => self.push(ChangeKind::Removed, element_path, Some(old_value), None),
+            }
+        }
+
+        for (key, new_value) in &new_keyed {
+            if old_keyed.iter().all(|(k, _)| k != key) {
+                self.push(
+                    ChangeKind::Added,
+                    format!("{path}[{key}]"),
+                    None,
+                    Some(new_value),
+                );
+            }
+        }
+    }
+
+    /// Compares two arrays element by element, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the arrays.
+    /// * `pattern` - The path of the arrays, with `[]` in place of element keys.
+    /// * `old` - The old elements.
+    /// * `new` - The new elements.
+    fn diff_indexed(&mut self, path: &str, pattern: &str, old: &[Value], new: &[Value]) {
+        let element_pattern = format!("{pattern}[]");
+
+        for i in 0..old.len().max(new.len()) {
+            let element_path = format!("{path}[{i}]");
+
+            match (old.get(i), new.get(i)) {
+                (Some(old), Some(new)) => {
+                    self.diff_values(element_path, element_pattern.clone(), old, new)
+                }
+                (Some(old), None) => self.push(ChangeKind::Removed, element_path, Some(old), None),
+                (None, Some(new)) => self.push(ChangeKind::Added, element_path, None, Some(new)),
+                (None, None) => {}
+            }
+        }
+    }
+}
+
+/// Checks whether a value is neither an object nor an array.
+///
+/// # Arguments
+///
+/// * `value` - The value to check.
+fn is_scalar(value: &Value) -> bool {
+    !matches!(value, Value::Object(_) | Value::Array(_))
+}
+
+/// Pairs the elements of an array with their stable key.
+///
+/// Elements sharing the same key (Eg: identical USB devices without a serial number) are told
+/// apart by their occurrence, as `key#2`, `key#3`, etc.
+///
+/// # Arguments
+///
+/// * `items` - The array elements.
+/// * `fields` - The fields forming the stable key.
+fn key_elements<'a>(items: &'a [Value], fields: &[&str]) -> Vec<(String, &'a Value)> {
+    let mut seen: HashMap<String, usize> = HashMap::new();
+
+    items
+        .iter()
+        .map(|item| {
+            let key = fields
+                .iter()
+                .map(|field| format_key(item.get(field)))
+                .collect::<Vec<_>>()
+                .join(":");
+            let count = seen.entry(key.clone()).or_default();
+            *count += 1;
+
+            match *count {
+                1 => (key, item),
+                n => (format!("{key}#{n}"), item),
+            }
+        })
+        .collect()
+}
@@ -0,0 +1,171 @@
+use serde_json::Value;
+
+use crate::i18n;
+
+/// The message keys of the Vulkan physical device type labels.
+const VULKAN_DEVICE_TYPES: &[(&str, &str)] = &[
+    ("other", "vulkan-device-type-other"),
+    ("integratedGpu", "vulkan-device-type-integrated-gpu"),
+    ("discreteGpu", "vulkan-device-type-discrete-gpu"),
+    ("virtualGpu", "vulkan-device-type-virtual-gpu"),
+    ("cpu", "vulkan-device-type-cpu"),
+];
+
+/// The message keys of the Metal device location labels.
+const METAL_LOCATIONS: &[(&str, &str)] = &[
+    ("builtIn", "metal-location-built-in"),
+    ("slot", "metal-location-slot"),
+    ("external", "metal-location-external"),
+    ("unspecified", "metal-location-unspecified"),
+];
+
+/// The message keys of the Metal support tier labels.
+const METAL_TIERS: &[(&str, &str)] = &[
+    ("tierNone", "metal-tier-none"),
+    ("tier1", "metal-tier-1"),
+    ("tier2", "metal-tier-2"),
+];
+
+/// The message keys of the USB speed class labels.
+const USB_SPEED_CLASSES: &[(&str, &str)] = &[
+    ("low", "usb-speed-low"),
+    ("full", "usb-speed-full"),
+    ("high", "usb-speed-high"),
+    ("super", "usb-speed-super"),
+    ("superPlus", "usb-speed-super-plus"),
+];
+
+/// The ways structured values are turned into human-readable text.
+#[derive(Clone, Copy)]
+enum Format {
+    /// A width and a height, in pixels.
+    Dimensions,
+    /// An x and a y coordinate.
+    Position,
+    /// A disk kind.
+    DiskKind,
+    /// A USB speed class along with its rate.
+    USBSpeed,
+    /// A Metal width, height and depth.
+    MetalSize,
+    /// An enum value, given the (value, message key) pairs of its variants.
+    Label(&'static [(&'static str, &'static str)]),
+}
+
+/// Structured values formatted for display, keyed by section name and field path (`[]` stands
+/// for any array element).
+const FORMATS: &[(&str, &str, Format)] = &[
+    (
+        "vulkan",
+        "devices[].deviceType",
+        Format::Label(VULKAN_DEVICE_TYPES),
+    ),
+    (
+        "metal",
+        "devices[].location",
+        Format::Label(METAL_LOCATIONS),
+    ),
+    (
+        "metal",
+        "devices[].maxThreadsPerThreadgroup",
+        Format::MetalSize,
+    ),
+    (
+        "metal",
+        "devices[].argumentBuffersSupport",
+        Format::Label(METAL_TIERS),
+    ),
+    (
+        "metal",
+        "devices[].readWriteTextureSupport",
+        Format::Label(METAL_TIERS),
+    ),
+    ("displays", "displays[].dimensions", Format::Dimensions),
+    ("displays", "displays[].position", Format::Position),
+    ("disks", "disks[].kind", Format::DiskKind),
+    ("usb", "devices[].speed", Format::USBSpeed),
+];
+
+/// Formats a structured value of a section as human-readable text, in the current locale.
+///
+/// Returns `None` if the field isn't formatted specially, or if the value doesn't have the
+/// expected shape (Eg: in reports generated by an older version), in which case it should be
+/// displayed as is.
+///
+/// # Arguments
+///
+/// * `section` - The section name.
+/// * `pattern` - The path of the value, with `[]` in place of array indices.
+/// * `value` - The value.
+pub fn format(section: &str, pattern: &str, value: &Value) -> Option<String> {
+    let (_, _, format) = FORMATS
+        .iter()
+        .find(|(s, path, _)| *s == section && *path == pattern)?;
+
+    match format {
+        Format::Dimensions => Some(format!(
+            "{}x{}",
+            value.get("width")?.as_u64()?,
+            value.get("height")?.as_u64()?
+        )),
+        Format::Position => Some(format!(
+            "({}, {})",
+            value.get("x")?.as_i64()?,
+            value.get("y")?.as_i64()?
+        )),
+        Format::DiskKind => match value {
+            Value::String(kind) => match kind.as_str() {
+                "hdd" => Some(i18n::tr("disk-kind-hdd")),
+                "ssd" => Some(i18n::tr("disk-kind-ssd")),
+                _ => None,
+            },
+            _ => Some(i18n::tr_args(
+                "value-unknown-code",
+                &[("code", value.get("unknown")?.as_i64()?.into())],
+            )),
+        },
+        Format::USBSpeed => {
+            let class = value.get("class")?.as_str()?;
+            let speed =
+                label(USB_SPEED_CLASSES, class).unwrap_or_else(|| i18n::tr("value-unknown"));
+
+            match value.get("mbps").and_then(Value::as_f64) {
+                Some(mbps) => Some(i18n::tr_args(
+                    "usb-speed-rate",
+                    &[("speed", speed.into()), ("mbps", mbps.into())],
+                )),
+                None => Some(speed),
+            }
+        }
+        Format::MetalSize => {
+            let width = value.get("width")?.as_u64()?;
+            let height = value.get("height")?.as_u64()?;
+            let depth = value.get("depth")?.as_u64()?;
+            let product = width.saturating_mul(height).saturating_mul(depth);
+
+            Some(i18n::tr_args(
+                "metal-size",
+                &[
+                    ("product", product.into()),
+                    ("width", width.into()),
+                    ("height", height.into()),
+                    ("depth", depth.into()),
+                ],
+            ))
+        }
+        Format::Label(labels) => label(labels, value.as_str()?),
+    }
+}
+
+/// Resolves the label of an enum value in the current locale.
+///
+/// # Arguments
+///
+/// * `labels` - The (value, message key) pairs of the enum's variants.
+/// * `value` - The enum value.
+fn label(labels: &[(&str, &str)], value: &str) -> Option<String> {
+    labels
+        .iter()
+        .find(|(v, _)| *v == value)
+        .map(|(_, key)| i18n::tr(key))
+}
@@ -0,0 +1,146 @@
+use std::fmt::Write;
+
+use super::tables::{self, Table};
+use super::{format_timestamp, ordered_sections};
+use crate::i18n;
+use crate::report::SystemReport;
+
+/// Inline stylesheet of the exported page, so it renders without any external assets.
+const STYLE: &str = r#"
+body { font-family: system-ui, -apple-system, "Segoe UI", sans-serif; margin: 2rem auto; max-width: 960px; padding: 0 1rem; color: #1f2328; background: #ffffff; }
+h1 { margin-bottom: 0.25rem; }
+h2 { margin-top: 2rem; border-bottom: 1px solid #d0d7de; padding-bottom: 0.25rem; }
+h3 { font-size: 1rem; margin-bottom: 0.5rem; }
+dl.meta { display: grid; grid-template-columns: max-content auto; gap: 0.25rem 1rem; color: #59636e; }
+dl.meta dt { font-weight: 600; }
+dl.meta dd { margin: 0; }
+table { border-collapse: collapse; width: 100%; margin-bottom: 1rem; }
+th, td { border: 1px solid #d0d7de; padding: 0.35rem 0.6rem; text-align: left; vertical-align: top; }
+th { width: 40%; background: #f6f8fa; font-weight: 600; }
+td { font-family: ui-monospace, "SFMono-Regular", Menlo, monospace; word-break: break-word; }
+.error { border-left: 4px solid #cf222e; background: #ffebe9; padding: 0.5rem 0.75rem; }
+@media (prefers-color-scheme: dark) {
+  body { color: #e6edf3; background: #0d1117; }
+  h2, th, td { border-color: #30363d; }
+  th { background: #161b22; }
+  dl.meta { color: #9198a1; }
+  .error { background: #3c1618; border-left-color: #f85149; }
+}
+"#;
+
+/// Escapes text for use in HTML content and attribute values.
+///
+/// # Arguments
+///
+/// * `text` - The text to escape.
+fn escape(text: &str) -> String {
+    let mut escaped = String::with_capacity(text.len());
+
+    for c in text.chars() {
+        match c {
+            '&' => escaped.push_str("&amp;"),
+            '<' => escaped.push_str("&lt;"),
+            '>' => escaped.push_str("&gt;"),
+            '"' => escaped.push_str("&quot;"),
+            '\'' => escaped.push_str("&#39;"),
+            _ => escaped.push(c),
+        }
+    }
+
+    escaped
+}
+
+/// Writes a table as an HTML table.
+///
+/// # Arguments
+///
+/// * `out` - The output buffer.
+/// * `table` - The table to write.
+/// * `heading` - Whether to write the table title as a heading.
+fn write_table(out: &mut String, table: &Table, heading: bool) {
+    if heading {
+        let _ = writeln!(out, "<h3>{}</h3>", escape(&table.title));
+    }
+
+    let _ = writeln!(out, "<table>");
+    for (key, value) in &table.rows {
+        let _ = writeln!(
+            out,
+            "<tr><th>{}</th><td>{}</td></tr>",
+            escape(key),
+            escape(value)
+        );
+    }
+    let _ = writeln!(out, "</table>");
+}
+
+/// Renders a system report as a self-contained HTML page.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+pub fn render(report: &SystemReport) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "<!DOCTYPE html>");
+    let _ = writeln!(out, "<html lang=\"{}\">", i18n::locale());
+    let _ = writeln!(out, "<head>");
+    let _ = writeln!(out, "<meta charset=\"utf-8\">");
+    let _ = writeln!(
+        out,
+        "<title>{}</title>",
+        escape(&i18n::tr("report-page-title"))
+    );
+    let _ = writeln!(out, "<style>{STYLE}</style>");
+    let _ = writeln!(out, "</head>");
+    let _ = writeln!(out, "<body>");
+    let _ = writeln!(out, "<h1>{}</h1>", escape(&i18n::tr("report-title")));
+    let _ = writeln!(out, "<dl class=\"meta\">");
+    let _ = writeln!(
+        out,
+        "<dt>{}</dt><dd>{}</dd>",
+        escape(&i18n::tr("report-generated-at")),
+        format_timestamp(report.generated_at)
+    );
+    let _ = writeln!(
+        out,
+        "<dt>{}</dt><dd>{}</dd>",
+        escape(&i18n::tr("report-app-version")),
+        escape(&report.app_version)
+    );
+    let _ = writeln!(
+        out,
+        "<dt>{}</dt><dd>{}</dd>",
+        escape(&i18n::tr("report-schema-version")),
+        report.schema_version
+    );
+    let _ = writeln!(out, "</dl>");
+
+    for (name, title, section) in ordered_sections(report) {
+        let _ = writeln!(out, "<section>");
+        let _ = writeln!(out, "<h2>{}</h2>", escape(&title));
+
+        if let Some(error) = &section.error {
+            let _ = writeln!(
+                out,
+                "<p class=\"error\"><strong>{}:</strong> {}</p>",
+                escape(&i18n::tr("report-unavailable")),
+                escape(&error.message)
+            );
+        }
+
+        if let Some(data) = &section.data {
+            for table in tables::flatten(name, &title, data) {
+                // The section's own table sits directly below the section heading.
+                write_table(&mut out, &table, table.title != title);
+            }
+        }
+
+        let _ = writeln!(out, "</section>");
+    }
+
+    let _ = writeln!(out, "</body>");
+    let _ = writeln!(out, "</html>");
+
+    out
+}
@@ -0,0 +1,93 @@
+use std::fmt::Write;
+
+use super::tables::{self, Table};
+use super::{format_timestamp, ordered_sections};
+use crate::i18n;
+use crate::report::SystemReport;
+
+/// Escapes text for use inside a Markdown table cell.
+///
+/// # Arguments
+///
+/// * `text` - The text to escape.
+fn escape(text: &str) -> String {
+    text.replace('|', "\\|")
+        .replace('<', "&lt;")
+        .replace('\n', " ")
+}
+
+/// Writes a table as a Markdown table.
+///
+/// # Arguments
+///
+/// * `out` - The output buffer.
+/// * `table` - The table to write.
+/// * `heading` - Whether to write the table title as a heading.
+fn write_table(out: &mut String, table: &Table, heading: bool) {
+    if heading {
+        let _ = writeln!(out, "### {}\n", escape(&table.title));
+    }
+
+    let _ = writeln!(
+        out,
+        "| {} | {} |",
+        escape(&i18n::tr("table-field")),
+        escape(&i18n::tr("table-value"))
+    );
+    let _ = writeln!(out, "| --- | --- |");
+    for (key, value) in &table.rows {
+        let _ = writeln!(out, "| {} | {} |", escape(key), escape(value));
+    }
+    let _ = writeln!(out);
+}
+
+/// Renders a system report as a Markdown document.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+pub fn render(report: &SystemReport) -> String {
+    let mut out = String::new();
+
+    let _ = writeln!(out, "# {}\n", i18n::tr("report-title"));
+    let _ = writeln!(
+        out,
+        "- **{}:** {}",
+        i18n::tr("report-generated-at"),
+        format_timestamp(report.generated_at)
+    );
+    let _ = writeln!(
+        out,
+        "- **{}:** {}",
+        i18n::tr("report-app-version"),
+        report.app_version
+    );
+    let _ = writeln!(
+        out,
+        "- **{}:** {}\n",
+        i18n::tr("report-schema-version"),
+        report.schema_version
+    );
+
+    for (name, title, section) in ordered_sections(report) {
+        let _ = writeln!(out, "## {title}\n");
+
+        if let Some(error) = &section.error {
+            let _ = writeln!(
+                out,
+                "> **{}:** {}\n",
+                i18n::tr("report-unavailable"),
+                escape(&error.message)
+            );
+        }
+
+        if let Some(data) = &section.data {
+            for table in tables::flatten(name, &title, data) {
+                // The section's own table sits directly below the section heading.
+                write_table(&mut out, &table, table.title != title);
+            }
+        }
+    }
+
+    out
+}
@@ -0,0 +1,130 @@
+use std::fs;
+use std::path::Path;
+use std::time::{Duration, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use specta::Type;
+
+use crate::collectors::REGISTRY;
+use crate::i18n;
+use crate::report::{SectionReport, SystemReport};
+use crate::types::CoreError;
+
+pub mod format;
+mod html;
+mod markdown;
+pub mod tables;
+
+/// The file formats a system report can be exported to.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize, Type, clap::ValueEnum)]
+#[serde(rename_all = "lowercase")]
+pub enum ExportFormat {
+    /// Pretty-printed JSON.
+    Json,
+    /// YAML.
+    Yaml,
+    /// A Markdown summary with a table per section.
+    Markdown,
+    /// A self-contained HTML page.
+    Html,
+}
+
+impl ExportFormat {
+    /// Returns the file extension conventionally used for the format.
+    pub fn extension(self) -> &'static str {
+        match self {
+            Self::Json => "json",
+            Self::Yaml => "yaml",
+            Self::Markdown => "md",
+            Self::Html => "html",
+        }
+    }
+
+    /// Infers the format from a file's extension.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The file path.
+    pub fn from_path(path: &Path) -> Option<Self> {
+        let extension = path.extension()?.to_str()?.to_ascii_lowercase();
+
+        match extension.as_str() {
+            "json" => Some(Self::Json),
+            "yaml" | "yml" => Some(Self::Yaml),
+            "md" | "markdown" => Some(Self::Markdown),
+            "html" | "htm" => Some(Self::Html),
+            _ => None,
+        }
+    }
+}
+
+/// Formats a timestamp in milliseconds since the Unix epoch as an RFC 3339 string.
+///
+/// # Arguments
+///
+/// * `millis` - The timestamp.
+fn format_timestamp(millis: u64) -> String {
+    humantime::format_rfc3339_seconds(UNIX_EPOCH + Duration::from_millis(millis)).to_string()
+}
+
+/// Returns the sections of a report paired with their names and titles (in the current locale),
+/// in display order.
+///
+/// Sections known to the registry come first, in registry order, followed by any sections the
+/// registry doesn't know about (Eg: in reports generated by a newer version), titled by their
+/// names.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+fn ordered_sections(report: &SystemReport) -> Vec<(&str, String, &SectionReport)> {
+    let mut sections: Vec<(&str, String, &SectionReport)> = REGISTRY
+        .iter()
+        .filter_map(|c| {
+            report
+                .sections
+                .get(c.name())
+                .map(|s| (c.name(), i18n::section_title(c.name()), s))
+        })
+        .collect();
+
+    sections.extend(
+        report
+            .sections
+            .iter()
+            .filter(|(name, _)| REGISTRY.iter().all(|c| c.name() != name.as_str()))
+            .map(|(name, section)| (name.as_str(), i18n::section_title(name), section)),
+    );
+
+    sections
+}
+
+/// Renders a system report in the given format.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+/// * `format` - The export format.
+pub fn render(report: &SystemReport, format: ExportFormat) -> Result<String, CoreError> {
+    match format {
+        ExportFormat::Json => {
+            serde_json::to_string_pretty(report).map_err(|e| CoreError::Error(e.into()))
+        }
+        ExportFormat::Yaml => serde_yaml::to_string(report).map_err(|e| CoreError::Error(e.into())),
+        ExportFormat::Markdown => Ok(markdown::render(report)),
+        ExportFormat::Html => Ok(html::render(report)),
+    }
+}
+
+/// Renders a system report in the given format and writes it to a file.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+/// * `format` - The export format.
+/// * `path` - The path of the file to write.
+pub fn export(report: &SystemReport, format: ExportFormat, path: &Path) -> Result<(), CoreError> {
+    let contents = render(report, format)?;
+
+    fs::write(path, contents).map_err(|e| CoreError::Error(e.into()))
+}
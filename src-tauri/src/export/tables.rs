@@ -0,0 +1,189 @@
+use serde_json::Value;
+
+use super::format;
+use crate::i18n;
+
+/// A titled two-column (field, value) table.
+pub struct Table {
+    /// The table title.
+    pub title: String,
+    /// The (field, value) rows of the table.
+    pub rows: Vec<(String, String)>,
+}
+
+/// Converts a camelCase JSON key into a human-readable label (Eg: `processorsCount` -> `Processors count`).
+///
+/// # Arguments
+///
+/// * `key` - The JSON key.
+pub fn humanize(key: &str) -> String {
+    let chars: Vec<char> = key.chars().collect();
+    let mut words: Vec<String> = Vec::new();
+    let mut word = String::new();
+
+    for (i, c) in chars.iter().enumerate() {
+        let prev_lower = i > 0 && chars[i - 1].is_lowercase();
+        let next_lower = chars.get(i + 1).is_some_and(|n| n.is_lowercase());
+        let prev_upper = i > 0 && chars[i - 1].is_uppercase();
+
+        if c.is_uppercase() && !word.is_empty() && (prev_lower || (prev_upper && next_lower)) {
+            words.push(std::mem::take(&mut word));
+        }
+        word.push(*c);
+    }
+    if !word.is_empty() {
+        words.push(word);
+    }
+
+    words
+        .iter()
+        .enumerate()
+        .map(|(i, w)| {
+            let acronym = w.chars().count() > 1 && w.chars().all(|c| !c.is_lowercase());
+            match (i, acronym) {
+                (_, true) => w.clone(),
+                (0, false) => {
+                    let mut chars = w.chars();
+                    chars
+                        .next()
+                        .map(|first| first.to_uppercase().chain(chars).collect())
+                        .unwrap_or_default()
+                }
+                (_, false) => w.to_lowercase(),
+            }
+        })
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Checks whether a JSON value can be printed on a single table row.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value.
+fn is_scalar(value: &Value) -> bool {
+    match value {
+        Value::Array(values) => values.iter().all(|v| !v.is_object() && !v.is_array()),
+        Value::Object(_) => false,
+        _ => true,
+    }
+}
+
+/// Formats a scalar JSON value for display in a table cell.
+///
+/// # Arguments
+///
+/// * `value` - The JSON value.
+fn format_scalar(value: &Value) -> String {
+    match value {
+        Value::Null => "-".into(),
+        Value::Bool(true) => i18n::tr("value-yes"),
+        Value::Bool(false) => i18n::tr("value-no"),
+        Value::String(s) if s.is_empty() => "-".into(),
+        // The placeholder collectors keep in fields that could not be gathered.
+        Value::String(s) if s == "Unknown" => i18n::tr("value-unknown"),
+        Value::String(s) => s.clone(),
+        Value::Array(values) if values.is_empty() => "-".into(),
+        Value::Array(values) => values
+            .iter()
+            .map(format_scalar)
+            .collect::<Vec<_>>()
+            .join(", "),
+        other => other.to_string(),
+    }
+}
+
+/// Joins a parent path and a field name.
+///
+/// # Arguments
+///
+/// * `parent` - The parent path.
+/// * `key` - The field name.
+fn join(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+/// Recursively flattens a JSON value into tables.
+///
+/// # Arguments
+///
+/// * `tables` - The output tables.
+/// * `section` - The section name.
+/// * `pattern` - The path of the value, with `[]` in place of array indices.
+/// * `title` - The table title.
+/// * `value` - The JSON value.
+fn flatten_into(tables: &mut Vec<Table>, section: &str, pattern: &str, title: &str, value: &Value) {
+    let Value::Object(map) = value else {
+        if let Value::Array(items) = value {
+            if !is_scalar(value) {
+                let pattern = format!("{pattern}[]");
+                for (i, item) in items.iter().enumerate() {
+                    flatten_into(
+                        tables,
+                        section,
+                        &pattern,
+                        &format!("{title} #{}", i + 1),
+                        item,
+                    );
+                }
+                return;
+            }
+        }
+
+        tables.push(Table {
+            title: title.into(),
+            rows: vec![(i18n::tr("table-value"), format_scalar(value))],
+        });
+        return;
+    };
+
+    let mut rows = Vec::new();
+    let mut nested = Vec::new();
+    for (key, value) in map {
+        let path = join(pattern, key);
+        match format::format(section, &path, value) {
+            Some(formatted) => rows.push((humanize(key), formatted)),
+            None if is_scalar(value) => rows.push((humanize(key), format_scalar(value))),
+            None => nested.push((key, path, value)),
+        }
+    }
+
+    if !rows.is_empty() {
+        tables.push(Table {
+            title: title.into(),
+            rows,
+        });
+    }
+
+    for (key, path, value) in nested {
+        flatten_into(
+            tables,
+            section,
+            &path,
+            &format!("{title} > {}", humanize(key)),
+            value,
+        );
+    }
+}
+
+/// Flattens a section's JSON value into tables.
+///
+/// Scalar fields of an object become rows of a single table, while nested objects and lists of
+/// objects become their own tables following it. Structured values, such as display dimensions,
+/// are formatted as a single row (see [`format::format`]).
+///
+/// # Arguments
+///
+/// * `section` - The section name.
+/// * `title` - The section title.
+/// * `value` - The section's JSON value.
+pub fn flatten(section: &str, title: &str, value: &Value) -> Vec<Table> {
+    let mut tables = Vec::new();
+    flatten_into(&mut tables, section, "", title, value);
+
+    tables
+}
@@ -0,0 +1,560 @@
+use std::cmp::Ordering;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use serde::Serialize;
+use serde_json::{json, Map, Value};
+use specta::Type;
+
+use crate::export::ExportFormat;
+use crate::report::SystemReport;
+use crate::types::CoreError;
+
+/// Computes a field of an array element from its other fields. Returns `None` if the element
+/// lacks the fields it is computed from.
+type Derive = fn(&Value) -> Option<Value>;
+
+/// Fields that are computed when reports are indexed, so that they can be queried, keyed by
+/// section name, array and field.
+const DERIVED_FIELDS: &[(&str, &str, &str, Derive)] = &[
+    ("disks", "disks", "usedPercent", disk_used_percent),
+    ("disks", "disks", "availablePercent", disk_available_percent),
+    ("network", "interfaces", "ipNetworkCount", ip_network_count),
+];
+
+/// Computes the percentage of a disk's space that is used, to one decimal place.
+fn disk_used_percent(disk: &Value) -> Option<Value> {
+    let total = disk.get("totalSpace")?.as_f64()?;
+    let available = disk.get("availableSpace")?.as_f64()?;
+    if total <= 0.0 {
+        return None;
+    }
+
+    Some(json!(((total - available) / total * 1000.0).round() / 10.0))
+}
+
+/// Computes the percentage of a disk's space that is available, to one decimal place.
+fn disk_available_percent(disk: &Value) -> Option<Value> {
+    let total = disk.get("totalSpace")?.as_f64()?;
+    let available = disk.get("availableSpace")?.as_f64()?;
+    if total <= 0.0 {
+        return None;
+    }
+
+    Some(json!((available / total * 1000.0).round() / 10.0))
+}
+
+/// Counts the IP networks of a network interface.
+fn ip_network_count(itf: &Value) -> Option<Value> {
+    Some(json!(itf.get("ipNetworks")?.as_array()?.len()))
+}
+
+/// Adds the computed fields of a section (see [`DERIVED_FIELDS`]) to its information.
+///
+/// # Arguments
+///
+/// * `section` - The section name.
+/// * `data` - The section's information.
+pub(crate) fn derive_fields(section: &str, data: &mut Value) {
+    for (_, array, field, derive) in DERIVED_FIELDS.iter().filter(|(s, ..)| *s == section) {
+        let Some(Value::Array(items)) = data.get_mut(*array) else {
+            continue;
+        };
+        for item in items {
+            if let (Some(value), Some(object)) = (derive(item), item.as_object_mut()) {
+                object.insert(field.to_string(), value);
+            }
+        }
+    }
+}
+
+/// The comparison operators of a query condition, longest first so that `<=` isn't read as `<`.
+const OPERATORS: &[(&str, Operator)] = &[
+    ("<=", Operator::Le),
+    (">=", Operator::Ge),
+    ("!=", Operator::Ne),
+    ("=", Operator::Eq),
+    ("<", Operator::Lt),
+    (">", Operator::Gt),
+    ("~", Operator::Contains),
+];
+
+/// How a condition compares a value of a report against the value it's given.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Operator {
+    Eq,
+    Ne,
+    Lt,
+    Le,
+    Gt,
+    Ge,
+    /// The value contains the given text, ignoring case.
+    Contains,
+}
+
+/// A step of the path of a condition.
+#[derive(Debug, Clone, PartialEq, Eq)]
+enum Step {
+    /// A field of an object.
+    Key(String),
+    /// Any element of an array (`[]`).
+    Each,
+}
+
+/// A condition of a query (Eg: `vulkan.devices[].vendorId = 0x10de`).
+#[derive(Debug, Clone)]
+struct Condition {
+    /// The path of the compared value, starting with the section name.
+    path: Vec<Step>,
+    /// How the values are compared.
+    operator: Operator,
+    /// The value the report's value is compared against.
+    value: Value,
+}
+
+/// Parses the path of a condition, with `[]` standing for any array element (Eg:
+/// `vulkan.devices[].vendorId`).
+///
+/// # Arguments
+///
+/// * `path` - The path.
+fn parse_path(path: &str) -> Option<Vec<Step>> {
+    let mut steps = Vec::new();
+
+    for segment in path.split('.') {
+        let mut key = segment;
+        let mut each = 0;
+        while let Some(rest) = key.strip_suffix("[]") {
+            key = rest;
+            each += 1;
+        }
+        if key.is_empty() {
+            return None;
+        }
+
+        steps.push(Step::Key(key.to_string()));
+        steps.extend((0..each).map(|_| Step::Each));
+    }
+
+    Some(steps)
+}
+
+/// Parses the value of a condition: hexadecimal (`0x10de`) and decimal numbers are compared as
+/// numbers, anything else as text. Surrounding quotes are removed.
+///
+/// # Arguments
+///
+/// * `value` - The value.
+fn parse_value(value: &str) -> Value {
+    if let Some(quoted) = value
+        .strip_prefix('"')
+        .and_then(|v| v.strip_suffix('"'))
+        .or_else(|| value.strip_prefix('\'').and_then(|v| v.strip_suffix('\'')))
+    {
+        return json!(quoted);
+    }
+
+    if let Some(hex) = value
+        .strip_prefix("0x")
+        .or_else(|| value.strip_prefix("0X"))
+    {
+        if let Ok(n) = u64::from_str_radix(hex, 16) {
+            return json!(n);
+        }
+    }
+    if let Ok(n) = value.parse::<u64>() {
+        return json!(n);
+    }
+    if let Ok(n) = value.parse::<i64>() {
+        return json!(n);
+    }
+
+    json!(value)
+}
+
+impl Condition {
+    /// Parses a condition of the form `<path> <operator> <value>`.
+    ///
+    /// # Arguments
+    ///
+    /// * `condition` - The condition.
+    fn parse(condition: &str) -> Result<Self, CoreError> {
+        let invalid = |reason: &str| {
+            CoreError::Error(format!("Invalid condition `{condition}`: {reason}").into())
+        };
+
+        let (at, symbol, operator) = condition
+            .char_indices()
+            .find_map(|(at, _)| {
+                OPERATORS
+                    .iter()
+                    .find(|(symbol, _)| condition[at..].starts_with(symbol))
+                    .map(|(symbol, operator)| (at, *symbol, *operator))
+            })
+            .ok_or_else(|| invalid("expected one of =, !=, <, <=, >, >= or ~"))?;
+
+        let path = condition[..at].trim();
+        let value = condition[at + symbol.len()..].trim();
+        if value.is_empty() {
+            return Err(invalid("missing value"));
+        }
+
+        Ok(Self {
+            path: parse_path(path).ok_or_else(|| invalid("invalid path"))?,
+            operator,
+            value: parse_value(value),
+        })
+    }
+
+    /// Checks a value of a report against the condition.
+    ///
+    /// # Arguments
+    ///
+    /// * `actual` - The value of the report.
+    fn test(&self, actual: &Value) -> bool {
+        if self.operator == Operator::Contains {
+            return match (text(actual), text(&self.value)) {
+                (Some(actual), Some(expected)) => {
+                    actual.to_lowercase().contains(&expected.to_lowercase())
+                }
+                _ => false,
+            };
+        }
+
+        let Some(ordering) = compare(actual, &self.value) else {
+            return false;
+        };
+
+        match self.operator {
+            Operator::Eq => ordering.is_eq(),
+            Operator::Ne => ordering.is_ne(),
+            Operator::Lt => ordering.is_lt(),
+            Operator::Le => ordering.is_le(),
+            Operator::Gt => ordering.is_gt(),
+            Operator::Ge => ordering.is_ge(),
+            Operator::Contains => unreachable!(),
+        }
+    }
+}
+
+/// Returns a scalar value as text, or `None` for arrays, objects and null.
+///
+/// # Arguments
+///
+/// * `value` - The value.
+fn text(value: &Value) -> Option<String> {
+    match value {
+        Value::String(s) => Some(s.clone()),
+        Value::Number(n) => Some(n.to_string()),
+        Value::Bool(b) => Some(b.to_string()),
+        _ => None,
+    }
+}
+
+/// Parses a version made of dot-separated numbers (Eg: `550.54.14`).
+///
+/// # Arguments
+///
+/// * `s` - The version.
+fn version(s: &str) -> Option<Vec<u64>> {
+    s.split('.').map(|part| part.parse().ok()).collect()
+}
+
+/// Compares two values: numbers numerically, versions part by part (so that `550.9` comes before
+/// `550.54`) and anything else as text, ignoring case.
+///
+/// # Arguments
+///
+/// * `a` - The first value.
+/// * `b` - The second value.
+fn compare(a: &Value, b: &Value) -> Option<Ordering> {
+    if let (Some(a), Some(b)) = (a.as_f64(), b.as_f64()) {
+        return a.partial_cmp(&b);
+    }
+
+    let (a, b) = (text(a)?, text(b)?);
+    if let (Some(mut a), Some(mut b)) = (version(&a), version(&b)) {
+        let len = a.len().max(b.len());
+        a.resize(len, 0);
+        b.resize(len, 0);
+        return Some(a.cmp(&b));
+    }
+
+    Some(a.to_lowercase().cmp(&b.to_lowercase()))
+}
+
+/// A query over the reports of a fleet: conditions joined by `and`, all of which must hold for a
+/// host to match (Eg: `vulkan.devices[].vendorId = 0x10de and vulkan.devices[].driverVersion <
+/// 550`).
+///
+/// Conditions whose paths go through the same array element (`[]`) must hold for the same
+/// element, so the example above matches hosts with an NVIDIA device whose driver is older than
+/// 550, rather than hosts with an NVIDIA device and another device with an old driver. An empty
+/// query matches every host.
+#[derive(Debug, Clone, Default)]
+pub struct Query {
+    conditions: Vec<Condition>,
+}
+
+impl Query {
+    /// Parses a query.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query (Eg: `platform.graphicsPlatform = Wayland`).
+    pub fn parse(query: &str) -> Result<Self, CoreError> {
+        let mut conditions = Vec::new();
+        let mut condition = Vec::new();
+
+        for word in query.split_whitespace() {
+            if word.eq_ignore_ascii_case("and") {
+                conditions.push(Condition::parse(&condition.join(" "))?);
+                condition.clear();
+            } else {
+                condition.push(word);
+            }
+        }
+        if !condition.is_empty() {
+            conditions.push(Condition::parse(&condition.join(" "))?);
+        }
+
+        Ok(Self { conditions })
+    }
+
+    /// Returns the names of the sections the query's conditions are about, in order of appearance
+    /// and possibly repeated.
+    pub(crate) fn sections(&self) -> impl Iterator<Item = &str> {
+        self.conditions
+            .iter()
+            .filter_map(|condition| match condition.path.first() {
+                Some(Step::Key(section)) => Some(section.as_str()),
+                _ => None,
+            })
+    }
+
+    /// Checks the sections of a host against the query.
+    ///
+    /// # Arguments
+    ///
+    /// * `sections` - The sections of the host, keyed by section name.
+    pub(crate) fn matches(&self, sections: &Value) -> bool {
+        let conditions: Vec<_> = self
+            .conditions
+            .iter()
+            .map(|condition| (condition.path.as_slice(), condition))
+            .collect();
+
+        matches(sections, &conditions)
+    }
+}
+
+/// A condition along with the steps of its path that remain to be followed.
+type PendingCondition<'a> = (&'a [Step], &'a Condition);
+
+/// Checks a value against conditions, given the remaining steps of their paths. Conditions that
+/// go through the same step are checked together, so that they hold for the same array element.
+///
+/// # Arguments
+///
+/// * `value` - The value.
+/// * `conditions` - The (remaining steps, condition) pairs.
+fn matches(value: &Value, conditions: &[PendingCondition]) -> bool {
+    let mut groups: Vec<(&Step, Vec<PendingCondition>)> = Vec::new();
+
+    for (steps, condition) in conditions {
+        match steps.split_first() {
+            None => {
+                if !condition.test(value) {
+                    return false;
+                }
+            }
+            Some((step, rest)) => match groups.iter_mut().find(|(s, _)| *s == step) {
+                Some((_, group)) => group.push((rest, condition)),
+                None => groups.push((step, vec![(rest, condition)])),
+            },
+        }
+    }
+
+    groups.iter().all(|(step, group)| match step {
+        Step::Key(key) => value.get(key).is_some_and(|value| matches(value, group)),
+        Step::Each => value
+            .as_array()
+            .is_some_and(|items| items.iter().any(|item| matches(item, group))),
+    })
+}
+
+/// Describes a host of a fleet.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetHost {
+    /// The hostname, or the name of the report file if the platform section is missing.
+    pub hostname: String,
+    /// The path of the host's report.
+    pub path: PathBuf,
+    /// The time the report was generated, in milliseconds since the Unix epoch.
+    pub generated_at: u64,
+    /// The host's platform (Eg: `Linux`), if known.
+    pub platform: Option<String>,
+    /// The host's kernel version, if known.
+    pub kernel: Option<String>,
+}
+
+/// A report file that could not be added to a fleet.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SkippedReport {
+    /// The path of the report.
+    pub path: PathBuf,
+    /// Why the report was skipped.
+    pub reason: String,
+}
+
+/// Describes a loaded fleet.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct FleetSummary {
+    /// The directory the reports were loaded from.
+    pub dir: PathBuf,
+    /// The number of hosts.
+    pub hosts: usize,
+    /// The report files that could not be loaded.
+    pub skipped: Vec<SkippedReport>,
+}
+
+/// A host of a fleet, along with its indexed sections.
+struct IndexedHost {
+    /// The description of the host.
+    host: FleetHost,
+    /// The successfully gathered sections of the host's report, with their derived fields (see
+    /// [`DERIVED_FIELDS`]), keyed by section name.
+    sections: Value,
+}
+
+/// Index of the reports of many machines, keyed by hostname, that can be searched with a
+/// [`Query`].
+pub struct Fleet {
+    /// The directory the reports were loaded from.
+    dir: PathBuf,
+    /// The hosts, keyed by hostname.
+    hosts: BTreeMap<String, IndexedHost>,
+    /// The report files that could not be loaded.
+    skipped: Vec<SkippedReport>,
+}
+
+impl Fleet {
+    /// Loads every JSON or YAML report in a directory. Reports that can't be loaded are skipped
+    /// (see [`Fleet::skipped`]). If several reports share a hostname, the most recent one is kept.
+    ///
+    /// # Arguments
+    ///
+    /// * `dir` - The directory.
+    pub fn load(dir: &Path) -> Result<Self, CoreError> {
+        let mut paths: Vec<PathBuf> = fs::read_dir(dir)
+            .map_err(|e| CoreError::Error(format!("{}: {e}", dir.display()).into()))?
+            .filter_map(|entry| Some(entry.ok()?.path()))
+            .filter(|path| {
+                matches!(
+                    ExportFormat::from_path(path),
+                    Some(ExportFormat::Json | ExportFormat::Yaml)
+                )
+            })
+            .collect();
+        paths.sort();
+
+        let mut fleet = Self {
+            dir: dir.to_path_buf(),
+            hosts: BTreeMap::new(),
+            skipped: Vec::new(),
+        };
+        for path in paths {
+            match SystemReport::load(&path) {
+                Ok(report) => fleet.insert(path, &report),
+                Err(e) => fleet.skipped.push(SkippedReport {
+                    path,
+                    reason: e.reason(),
+                }),
+            }
+        }
+
+        Ok(fleet)
+    }
+
+    /// Indexes a report, unless a more recent report of the same host is already indexed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the report.
+    /// * `report` - The report.
+    pub fn insert(&mut self, path: PathBuf, report: &SystemReport) {
+        let platform = report.section("platform");
+        let field = |name: &str| {
+            platform
+                .and_then(|p| p.get(name))
+                .and_then(Value::as_str)
+                .map(str::to_string)
+        };
+        let hostname = field("hostname").unwrap_or_else(|| {
+            path.file_stem()
+                .map(|stem| stem.to_string_lossy().into_owned())
+                .unwrap_or_default()
+        });
+
+        if let Some(existing) = self.hosts.get(&hostname) {
+            if existing.host.generated_at >= report.generated_at {
+                return;
+            }
+        }
+
+        let mut sections = Map::new();
+        for (name, section) in &report.sections {
+            if let Some(data) = &section.data {
+                let mut data = data.clone();
+                derive_fields(name, &mut data);
+                sections.insert(name.clone(), data);
+            }
+        }
+
+        let host = FleetHost {
+            hostname: hostname.clone(),
+            path,
+            generated_at: report.generated_at,
+            platform: field("platform"),
+            kernel: field("kernel"),
+        };
+        self.hosts.insert(
+            hostname,
+            IndexedHost {
+                host,
+                sections: Value::Object(sections),
+            },
+        );
+    }
+
+    /// Returns the report files that could not be loaded.
+    pub fn skipped(&self) -> &[SkippedReport] {
+        &self.skipped
+    }
+
+    /// Describes the fleet.
+    pub fn summary(&self) -> FleetSummary {
+        FleetSummary {
+            dir: self.dir.clone(),
+            hosts: self.hosts.len(),
+            skipped: self.skipped.clone(),
+        }
+    }
+
+    /// Returns the hosts matching a query, ordered by hostname.
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query.
+    pub fn query(&self, query: &Query) -> Vec<FleetHost> {
+        self.hosts
+            .values()
+            .filter(|indexed| query.matches(&indexed.sections))
+            .map(|indexed| indexed.host.clone())
+            .collect()
+    }
+}
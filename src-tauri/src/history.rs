@@ -0,0 +1,352 @@
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use rusqlite::{params, Connection, OpenFlags};
+use serde::Serialize;
+use specta::Type;
+
+use crate::config::HistoryConfig;
+use crate::monitor::MetricsSample;
+use crate::types::CoreError;
+
+/// The resolution of the samples as recorded.
+const RAW: u64 = 0;
+/// The resolution of per-minute averages, in milliseconds.
+const MINUTE: u64 = 60 * 1000;
+/// The resolution of per-hour averages, in milliseconds.
+const HOUR: u64 = 60 * MINUTE;
+
+/// How long to wait for the database while another process is writing to it.
+const BUSY_TIMEOUT: Duration = Duration::from_secs(5);
+
+/// The schema of the history database. Values are stored with the number of samples they're the
+/// average of, so that averages can be averaged again without skewing them.
+const SCHEMA: &str = "
+CREATE TABLE IF NOT EXISTS samples (
+    metric TEXT NOT NULL,
+    subject TEXT NOT NULL,
+    resolution INTEGER NOT NULL,
+    time INTEGER NOT NULL,
+    value REAL NOT NULL,
+    count INTEGER NOT NULL,
+    PRIMARY KEY (metric, subject, resolution, time)
+) WITHOUT ROWID;
+CREATE INDEX IF NOT EXISTS samples_by_age ON samples (resolution, time);
+";
+
+/// Returns the values of a metric in a sample, by subject (Eg: a mount point), or with an empty
+/// subject for metrics of the whole system.
+type Values = fn(&MetricsSample) -> Vec<(String, f64)>;
+
+/// The metrics that are recorded, keyed by name, with their unit.
+const METRICS: &[(&str, &str, Values)] = &[
+    ("cpu.usage", "percent", |s| {
+        mean(s.cores.iter().map(|core| core.usage as f64))
+    }),
+    ("cpu.frequency", "hertz", |s| {
+        mean(s.cores.iter().map(|core| core.frequency as f64))
+    }),
+    ("memory.used", "bytes", |s| {
+        vec![(String::new(), s.memory.used_memory as f64)]
+    }),
+    ("memory.available", "bytes", |s| {
+        vec![(String::new(), s.memory.available_memory as f64)]
+    }),
+    ("swap.used", "bytes", |s| {
+        vec![(String::new(), s.memory.used_swap as f64)]
+    }),
+    ("network.received", "bytes/s", |s| {
+        s.interfaces
+            .iter()
+            .map(|itf| (itf.itf_name.clone(), itf.received_rate))
+            .collect()
+    }),
+    ("network.transmitted", "bytes/s", |s| {
+        s.interfaces
+            .iter()
+            .map(|itf| (itf.itf_name.clone(), itf.transmitted_rate))
+            .collect()
+    }),
+    ("disk.used", "percent", |s| {
+        s.disks
+            .iter()
+            .filter(|disk| disk.total_space > 0)
+            .map(|disk| {
+                let used = disk.total_space.saturating_sub(disk.available_space);
+                let percent = used as f64 / disk.total_space as f64 * 100.0;
+                (disk.mount_point.clone(), percent)
+            })
+            .collect()
+    }),
+    ("disk.available", "bytes", |s| {
+        s.disks
+            .iter()
+            .map(|disk| (disk.mount_point.clone(), disk.available_space as f64))
+            .collect()
+    }),
+    ("disk.read", "bytes/s", |s| {
+        s.disks
+            .iter()
+            .filter_map(|disk| Some((disk.mount_point.clone(), disk.read_rate?)))
+            .collect()
+    }),
+    ("disk.written", "bytes/s", |s| {
+        s.disks
+            .iter()
+            .filter_map(|disk| Some((disk.mount_point.clone(), disk.write_rate?)))
+            .collect()
+    }),
+    ("temperature", "celsius", |s| {
+        s.temperatures
+            .iter()
+            .map(|sensor| (sensor.label.clone(), sensor.temperature as f64))
+            .collect()
+    }),
+];
+
+/// Returns the mean of some values as the value of the whole system, or nothing if there are no
+/// values.
+///
+/// # Arguments
+///
+/// * `values` - The values.
+fn mean(values: impl Iterator<Item = f64>) -> Vec<(String, f64)> {
+    let (sum, count) = values.fold((0.0, 0), |(sum, count), value| (sum + value, count + 1));
+    if count == 0 {
+        return Vec::new();
+    }
+
+    vec![(String::new(), sum / count as f64)]
+}
+
+/// A metric that is recorded.
+#[derive(Debug, Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryMetric {
+    /// The name of the metric (Eg: `disk.used`).
+    pub name: String,
+    /// The unit of the metric's values (Eg: `percent`, `bytes/s`).
+    pub unit: String,
+}
+
+/// Returns the names of the metrics that are recorded.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    METRICS.iter().map(|(name, _, _)| *name)
+}
+
+/// Returns the metrics that are recorded.
+pub fn metrics() -> Vec<HistoryMetric> {
+    METRICS
+        .iter()
+        .map(|(name, unit, _)| HistoryMetric {
+            name: name.to_string(),
+            unit: unit.to_string(),
+        })
+        .collect()
+}
+
+/// A recorded value of a metric.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct HistoryPoint {
+    /// The time of the value, in milliseconds since the Unix epoch. For averages, this is the
+    /// start of the minute or hour they cover.
+    pub time: u64,
+    /// The value, or the average of the values recorded in the minute or hour.
+    pub value: f64,
+}
+
+/// The recorded values of a metric for a single subject, oldest first.
+#[derive(Debug, Clone, PartialEq, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct MetricSeries {
+    /// The name of the metric (Eg: `disk.used`).
+    pub metric: String,
+    /// What the values are of (Eg: a mount point or network interface), or empty for metrics of
+    /// the whole system.
+    pub subject: String,
+    /// The unit of the values (Eg: `percent`).
+    pub unit: String,
+    /// The values.
+    pub points: Vec<HistoryPoint>,
+}
+
+/// The metrics history, stored in a SQLite database.
+///
+/// Samples are kept as recorded for a while, then averaged per minute and later per hour, and
+/// deleted once they're older than the retention of per-hour averages (see [`HistoryConfig`]).
+pub struct History {
+    conn: Connection,
+}
+
+impl History {
+    /// Opens the history for recording, creating the database and its directory if needed.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the database.
+    pub fn open(path: &Path) -> Result<Self, CoreError> {
+        if let Some(dir) = path.parent() {
+            fs::create_dir_all(dir).map_err(|e| CoreError::Error(e.into()))?;
+        }
+
+        let conn = Connection::open(path).map_err(|e| CoreError::Error(e.into()))?;
+        // Write-ahead logging lets the history be queried while it's being recorded.
+        conn.pragma_update(None, "journal_mode", "WAL")
+            .map_err(|e| CoreError::Error(e.into()))?;
+        conn.busy_timeout(BUSY_TIMEOUT)
+            .map_err(|e| CoreError::Error(e.into()))?;
+        conn.execute_batch(SCHEMA)
+            .map_err(|e| CoreError::Error(e.into()))?;
+
+        Ok(Self { conn })
+    }
+
+    /// Opens the history for querying. Fails if nothing has been recorded yet.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the database.
+    pub fn open_read_only(path: &Path) -> Result<Self, CoreError> {
+        let conn = Connection::open_with_flags(path, OpenFlags::SQLITE_OPEN_READ_ONLY)
+            .map_err(|e| CoreError::Error(format!("{}: {e}", path.display()).into()))?;
+        conn.busy_timeout(BUSY_TIMEOUT)
+            .map_err(|e| CoreError::Error(e.into()))?;
+
+        Ok(Self { conn })
+    }
+
+    /// Records the value of every metric in a sample.
+    ///
+    /// # Arguments
+    ///
+    /// * `sample` - The sample.
+    pub fn record(&mut self, sample: &MetricsSample) -> Result<(), CoreError> {
+        let tx = self
+            .conn
+            .transaction()
+            .map_err(|e| CoreError::Error(e.into()))?;
+        {
+            let mut insert = tx
+                .prepare_cached(
+                    "INSERT OR REPLACE INTO samples
+                         (metric, subject, resolution, time, value, count)
+                     VALUES (?1, ?2, ?3, ?4, ?5, 1)",
+                )
+                .map_err(|e| CoreError::Error(e.into()))?;
+
+            for (metric, _, values) in METRICS {
+                for (subject, value) in values(sample) {
+                    insert
+                        .execute(params![metric, subject, RAW, sample.sampled_at, value])
+                        .map_err(|e| CoreError::Error(e.into()))?;
+                }
+            }
+        }
+
+        tx.commit().map_err(|e| CoreError::Error(e.into()))
+    }
+
+    /// Averages the samples that are older than their retention into coarser ones, and deletes
+    /// the per-hour averages that are older than theirs.
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The history settings.
+    /// * `now` - The current time, in milliseconds since the Unix epoch.
+    pub fn downsample(&mut self, config: &HistoryConfig, now: u64) -> Result<(), CoreError> {
+        let tx = self
+            .conn
+            .transaction()
+            .map_err(|e| CoreError::Error(e.into()))?;
+
+        for (from, to, retention) in [
+            (RAW, MINUTE, config.raw * HOUR),
+            (MINUTE, HOUR, config.minutes * 24 * HOUR),
+        ] {
+            // Only whole minutes and hours are averaged, so each is averaged once.
+            let cutoff = now.saturating_sub(retention) / to * to;
+            tx.execute(
+                "INSERT INTO samples (metric, subject, resolution, time, value, count)
+                 SELECT metric, subject, ?2, time / ?2 * ?2,
+                     SUM(value * count) / SUM(count), SUM(count)
+                 FROM samples WHERE resolution = ?1 AND time < ?3
+                 GROUP BY metric, subject, time / ?2
+                 ON CONFLICT DO UPDATE SET
+                     value = (value * count + excluded.value * excluded.count)
+                         / (count + excluded.count),
+                     count = count + excluded.count",
+                params![from, to, cutoff],
+            )
+            .map_err(|e| CoreError::Error(e.into()))?;
+            tx.execute(
+                "DELETE FROM samples WHERE resolution = ?1 AND time < ?2",
+                params![from, cutoff],
+            )
+            .map_err(|e| CoreError::Error(e.into()))?;
+        }
+
+        tx.execute(
+            "DELETE FROM samples WHERE resolution = ?1 AND time < ?2",
+            params![HOUR, now.saturating_sub(config.hours * 24 * HOUR)],
+        )
+        .map_err(|e| CoreError::Error(e.into()))?;
+
+        tx.commit().map_err(|e| CoreError::Error(e.into()))
+    }
+
+    /// Returns the values of a metric recorded in a time range, one series per subject.
+    ///
+    /// # Arguments
+    ///
+    /// * `metric` - The name of the metric (Eg: `disk.used`).
+    /// * `subject` - The only subject to return the values of (Eg: `/home`), if any.
+    /// * `from` - The start of the range, in milliseconds since the Unix epoch.
+    /// * `to` - The end of the range (excluded), in milliseconds since the Unix epoch.
+    pub fn query(
+        &self,
+        metric: &str,
+        subject: Option<&str>,
+        from: u64,
+        to: u64,
+    ) -> Result<Vec<MetricSeries>, CoreError> {
+        let Some((_, unit, _)) = METRICS.iter().find(|(name, _, _)| *name == metric) else {
+            return Err(CoreError::Error(format!("Unknown metric: {metric}").into()));
+        };
+
+        // SQLite integers are signed.
+        let to = to.min(i64::MAX as u64);
+        let mut select = self
+            .conn
+            .prepare(
+                "SELECT subject, time, value FROM samples
+                 WHERE metric = ?1 AND (?2 IS NULL OR subject = ?2) AND time >= ?3 AND time < ?4
+                 ORDER BY subject, time",
+            )
+            .map_err(|e| CoreError::Error(e.into()))?;
+        let rows = select
+            .query_map(params![metric, subject, from, to], |row| {
+                Ok((row.get::<_, String>(0)?, row.get(1)?, row.get(2)?))
+            })
+            .map_err(|e| CoreError::Error(e.into()))?;
+
+        let mut series: Vec<MetricSeries> = Vec::new();
+        for row in rows {
+            let (subject, time, value) = row.map_err(|e| CoreError::Error(e.into()))?;
+            let point = HistoryPoint { time, value };
+
+            match series.last_mut() {
+                Some(last) if last.subject == subject => last.points.push(point),
+                _ => series.push(MetricSeries {
+                    metric: metric.into(),
+                    subject,
+                    unit: unit.to_string(),
+                    points: vec![point],
+                }),
+            }
+        }
+
+        Ok(series)
+    }
+}
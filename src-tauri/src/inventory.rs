@@ -0,0 +1,205 @@
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+
+use serde_json::{Map, Value};
+
+use crate::diff::ReportDiff;
+use crate::export::{self, ExportFormat};
+use crate::report::SystemReport;
+use crate::types::CoreError;
+
+/// The fields that make up the hardware inventory, keyed by section name and, for fields of
+/// array elements, the array. Every other field is left out, so that values that change on their
+/// own (Eg: the available space of disks) aren't reported as changes of the hardware.
+///
+/// Array elements are matched between inventories by the same fields as report diffs (see
+/// [`ReportDiff`]), so every array's key fields are kept.
+const INVENTORY_FIELDS: &[(&str, Option<&str>, &[&str])] = &[
+    ("cpu", None, &["brand"]),
+    (
+        "platform",
+        None,
+        &[
+            "platform",
+            "osArch",
+            "kernel",
+            "id",
+            "versionId",
+            "prettyName",
+            "macosVersion",
+        ],
+    ),
+    (
+        "disks",
+        Some("disks"),
+        &["name", "mountPoint", "fileSystem", "kind", "totalSpace"],
+    ),
+    ("displays", Some("displays"), &["name", "dimensions"]),
+    (
+        "usb",
+        Some("devices"),
+        &[
+            "vendorId",
+            "productId",
+            "serialNumber",
+            "manufacturerString",
+            "productString",
+        ],
+    ),
+    (
+        "vulkan",
+        Some("devices"),
+        &[
+            "pipelineCacheUuid",
+            "deviceName",
+            "vendorId",
+            "deviceId",
+            "driverVersion",
+        ],
+    ),
+];
+
+/// Returns the names of the sections the hardware inventory is made of.
+pub fn sections() -> impl Iterator<Item = &'static str> {
+    INVENTORY_FIELDS.iter().map(|(section, _, _)| *section)
+}
+
+/// Returns the path of the inventory saved by the last run: `revelio/inventory.json` in the
+/// platform's local data directory. Returns `None` if the platform has no local data directory.
+pub fn path() -> Option<PathBuf> {
+    dirs::data_local_dir().map(|dir| dir.join("revelio").join("inventory.json"))
+}
+
+/// Keeps the given fields of an object.
+///
+/// # Arguments
+///
+/// * `value` - The object.
+/// * `fields` - The fields to keep.
+fn keep_fields(value: &Value, fields: &[&str]) -> Value {
+    let kept: Map<String, Value> = fields
+        .iter()
+        .filter_map(|field| Some((field.to_string(), value.get(*field)?.clone())))
+        .collect();
+
+    Value::Object(kept)
+}
+
+/// Reduces a report to the hardware inventory: the identity of the USB devices, disks, displays
+/// and Vulkan devices, the CPU brand, and the kernel and distribution versions. Sections that
+/// aren't part of the inventory are left out.
+///
+/// # Arguments
+///
+/// * `report` - The report.
+pub fn inventory(report: &SystemReport) -> SystemReport {
+    let mut inventory = report.clone();
+    inventory
+        .sections
+        .retain(|name, _| sections().any(|section| section == name));
+
+    for (name, section) in inventory.sections.iter_mut() {
+        let (Some(data), Some((_, array, fields))) = (
+            &section.data,
+            INVENTORY_FIELDS
+                .iter()
+                .find(|(section, _, _)| section == name),
+        ) else {
+            continue;
+        };
+
+        section.data = Some(match array {
+            None => keep_fields(data, fields),
+            Some(array) => {
+                let items = data
+                    .get(*array)
+                    .and_then(Value::as_array)
+                    .map(|items| items.iter().map(|item| keep_fields(item, fields)).collect())
+                    .unwrap_or_default();
+                let mut object = Map::new();
+                object.insert(array.to_string(), Value::Array(items));
+
+                Value::Object(object)
+            }
+        });
+    }
+
+    inventory
+}
+
+/// The hardware inventory saved by the last run, which every check is compared with, along with
+/// the file the latest inventory is saved to.
+pub struct InventoryFile {
+    /// The path of the file.
+    path: PathBuf,
+    /// The inventory saved by the last run, if any.
+    last_run: Option<SystemReport>,
+    /// The inventory saved by the latest check, if any.
+    saved: Option<SystemReport>,
+}
+
+impl InventoryFile {
+    /// Loads the inventory saved by the last run, if any.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the file the inventory is saved to (see [`path`]).
+    pub fn open(path: &Path) -> Result<Self, CoreError> {
+        let last_run = match fs::metadata(path) {
+            Ok(_) => Some(SystemReport::load(path)?),
+            Err(e) if e.kind() == ErrorKind::NotFound => None,
+            Err(e) => return Err(CoreError::Error(e.into())),
+        };
+
+        Ok(Self {
+            path: path.to_path_buf(),
+            saved: last_run.clone(),
+            last_run,
+        })
+    }
+
+    /// Compares the hardware inventory of a report with the one saved by the last run, and saves
+    /// it in the file. Returns `None` if no inventory was saved by the last run.
+    ///
+    /// Sections that fail to be gathered keep their latest saved inventory, so that they aren't
+    /// reported as added by the next run once they're gathered again. Sections missing from the
+    /// report (Eg: disabled in the configuration) keep it too, without being reported as removed.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - The report of the current system.
+    pub fn check(&mut self, report: &SystemReport) -> Result<Option<ReportDiff>, CoreError> {
+        let mut current = inventory(report);
+        if let Some(saved) = &self.saved {
+            for (name, section) in &saved.sections {
+                current
+                    .sections
+                    .entry(name.clone())
+                    .or_insert_with(|| section.clone());
+            }
+        }
+
+        let diff = self
+            .last_run
+            .as_ref()
+            .map(|last_run| ReportDiff::new(last_run, &current));
+
+        if let Some(saved) = &self.saved {
+            for (name, section) in current.sections.iter_mut() {
+                if section.data.is_none() {
+                    if let Some(last) = saved.sections.get(name).filter(|s| s.data.is_some()) {
+                        *section = last.clone();
+                    }
+                }
+            }
+        }
+        if let Some(dir) = self.path.parent() {
+            fs::create_dir_all(dir).map_err(|e| CoreError::Error(e.into()))?;
+        }
+        export::export(&current, ExportFormat::Json, &self.path)?;
+        self.saved = Some(current);
+
+        Ok(diff)
+    }
+}
@@ -1,311 +1,952 @@
+use std::path::PathBuf;
+use std::sync::mpsc::{self, RecvTimeoutError, Sender};
 use std::sync::Mutex;
-
-use tauri::{Builder, Manager, State};
+use std::thread;
+use std::time::Duration;
+
+use serde::Serialize;
+use serde_json::Value;
+use specta::Type;
+use specta_typescript::{BigIntExportBehavior, Typescript};
+use tauri::async_runtime;
+use tauri::{AppHandle, Builder, Manager};
+use tauri_plugin_dialog::DialogExt;
 use tauri_plugin_log::{Target, TargetKind};
-
+use tauri_plugin_notification::NotificationExt;
+use tauri_specta::{collect_commands, collect_events, ErrorHandlingMode, Event};
+
+use crate::agent::client::AgentClient;
+use crate::agent::AgentInfo;
+use crate::alerts::{Alerts, METRICS};
+use crate::collectors::{Collector, REGISTRY};
+use crate::config::Config;
+use crate::diff::ReportDiff;
+use crate::export::ExportFormat;
+use crate::fleet::{Fleet, FleetHost, FleetSummary, Query};
+use crate::history::{History, HistoryMetric, MetricSeries};
+use crate::inventory::InventoryFile;
+use crate::monitor::{MetricsSample, Monitor, DEFAULT_INTERVAL, MINIMUM_INTERVAL};
+use crate::redact::{RedactionRule, Redactor};
+use crate::report::SystemReport;
+use crate::state::{SectionCache, SectionSnapshot, SectionTiming};
 use crate::types::CoreError;
 use crate::utils::cpu::CpuInfo;
 use crate::utils::disks::DisksInfo;
 use crate::utils::displays::DisplaysInfo;
+use crate::utils::gpu::directx::DirectXInfo;
+use crate::utils::gpu::metal::MetalInfo;
 use crate::utils::gpu::opengl::OpenGLInfo;
+use crate::utils::gpu::vulkan::VulkanInfo;
 use crate::utils::network::NetworksInfo;
-use crate::utils::platform::PlatformInfo;
+use crate::utils::platform::{os_type, PlatformInfo};
 use crate::utils::usb::USBInfo;
 
-#[cfg(target_os = "windows")]
-use crate::utils::gpu::directx::DirectXInfo;
+pub mod agent;
+pub mod alerts;
+pub mod cli;
+pub mod collectors;
+pub mod config;
+pub mod diff;
+pub mod export;
+pub mod fleet;
+pub mod history;
+pub mod inventory;
+pub mod metrics;
+pub mod probe;
+pub mod redact;
+pub mod report;
+pub mod schema;
+pub mod state;
+mod upgrade;
+
+// The collectors live in the GUI-free `revelio-core` crate, and are re-exported at their former
+// paths.
+#[cfg(target_os = "linux")]
+pub use revelio_core::sysroot;
+pub use revelio_core::{i18n, monitor, types, utils};
+
+type AppState = SectionCache;
+
+/// The agent the app inspects instead of the current system, if any.
+#[derive(Default)]
+struct RemoteAgent(Mutex<Option<AgentClient>>);
+
+/// Returns the client of the agent the app inspects, if the app is pointed at one.
+///
+/// # Arguments
+///
+/// * `app` - The app handle.
+fn remote(app: &AppHandle) -> Option<AgentClient> {
+    app.state::<RemoteAgent>().0.lock().unwrap().clone()
+}
 
-#[cfg(target_os = "macos")]
-use crate::utils::gpu::metal::MetalInfo;
-#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
-use crate::utils::gpu::vulkan::VulkanInfo;
+/// The reports loaded in the fleet view, if any.
+#[derive(Default)]
+struct LoadedFleet(Mutex<Option<Fleet>>);
 
-mod types;
-mod utils;
+/// The background sampler of the changing values of the system, if monitoring. Dropping the
+/// sender stops it.
+#[derive(Default)]
+struct Monitoring(Mutex<Option<Sender<()>>>);
 
+/// The path of the generated TypeScript bindings of the commands, events and types shared with
+/// the frontend.
+pub const BINDINGS_PATH: &str = concat!(env!("CARGO_MANIFEST_DIR"), "/../src/bindings.ts");
+
+/// The alert rules of the configuration, evaluated against the sections gathered by the app and
+/// the samples taken while monitoring.
 #[derive(Default)]
-struct AppStateInner {
-    cpu_info: Option<CpuInfo>,
-    opengl_info: Option<OpenGLInfo>,
-    displays_info: Option<DisplaysInfo>,
-    disks_info: Option<DisksInfo>,
-    networks_info: Option<NetworksInfo>,
-    usb_info: Option<USBInfo>,
-    platform_info: Option<PlatformInfo>,
+struct AlertState(Mutex<Alerts>);
+
+/// Evaluates the alert rules about a section against its latest information, logging the alerts
+/// that start or stop firing and raising a desktop notification for those that start.
+///
+/// # Arguments
+///
+/// * `app` - The app handle.
+/// * `section` - The section name, or [`METRICS`] for a sample of the changing values.
+/// * `data` - The section's information.
+fn check_alerts(app: &AppHandle, section: &str, data: &Value) {
+    let events = app
+        .state::<AlertState>()
+        .0
+        .lock()
+        .unwrap()
+        .update(section, data);
+
+    for event in events {
+        if !event.firing {
+            log::info!("alert resolved: {}", event.name);
+            continue;
+        }
+
+        log::warn!("alert firing: {}: {}", event.name, event.message);
+        if let Err(e) = app
+            .notification()
+            .builder()
+            .title(&event.name)
+            .body(&event.message)
+            .show()
+        {
+            log::error!("failed to notify of alert {}: {e}", event.name);
+        }
+    }
+}
 
-    #[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
-    vulkan_info: Option<VulkanInfo>,
-    #[cfg(target_os = "windows")]
-    directx_info: Option<DirectXInfo>,
-    #[cfg(target_os = "macos")]
-    metal_info: Option<MetalInfo>,
+/// The hardware inventory saved by the last run, opened by the first check, and the changes found
+/// by the latest check, if any.
+#[derive(Default)]
+struct InventoryState {
+    file: Mutex<Option<InventoryFile>>,
+    changes: Mutex<Option<ReportDiff>>,
+}
+
+/// Payload of the `section-collected` event, emitted whenever a prefetched section is ready.
+#[derive(Clone, Serialize, Type, Event)]
+#[serde(rename_all = "camelCase")]
+struct SectionCollected {
+    section: &'static str,
+    snapshot: SectionSnapshot,
 }
 
-type AppState = Mutex<AppStateInner>;
+/// Payload of the `metrics-sampled` event, emitted at every interval while monitoring.
+#[derive(Clone, Serialize, Type, Event)]
+struct MetricsSampled(MetricsSample);
+
+/// Payload of the `inventory-checked` event, emitted whenever the hardware inventory has been
+/// compared with the one saved by the last run. Holds the changes, or `None` on the first run.
+#[derive(Clone, Serialize, Type, Event)]
+struct InventoryChecked(Option<ReportDiff>);
+
+/// Runs a blocking operation on the section cache without blocking the async runtime.
+///
+/// Sections whose collectors must run on the main thread are dispatched to it, unless they are
+/// gathered in a helper process; all other sections run on a blocking worker thread.
+///
+/// # Arguments
+///
+/// * `app` - The app handle.
+/// * `collector` - The collector of the section being operated on.
+/// * `op` - The operation to run.
+async fn run_blocking<F>(
+    app: AppHandle,
+    collector: &'static dyn Collector,
+    op: F,
+) -> Result<SectionSnapshot, CoreError>
+where
+    F: FnOnce(&SectionCache, &'static dyn Collector) -> Result<SectionSnapshot, CoreError>
+        + Send
+        + 'static,
+{
+    let handle = app.clone();
+    let snapshot = if !collector.requires_main_thread() || collector.is_isolated() {
+        async_runtime::spawn_blocking(move || op(&handle.state::<AppState>(), collector))
+            .await
+            .map_err(|e| CoreError::Error(e.to_string().into()))??
+    } else {
+        let (tx, rx) = mpsc::channel();
+        app.run_on_main_thread(move || {
+            let _ = tx.send(op(&handle.state::<AppState>(), collector));
+        })
+        .map_err(|e| CoreError::Error(e.to_string().into()))?;
+
+        async_runtime::spawn_blocking(move || rx.recv())
+            .await
+            .map_err(|e| CoreError::Error(e.to_string().into()))?
+            .map_err(|e| CoreError::Error(e.into()))??
+    };
+
+    check_alerts(&app, collector.name(), &snapshot.data);
+
+    Ok(snapshot)
+}
 
 #[tauri::command]
+#[specta::specta]
 fn is_release_profile() -> bool {
     !cfg!(debug_assertions)
 }
 
+/// Returns the type of the inspected system's operating system.
 #[tauri::command]
-fn get_os_type() -> &'static str {
-    #[cfg(target_os = "windows")]
-    return "Windows";
+#[specta::specta]
+async fn get_os_type(app: AppHandle) -> Result<String, CoreError> {
+    match remote(&app) {
+        Some(agent) => Ok(agent.info().await?.os_type),
+        None => Ok(os_type().into()),
+    }
+}
 
-    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
-    return "MacIntel";
+/// Returns the names of the sections enabled on the inspected system's platform.
+#[tauri::command]
+#[specta::specta]
+async fn get_sections(app: AppHandle) -> Result<Vec<String>, CoreError> {
+    match remote(&app) {
+        Some(agent) => Ok(agent.info().await?.sections),
+        None => Ok(REGISTRY.enabled().map(|c| c.name().to_string()).collect()),
+    }
+}
 
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-    return "MacSilicon";
+#[tauri::command]
+#[specta::specta]
+async fn get_section_info(app: AppHandle, section: String) -> Result<SectionSnapshot, CoreError> {
+    if let Some(agent) = remote(&app) {
+        return agent.section(&section, false).await;
+    }
+    let collector = REGISTRY.get(&section)?;
 
-    #[cfg(target_os = "linux")]
-    #[allow(clippy::needless_return)]
-    return "Linux";
+    run_blocking(app, collector, |cache, collector| {
+        cache.get_or_collect(collector)
+    })
+    .await
 }
 
+/// Gathers a section again, replacing its cached snapshot.
+///
+/// This one command takes the section name, instead of one `refresh_<section>` command per
+/// section, so that sections added to the registry need no new command. The typed per-section
+/// wrappers (Eg: `refreshDisksInfo`) are in `src/api.ts`.
 #[tauri::command]
-fn get_cpu_info(state: State<'_, AppState>) -> Result<CpuInfo, CoreError> {
-    let mut state = state.lock().unwrap();
-
-    if let Some(info) = &state.cpu_info {
-        return Ok(info.clone());
+#[specta::specta]
+async fn refresh_section_info(
+    app: AppHandle,
+    section: String,
+) -> Result<SectionSnapshot, CoreError> {
+    if let Some(agent) = remote(&app) {
+        return agent.section(&section, true).await;
     }
+    let collector = REGISTRY.get(&section)?;
 
-    let info = CpuInfo::get()?;
-    state.cpu_info = Some(info.clone());
-
-    Ok(info)
+    run_blocking(app, collector, |cache, collector| cache.refresh(collector)).await
 }
 
 #[tauri::command]
-fn get_disks_info(state: State<'_, AppState>) -> DisksInfo {
-    let mut state = state.lock().unwrap();
-
-    if let Some(info) = &state.disks_info {
-        return info.clone();
+#[specta::specta]
+async fn invalidate_section_info(app: AppHandle, section: String) -> Result<(), CoreError> {
+    // A remote agent keeps its own cache, which is bypassed with `refresh_section_info` instead.
+    if remote(&app).is_some() {
+        return Ok(());
     }
+    let collector = REGISTRY.get(&section)?;
 
-    let info = DisksInfo::get();
-    state.disks_info = Some(info.clone());
+    // Invalidation waits for any in-flight collection of the section to finish.
+    async_runtime::spawn_blocking(move || app.state::<AppState>().invalidate(collector))
+        .await
+        .map_err(|e| CoreError::Error(e.to_string().into()))
+}
 
-    info
+/// Sets how long a section's information is cached for, or caches it indefinitely if `ttl_secs`
+/// is `None`.
+#[tauri::command]
+#[specta::specta]
+fn set_section_ttl(
+    section: String,
+    ttl_secs: Option<u64>,
+    app: AppHandle,
+) -> Result<(), CoreError> {
+    let collector = REGISTRY.get(&section)?;
+    app.state::<AppState>()
+        .set_ttl(collector, ttl_secs.map(Duration::from_secs));
+
+    Ok(())
 }
 
-#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
+/// Sets how long a section may take to be gathered before it fails, or restores the section's
+/// default timeout if `timeout_secs` is `None`.
 #[tauri::command]
-fn get_vulkan_info(state: State<'_, AppState>) -> Result<VulkanInfo, CoreError> {
-    let mut state = state.lock().unwrap();
+#[specta::specta]
+fn set_section_timeout(
+    section: String,
+    timeout_secs: Option<u64>,
+    app: AppHandle,
+) -> Result<(), CoreError> {
+    let collector = REGISTRY.get(&section)?;
+    app.state::<AppState>()
+        .set_timeout(collector, timeout_secs.map(Duration::from_secs));
+
+    Ok(())
+}
 
-    if let Some(info) = &state.vulkan_info {
-        return Ok(info.clone());
-    }
+/// Returns how long the last request for each section took, whether it was served from the cache
+/// and the error it failed with, if any.
+#[tauri::command]
+#[specta::specta]
+fn get_diagnostics(app: AppHandle) -> Vec<SectionTiming> {
+    app.state::<AppState>().timings()
+}
 
-    let info = VulkanInfo::get()?;
-    state.vulkan_info = Some(info.clone());
+/// Gathers the given sections concurrently in the background, emitting a `section-collected`
+/// event as soon as each one is ready. Subsequent requests for those sections are served from the
+/// cache, or wait for the in-flight collection to finish.
+#[tauri::command]
+#[specta::specta]
+fn prefetch_sections(sections: Vec<String>, app: AppHandle) -> Result<(), CoreError> {
+    // A remote agent gathers sections in its own cache, so there is nothing to prefetch here.
+    if remote(&app).is_some() {
+        return Ok(());
+    }
+    let collectors = sections
+        .iter()
+        .map(|section| REGISTRY.get(section))
+        .collect::<Result<Vec<_>, _>>()?;
+
+    for collector in collectors {
+        let app = app.clone();
+
+        async_runtime::spawn(async move {
+            let result = run_blocking(app.clone(), collector, |cache, collector| {
+                cache.get_or_collect(collector)
+            })
+            .await;
+
+            match result {
+                Ok(snapshot) => {
+                    let payload = SectionCollected {
+                        section: collector.name(),
+                        snapshot,
+                    };
+                    if let Err(e) = payload.emit(&app) {
+                        log::error!("failed to emit section-collected event: {e}");
+                    }
+                }
+                Err(e) => log::warn!("failed to prefetch {} section: {e}", collector.name()),
+            }
+        });
+    }
 
-    Ok(info)
+    Ok(())
 }
 
-#[tauri::command]
-fn get_opengl_info(state: State<'_, AppState>) -> Result<OpenGLInfo, CoreError> {
-    let mut state = state.lock().unwrap();
+/// Gathers every section enabled on the inspected system into a single report. Sections are
+/// gathered concurrently and served from the cache where possible, or requested from the remote
+/// agent if the app is pointed at one.
+///
+/// # Arguments
+///
+/// * `app` - The app handle.
+async fn collect_report(app: &AppHandle) -> Result<SystemReport, CoreError> {
+    if let Some(agent) = remote(app) {
+        return agent.report().await;
+    }
 
-    if let Some(info) = &state.opengl_info {
-        return Ok(info.clone());
+    let tasks: Vec<_> = REGISTRY
+        .enabled()
+        .map(|collector| {
+            let task = run_blocking(app.clone(), collector, |cache, collector| {
+                cache.get_or_collect(collector)
+            });
+            (collector.name(), async_runtime::spawn(task))
+        })
+        .collect();
+
+    let mut sections = Vec::with_capacity(tasks.len());
+    for (name, task) in tasks {
+        let result = task
+            .await
+            .map_err(|e| CoreError::Error(e.to_string().into()))
+            .and_then(|result| result);
+        sections.push((name, result));
     }
 
-    let info = OpenGLInfo::get()?;
-    state.opengl_info = Some(info.clone());
+    Ok(SystemReport::from_sections(sections))
+}
 
-    Ok(info)
+/// Gathers every section into a report, then applies the given redaction rules, if any.
+///
+/// # Arguments
+///
+/// * `app` - The app handle.
+/// * `redaction` - The redaction rules, or `None` to keep every value.
+async fn collect_redacted_report(
+    app: &AppHandle,
+    redaction: Option<Vec<RedactionRule>>,
+) -> Result<SystemReport, CoreError> {
+    let mut report = collect_report(app).await?;
+    if let Some(rules) = redaction {
+        Redactor::new(rules)?.redact_report(&mut report);
+    }
+
+    Ok(report)
 }
 
+/// Returns the redaction rules of the configuration, which default to hashing every value
+/// identifying the machine or its devices.
 #[tauri::command]
-fn get_displays_info(state: State<'_, AppState>) -> Result<DisplaysInfo, CoreError> {
-    let mut state = state.lock().unwrap();
+#[specta::specta]
+fn get_default_redaction_rules() -> Result<Vec<RedactionRule>, CoreError> {
+    config::current().redaction_rules()
+}
 
-    if let Some(info) = &state.displays_info {
-        return Ok(info.clone());
-    }
+/// Gathers every section into a report, redacted with the given rules, if any.
+#[tauri::command]
+#[specta::specta]
+async fn get_system_report(
+    app: AppHandle,
+    redaction: Option<Vec<RedactionRule>>,
+) -> Result<SystemReport, CoreError> {
+    collect_redacted_report(&app, redaction).await
+}
 
-    let info = DisplaysInfo::get()?;
-    state.displays_info = Some(info.clone());
+/// Exports a report of every section to a file, redacted with the given rules, if any. If no path
+/// is given, the user is asked to choose one, starting in the configured export directory.
+/// Returns the path of the written file, or `None` if the user cancelled.
+#[tauri::command]
+#[specta::specta]
+async fn export_system_report(
+    app: AppHandle,
+    format: ExportFormat,
+    path: Option<PathBuf>,
+    redaction: Option<Vec<RedactionRule>>,
+) -> Result<Option<PathBuf>, CoreError> {
+    let path = match path {
+        Some(path) => path,
+        None => {
+            let mut dialog = app
+                .dialog()
+                .file()
+                .add_filter(format.extension().to_uppercase(), &[format.extension()])
+                .set_file_name(format!("revelio-report.{}", format.extension()));
+            if let Some(dir) = &config::current().export.dir {
+                dialog = dialog.set_directory(dir);
+            }
+            let chosen = async_runtime::spawn_blocking(move || dialog.blocking_save_file())
+                .await
+                .map_err(|e| CoreError::Error(e.to_string().into()))?;
+
+            match chosen {
+                Some(path) => path
+                    .into_path()
+                    .map_err(|e| CoreError::Error(e.to_string().into()))?,
+                None => return Ok(None),
+            }
+        }
+    };
+
+    let report = collect_redacted_report(&app, redaction).await?;
+    export::export(&report, format, &path)?;
+
+    Ok(Some(path))
+}
 
-    Ok(info)
+/// Compares a previously saved report against another one, or against the current system if no
+/// new report is given.
+#[tauri::command]
+#[specta::specta]
+async fn diff_system_reports(
+    app: AppHandle,
+    old_report: PathBuf,
+    new_report: Option<PathBuf>,
+) -> Result<ReportDiff, CoreError> {
+    let old = SystemReport::load(&old_report)?;
+    let new = match new_report {
+        Some(path) => SystemReport::load(&path)?,
+        None => collect_report(&app).await?,
+    };
+
+    Ok(ReportDiff::new(&old, &new))
+}
+
+/// Loads the reports in a directory into the fleet view, replacing any previously loaded fleet. If
+/// no directory is given, the user is asked to choose one. Returns the description of the fleet,
+/// or `None` if the user cancelled.
+#[tauri::command]
+#[specta::specta]
+async fn load_fleet(
+    app: AppHandle,
+    dir: Option<PathBuf>,
+) -> Result<Option<FleetSummary>, CoreError> {
+    let dir = match dir {
+        Some(dir) => dir,
+        None => {
+            let dialog = app.dialog().file();
+            let chosen = async_runtime::spawn_blocking(move || dialog.blocking_pick_folder())
+                .await
+                .map_err(|e| CoreError::Error(e.to_string().into()))?;
+
+            match chosen {
+                Some(path) => path
+                    .into_path()
+                    .map_err(|e| CoreError::Error(e.to_string().into()))?,
+                None => return Ok(None),
+            }
+        }
+    };
+
+    let fleet = async_runtime::spawn_blocking(move || Fleet::load(&dir))
+        .await
+        .map_err(|e| CoreError::Error(e.to_string().into()))??;
+    let summary = fleet.summary();
+    *app.state::<LoadedFleet>().0.lock().unwrap() = Some(fleet);
+
+    Ok(Some(summary))
 }
 
+/// Returns the hosts of the loaded fleet that match a query (Eg: `platform.graphicsPlatform =
+/// Wayland`), or every host if the query is empty.
 #[tauri::command]
-fn get_networks_info(state: State<'_, AppState>) -> NetworksInfo {
-    let mut state = state.lock().unwrap();
+#[specta::specta]
+fn query_fleet(app: AppHandle, query: String) -> Result<Vec<FleetHost>, CoreError> {
+    let query = Query::parse(&query)?;
+    let state = app.state::<LoadedFleet>();
+    let fleet = state.0.lock().unwrap();
+
+    match fleet.as_ref() {
+        Some(fleet) => Ok(fleet.query(&query)),
+        None => Err(CoreError::Error("No fleet has been loaded".into())),
+    }
+}
 
-    if let Some(info) = &state.networks_info {
-        return info.clone();
+/// Starts sampling the changing values of the current system (CPU usage and frequencies, memory
+/// usage, network traffic and disk space and I/O) in the background, emitting a `metrics-sampled`
+/// event every `interval_ms` milliseconds, or every second if omitted. Any previous monitoring is
+/// stopped first.
+#[tauri::command]
+#[specta::specta]
+fn start_monitoring(app: AppHandle, interval_ms: Option<u64>) -> Result<(), CoreError> {
+    if remote(&app).is_some() {
+        return Err(CoreError::Error(
+            "Monitoring is only available for the current system".into(),
+        ));
     }
+    let interval = interval_ms
+        .map(Duration::from_millis)
+        .unwrap_or(DEFAULT_INTERVAL)
+        .max(MINIMUM_INTERVAL);
+
+    let (tx, rx) = mpsc::channel::<()>();
+    let handle = app.clone();
+    thread::Builder::new()
+        .name("monitor".into())
+        .spawn(move || {
+            let mut monitor = Monitor::new();
+
+            while let Err(RecvTimeoutError::Timeout) = rx.recv_timeout(interval) {
+                let sample = monitor.sample();
+                match serde_json::to_value(&sample) {
+                    Ok(data) => check_alerts(&handle, METRICS, &data),
+                    Err(e) => log::error!("failed to check the alerts about metrics: {e}"),
+                }
+
+                if let Err(e) = MetricsSampled(sample).emit(&handle) {
+                    log::error!("failed to emit metrics-sampled event: {e}");
+                }
+            }
+        })
+        .map_err(|e| CoreError::Error(e.into()))?;
 
-    let info = NetworksInfo::get();
-    state.networks_info = Some(info.clone());
+    // Replacing the sender of any previous monitoring stops it.
+    *app.state::<Monitoring>().0.lock().unwrap() = Some(tx);
 
-    info
+    Ok(())
 }
 
+/// Stops sampling the changing values of the system, if monitoring.
 #[tauri::command]
-fn get_usb_info(state: State<'_, AppState>) -> Result<USBInfo, CoreError> {
-    let mut state = state.lock().unwrap();
+#[specta::specta]
+fn stop_monitoring(app: AppHandle) {
+    app.state::<Monitoring>().0.lock().unwrap().take();
+}
 
-    if let Some(info) = &state.usb_info {
-        return Ok(info.clone());
-    }
+/// Returns the metrics recorded in the metrics history (see `revelio-cli record`).
+#[tauri::command]
+#[specta::specta]
+fn get_history_metrics() -> Vec<HistoryMetric> {
+    history::metrics()
+}
 
-    let info = USBInfo::get()?;
-    state.usb_info = Some(info.clone());
+/// Returns the values of a metric (Eg: `disk.used`) recorded in the metrics history from `from`
+/// until `to`, or until now if omitted, in milliseconds since the Unix epoch. There is a series
+/// per subject (Eg: per mount point), unless only the values of `subject` are asked for.
+#[tauri::command]
+#[specta::specta]
+async fn query_history(
+    metric: String,
+    subject: Option<String>,
+    from: u64,
+    to: Option<u64>,
+) -> Result<Vec<MetricSeries>, CoreError> {
+    let path = config::current()
+        .history_path()
+        .ok_or_else(|| CoreError::Error("No data directory was found".into()))?;
+
+    async_runtime::spawn_blocking(move || {
+        History::open_read_only(&path)?.query(
+            &metric,
+            subject.as_deref(),
+            from,
+            to.unwrap_or(u64::MAX),
+        )
+    })
+    .await
+    .map_err(|e| CoreError::Error(e.to_string().into()))?
+}
 
-    Ok(info)
+/// Gathers the sections of the hardware inventory of the current system afresh, compares it with
+/// the one saved by the last run and saves it in its place, then emits an `inventory-checked`
+/// event. Changes are logged and raise a desktop notification.
+///
+/// # Arguments
+///
+/// * `app` - The app handle.
+async fn check_inventory_changes(app: &AppHandle) -> Result<Option<ReportDiff>, CoreError> {
+    let path =
+        inventory::path().ok_or_else(|| CoreError::Error("No data directory was found".into()))?;
+
+    let tasks: Vec<_> = REGISTRY
+        .enabled()
+        .filter(|collector| inventory::sections().any(|section| section == collector.name()))
+        .map(|collector| {
+            let task = run_blocking(app.clone(), collector, |cache, collector| {
+                cache.refresh(collector)
+            });
+            (collector.name(), async_runtime::spawn(task))
+        })
+        .collect();
+
+    let mut sections = Vec::with_capacity(tasks.len());
+    for (name, task) in tasks {
+        let result = task
+            .await
+            .map_err(|e| CoreError::Error(e.to_string().into()))
+            .and_then(|result| result);
+        sections.push((name, result));
+    }
+    let report = SystemReport::from_sections(sections);
+
+    let handle = app.clone();
+    let diff = async_runtime::spawn_blocking(move || {
+        let state = handle.state::<InventoryState>();
+        let mut file = state.file.lock().unwrap();
+        let file = match file.as_mut() {
+            Some(file) => file,
+            None => file.insert(InventoryFile::open(&path)?),
+        };
+        let diff = file.check(&report)?;
+        *state.changes.lock().unwrap() = diff.clone();
+
+        Ok::<_, CoreError>(diff)
+    })
+    .await
+    .map_err(|e| CoreError::Error(e.to_string().into()))??;
+
+    if let Some(diff) = diff.as_ref().filter(|diff| !diff.is_empty()) {
+        let sections: Vec<String> = diff
+            .sections
+            .keys()
+            .map(|s| i18n::section_title(s))
+            .collect();
+        let body = i18n::tr_args(
+            "inventory-changed-sections",
+            &[("sections", sections.join(", ").into())],
+        );
+
+        log::warn!("hardware changed since the last run: {body}");
+        if let Err(e) = app
+            .notification()
+            .builder()
+            .title(i18n::tr("inventory-changed"))
+            .body(&body)
+            .show()
+        {
+            log::error!("failed to notify of hardware changes: {e}");
+        }
+    }
+    if let Err(e) = InventoryChecked(diff.clone()).emit(app) {
+        log::error!("failed to emit inventory-checked event: {e}");
+    }
+
+    Ok(diff)
 }
 
+/// Returns the changes to the hardware inventory of the current system (USB devices, disks,
+/// displays, Vulkan devices, CPU, kernel and distribution) since the last run, as found by the
+/// latest check. Returns `None` if no check has completed yet, or if no inventory was saved by
+/// the last run.
 #[tauri::command]
-fn get_platform_info(state: State<'_, AppState>) -> Result<PlatformInfo, CoreError> {
-    let mut state = state.lock().unwrap();
+#[specta::specta]
+fn get_inventory_changes(app: AppHandle) -> Option<ReportDiff> {
+    app.state::<InventoryState>()
+        .changes
+        .lock()
+        .unwrap()
+        .clone()
+}
 
-    if let Some(info) = &state.platform_info {
-        return Ok(info.clone());
+/// Compares the hardware inventory of the current system with the one saved by the last run, as
+/// done on launch, and saves it in its place. Returns `None` if no inventory was saved by the last
+/// run.
+#[tauri::command]
+#[specta::specta]
+async fn check_inventory(app: AppHandle) -> Result<Option<ReportDiff>, CoreError> {
+    if remote(&app).is_some() {
+        return Err(CoreError::Error(
+            "Hardware changes are only checked on the current system".into(),
+        ));
     }
 
-    let info = PlatformInfo::get()?;
-    state.platform_info = Some(info.clone());
-
-    Ok(info)
+    check_inventory_changes(&app).await
 }
 
 #[tauri::command]
+#[specta::specta]
 fn get_app_version() -> String {
     env!("CARGO_PKG_VERSION").into()
 }
 
-#[cfg(target_os = "windows")]
+/// Points the app at a remote agent (see `revelio-cli serve`), or back at the current system if
+/// `url` is `None`. Returns the description of the agent, which is queried to check that it can
+/// be reached.
 #[tauri::command]
-fn get_directx_info(state: State<'_, AppState>) -> Result<DirectXInfo, CoreError> {
-    let mut state = state.lock().unwrap();
-
-    if let Some(info) = &state.directx_info {
-        return Ok(info.clone());
-    }
-
-    let info = DirectXInfo::get()?;
-    state.directx_info = Some(info.clone());
+#[specta::specta]
+async fn set_remote_agent(
+    app: AppHandle,
+    url: Option<String>,
+    token: Option<String>,
+) -> Result<Option<AgentInfo>, CoreError> {
+    let (agent, info) = match url {
+        Some(url) => {
+            let agent = AgentClient::new(&url, token)?;
+            let info = agent.info().await?;
+            (Some(agent), Some(info))
+        }
+        None => (None, None),
+    };
+
+    *app.state::<RemoteAgent>().0.lock().unwrap() = agent;
 
     Ok(info)
 }
 
-#[cfg(target_os = "macos")]
+/// Returns the URL of the remote agent the app is pointed at, if any.
 #[tauri::command]
-fn get_metal_info(state: State<'_, AppState>) -> Result<MetalInfo, CoreError> {
-    let mut state = state.lock().unwrap();
+#[specta::specta]
+fn get_remote_agent(app: AppHandle) -> Option<String> {
+    remote(&app).map(|agent| agent.url().to_string())
+}
 
-    if let Some(info) = &state.metal_info {
-        return Ok(info.clone());
-    }
+/// Returns the configuration in effect.
+#[tauri::command]
+#[specta::specta]
+fn get_config() -> Config {
+    Config::clone(&config::current())
+}
 
-    let info = MetalInfo::get()?;
-    state.metal_info = Some(info.clone());
+/// Saves the configuration to the configuration file and puts it into effect. Section timeouts
+/// and time-to-lives set since launch are replaced by the configured ones, and the logging
+/// settings take effect on the next launch.
+#[tauri::command]
+#[specta::specta]
+fn set_config(app: AppHandle, config: Config) -> Result<(), CoreError> {
+    config.validate()?;
+    let path = config::path()
+        .ok_or_else(|| CoreError::Error("No configuration directory was found".into()))?;
+    config.save(&path)?;
+
+    config.apply(&app.state::<AppState>());
+    app.state::<AlertState>()
+        .0
+        .lock()
+        .unwrap()
+        .set_rules(&config.alerts)?;
+    config::set(config);
+
+    Ok(())
+}
 
-    Ok(info)
+/// Returns the locales the backend's labels and error messages can be resolved in.
+#[tauri::command]
+#[specta::specta]
+fn get_locales() -> Vec<String> {
+    i18n::locales().into_iter().map(String::from).collect()
 }
 
-pub fn run() {
-    let mut level = log::LevelFilter::Trace;
-    if !cfg!(debug_assertions) {
-        level = log::LevelFilter::Warn;
-    }
+/// Returns the locale the backend's labels and error messages are resolved in.
+#[tauri::command]
+#[specta::specta]
+fn get_locale() -> String {
+    i18n::locale().into()
+}
 
-    let mut builder = Builder::default();
+/// Sets the locale the backend's labels and error messages are resolved in (Eg: `de-DE`). Returns
+/// the supported locale that was picked, which falls back to English.
+#[tauri::command]
+#[specta::specta]
+fn set_locale(locale: String) -> String {
+    i18n::set_locale(&locale).into()
+}
 
-    // Windows devices.
-    #[cfg(target_os = "windows")]
-    {
-        builder = builder.invoke_handler(tauri::generate_handler![
+/// Creates the builder that registers the commands and events shared with the frontend, and
+/// exports their TypeScript bindings.
+///
+/// The section information is returned as JSON by [`get_section_info`], so the types of each
+/// section are registered explicitly for the frontend to use, along with the names of the
+/// sections in the registry (as `SECTIONS`).
+pub fn specta_builder() -> tauri_specta::Builder {
+    tauri_specta::Builder::new()
+        .commands(collect_commands![
             is_release_profile,
             get_os_type,
-            get_cpu_info,
-            get_disks_info,
-            get_displays_info,
-            get_networks_info,
-            get_usb_info,
-            get_platform_info,
             get_app_version,
-            get_vulkan_info,
-            get_opengl_info,
-            get_directx_info,
-        ]);
-    }
+            get_locales,
+            get_locale,
+            set_locale,
+            get_sections,
+            get_section_info,
+            refresh_section_info,
+            invalidate_section_info,
+            set_section_ttl,
+            set_section_timeout,
+            get_diagnostics,
+            prefetch_sections,
+            get_default_redaction_rules,
+            get_system_report,
+            export_system_report,
+            diff_system_reports,
+            set_remote_agent,
+            get_remote_agent,
+            load_fleet,
+            query_fleet,
+            get_config,
+            set_config,
+            start_monitoring,
+            stop_monitoring,
+            get_history_metrics,
+            query_history,
+            get_inventory_changes,
+            check_inventory,
+        ])
+        .events(collect_events![
+            SectionCollected,
+            MetricsSampled,
+            InventoryChecked
+        ])
+        .typ::<PlatformInfo>()
+        .typ::<CpuInfo>()
+        .typ::<VulkanInfo>()
+        .typ::<OpenGLInfo>()
+        .typ::<DirectXInfo>()
+        .typ::<MetalInfo>()
+        .typ::<DisplaysInfo>()
+        .typ::<DisksInfo>()
+        .typ::<NetworksInfo>()
+        .typ::<USBInfo>()
+        .constant(
+            "SECTIONS",
+            REGISTRY.iter().map(|c| c.name()).collect::<Vec<_>>(),
+        )
+        .error_handling(ErrorHandlingMode::Throw)
+}
 
-    // Apple Silicon devices.
-    #[cfg(all(target_os = "macos", target_arch = "aarch64"))]
-    {
-        builder = builder.invoke_handler(tauri::generate_handler![
-            is_release_profile,
-            get_os_type,
-            get_cpu_info,
-            get_disks_info,
-            get_displays_info,
-            get_networks_info,
-            get_usb_info,
-            get_platform_info,
-            get_app_version,
-            get_metal_info
-        ]);
-    }
+/// Returns the configuration the TypeScript bindings are exported with.
+pub fn bindings_language() -> Typescript {
+    Typescript::default()
+        .bigint(BigIntExportBehavior::Number)
+        .header("// @ts-nocheck")
+}
 
-    // x86 Apple devices.
-    #[cfg(all(target_os = "macos", target_arch = "x86_64"))]
-    {
-        builder = builder.invoke_handler(tauri::generate_handler![
-            is_release_profile,
-            get_os_type,
-            get_cpu_info,
-            get_disks_info,
-            get_displays_info,
-            get_networks_info,
-            get_usb_info,
-            get_platform_info,
-            get_app_version,
-            get_vulkan_info,
-            get_opengl_info,
-            get_metal_info,
-        ]);
+pub fn run() {
+    if let Some(section) = probe::requested_section() {
+        std::process::exit(probe::run(&section));
     }
 
-    // Linux devices.
-    #[cfg(target_os = "linux")]
-    {
-        builder = builder.invoke_handler(tauri::generate_handler![
-            is_release_profile,
-            get_os_type,
-            get_cpu_info,
-            get_disks_info,
-            get_displays_info,
-            get_networks_info,
-            get_usb_info,
-            get_platform_info,
-            get_app_version,
-            get_vulkan_info,
-            get_opengl_info
-        ]);
+    // The logger isn't set up yet, so a broken configuration file is reported once it is.
+    let (config, config_error) = match config::load() {
+        Ok(config) => (config, None),
+        Err(e) => (Config::default(), Some(e)),
+    };
+
+    let mut logger = tauri_plugin_log::Builder::new()
+        .target(Target::new(TargetKind::Stderr))
+        .level(config.log_level());
+    if let Some(file) = &config.log.file {
+        logger = logger.target(Target::new(TargetKind::Folder {
+            path: file.parent().map(PathBuf::from).unwrap_or_default(),
+            file_name: file
+                .file_stem()
+                .map(|name| name.to_string_lossy().into_owned()),
+        }));
     }
 
-    builder
-        .setup(|app| {
-            app.manage(AppState::default());
+    let specta = specta_builder();
+
+    #[cfg(debug_assertions)]
+    specta
+        .export(bindings_language(), BINDINGS_PATH)
+        .expect("failed to export TypeScript bindings");
+
+    Builder::default()
+        .invoke_handler(specta.invoke_handler())
+        .setup(move |app| {
+            specta.mount_events(app);
+            if let Some(e) = config_error {
+                log::error!("failed to load the configuration, using the defaults: {e}");
+            }
+
+            let cache = AppState::default();
+            config.apply(&cache);
+            // The rules were checked when the configuration was loaded.
+            let alerts = Alerts::new(&config.alerts).unwrap_or_default();
+            config::set(config);
+            app.manage(cache);
+            app.manage(RemoteAgent::default());
+            app.manage(LoadedFleet::default());
+            app.manage(Monitoring::default());
+            app.manage(AlertState(Mutex::new(alerts)));
+            app.manage(InventoryState::default());
+
+            let handle = app.handle().clone();
+            async_runtime::spawn(async move {
+                if let Err(e) = check_inventory_changes(&handle).await {
+                    log::error!("failed to check the hardware inventory: {e}");
+                }
+            });
             Ok(())
         })
         .plugin(tauri_plugin_shell::init())
+        .plugin(tauri_plugin_dialog::init())
+        .plugin(tauri_plugin_notification::init())
         .plugin(tauri_plugin_os::init())
-        .plugin(
-            tauri_plugin_log::Builder::new()
-                .target(Target::new(TargetKind::Stderr))
-                .level(level)
-                .build(),
-        )
+        .plugin(logger.build())
         .run(tauri::generate_context!())
         .expect("error while running tauri application");
 }
@@ -2,5 +2,17 @@
 #![cfg_attr(not(debug_assertions), windows_subsystem = "windows")]
 
 fn main() {
+    // `revelio cpu`, `revelio all --export report.html`, etc. don't open the window.
+    if revelio_lib::cli::requested() {
+        // Release builds have no console of their own on Windows, so the output goes to the
+        // console of the shell the command was run from, if any.
+        #[cfg(target_os = "windows")]
+        unsafe {
+            winapi::um::wincon::AttachConsole(winapi::um::wincon::ATTACH_PARENT_PROCESS);
+        }
+
+        std::process::exit(revelio_lib::cli::run());
+    }
+
     revelio_lib::run()
 }
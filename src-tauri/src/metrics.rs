@@ -0,0 +1,296 @@
+use std::fmt::Write;
+
+use serde_json::Value;
+
+use crate::report::SystemReport;
+
+/// The content type of the exposition format [`render`] produces.
+pub const CONTENT_TYPE: &str = "application/openmetrics-text; version=1.0.0; charset=utf-8";
+
+/// The sections whose values change from one scrape to the next, which are gathered again on
+/// every scrape rather than served from a cache.
+pub const VOLATILE_SECTIONS: &[&str] = &["cpu", "disks", "network", "usb"];
+
+/// The type of a metric family.
+#[derive(Clone, Copy)]
+enum MetricType {
+    /// A value that can go up and down.
+    Gauge,
+    /// A value that only goes up, exposed with a `_total` suffix.
+    Counter,
+    /// Static facts exposed as labels of a sample whose value is always 1, with an `_info` suffix.
+    Info,
+}
+
+impl MetricType {
+    /// Returns the name of the type in the exposition format.
+    fn name(self) -> &'static str {
+        match self {
+            Self::Gauge => "gauge",
+            Self::Counter => "counter",
+            Self::Info => "info",
+        }
+    }
+
+    /// Returns the suffix of the samples of a family of this type.
+    fn suffix(self) -> &'static str {
+        match self {
+            Self::Gauge => "",
+            Self::Counter => "_total",
+            Self::Info => "_info",
+        }
+    }
+}
+
+/// Describes a metric family derived from the information of a section.
+struct Metric {
+    /// The name of the family.
+    name: &'static str,
+    /// The description of the family.
+    help: &'static str,
+    /// The type of the family.
+    kind: MetricType,
+    /// The section the family is derived from.
+    section: &'static str,
+    /// The array of the section with an element per sample, or `None` for a single sample derived
+    /// from the section itself.
+    array: Option<&'static str>,
+    /// The (label, field) pairs of the samples. Missing or null fields are left out.
+    labels: &'static [(&'static str, &'static str)],
+    /// The numeric field holding the value of the samples, or `None` for info metrics.
+    value: Option<&'static str>,
+}
+
+/// The metric families exposed by [`render`].
+const METRICS: &[Metric] = &[
+    Metric {
+        name: "revelio_disk_total_bytes",
+        help: "The total space of a disk.",
+        kind: MetricType::Gauge,
+        section: "disks",
+        array: Some("disks"),
+        labels: &[
+            ("mount_point", "mountPoint"),
+            ("name", "name"),
+            ("file_system", "fileSystem"),
+        ],
+        value: Some("totalSpace"),
+    },
+    Metric {
+        name: "revelio_disk_available_bytes",
+        help: "The space available on a disk.",
+        kind: MetricType::Gauge,
+        section: "disks",
+        array: Some("disks"),
+        labels: &[
+            ("mount_point", "mountPoint"),
+            ("name", "name"),
+            ("file_system", "fileSystem"),
+        ],
+        value: Some("availableSpace"),
+    },
+    Metric {
+        name: "revelio_network_interfaces",
+        help: "The number of network interfaces.",
+        kind: MetricType::Gauge,
+        section: "network",
+        array: None,
+        labels: &[],
+        value: Some("totalInterfaces"),
+    },
+    Metric {
+        name: "revelio_network_received_bytes",
+        help: "The number of bytes received on a network interface.",
+        kind: MetricType::Counter,
+        section: "network",
+        array: Some("interfaces"),
+        labels: &[("interface", "itfName")],
+        value: Some("receivedBytes"),
+    },
+    Metric {
+        name: "revelio_network_transmitted_bytes",
+        help: "The number of bytes transmitted on a network interface.",
+        kind: MetricType::Counter,
+        section: "network",
+        array: Some("interfaces"),
+        labels: &[("interface", "itfName")],
+        value: Some("transmittedBytes"),
+    },
+    Metric {
+        name: "revelio_cpu_core_frequency_hertz",
+        help: "The frequency of a CPU core.",
+        kind: MetricType::Gauge,
+        section: "cpu",
+        array: Some("cores"),
+        labels: &[("core", "id")],
+        value: Some("frequency"),
+    },
+    Metric {
+        name: "revelio_displays",
+        help: "The number of connected displays.",
+        kind: MetricType::Gauge,
+        section: "displays",
+        array: None,
+        labels: &[],
+        value: Some("totalDisplays"),
+    },
+    Metric {
+        name: "revelio_usb_devices",
+        help: "The number of connected USB devices.",
+        kind: MetricType::Gauge,
+        section: "usb",
+        array: None,
+        labels: &[],
+        value: Some("totalDevices"),
+    },
+    Metric {
+        name: "revelio_platform",
+        help: "The operating system and kernel.",
+        kind: MetricType::Info,
+        section: "platform",
+        array: None,
+        labels: &[
+            ("platform", "platform"),
+            ("kernel", "kernel"),
+            ("os_arch", "osArch"),
+            ("id", "id"),
+            ("version_id", "versionId"),
+            ("macos_version", "macOSVersion"),
+        ],
+        value: None,
+    },
+    Metric {
+        name: "revelio_vulkan_device",
+        help: "A Vulkan-enabled device and its driver.",
+        kind: MetricType::Info,
+        section: "vulkan",
+        array: Some("devices"),
+        labels: &[
+            ("index", "index"),
+            ("device_name", "deviceName"),
+            ("api_version", "apiVersion"),
+            ("driver_version", "driverVersion"),
+        ],
+        value: None,
+    },
+];
+
+/// Returns the sections the metrics are derived from.
+pub fn sections() -> Vec<&'static str> {
+    let mut sections: Vec<_> = METRICS.iter().map(|m| m.section).collect();
+    sections.sort_unstable();
+    sections.dedup();
+
+    sections
+}
+
+/// Escapes a label value for the exposition format.
+///
+/// # Arguments
+///
+/// * `value` - The label value.
+fn escape(value: &str) -> String {
+    value
+        .replace('\\', r"\\")
+        .replace('"', r#"\""#)
+        .replace('\n', r"\n")
+}
+
+/// Formats the labels of a sample, or returns an empty string if it has none.
+///
+/// # Arguments
+///
+/// * `labels` - The (label, value) pairs.
+fn format_labels(labels: &[(&str, String)]) -> String {
+    if labels.is_empty() {
+        return String::new();
+    }
+
+    let labels: Vec<_> = labels
+        .iter()
+        .map(|(name, value)| format!("{name}=\"{}\"", escape(value)))
+        .collect();
+
+    format!("{{{}}}", labels.join(","))
+}
+
+/// Returns the samples of a metric family, as (labels, value) pairs.
+///
+/// # Arguments
+///
+/// * `metric` - The metric family.
+/// * `data` - The information of the family's section.
+fn samples(metric: &Metric, data: &Value) -> Vec<(String, String)> {
+    let items = match metric.array {
+        Some(array) => match data.get(array) {
+            Some(Value::Array(items)) => items.iter().collect(),
+            _ => Vec::new(),
+        },
+        None => vec![data],
+    };
+
+    items
+        .into_iter()
+        .filter_map(|item| {
+            let value = match metric.value {
+                Some(field) => item.get(field).filter(|v| v.is_number())?.to_string(),
+                None => "1".into(),
+            };
+            let labels: Vec<_> = metric
+                .labels
+                .iter()
+                .filter_map(|(label, field)| match item.get(*field)? {
+                    Value::Null => None,
+                    Value::String(s) => Some((*label, s.clone())),
+                    value => Some((*label, value.to_string())),
+                })
+                .collect();
+
+            Some((format_labels(&labels), value))
+        })
+        .collect()
+}
+
+/// Renders metrics derived from the sections of a report in the OpenMetrics text format, for
+/// Prometheus to scrape.
+///
+/// Families whose section is missing from the report or failed to be gathered are left out, and
+/// `revelio_section_up` tells whether each section was gathered.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+pub fn render(report: &SystemReport) -> String {
+    let mut output = String::new();
+
+    let _ = writeln!(output, "# TYPE revelio_section_up gauge");
+    let _ = writeln!(
+        output,
+        "# HELP revelio_section_up Whether a section was gathered."
+    );
+    for (name, section) in &report.sections {
+        let up = u8::from(section.data.is_some());
+        let _ = writeln!(output, "revelio_section_up{{section=\"{name}\"}} {up}");
+    }
+
+    for metric in METRICS {
+        let Some(data) = report.section(metric.section) else {
+            continue;
+        };
+        let samples = samples(metric, data);
+        if samples.is_empty() {
+            continue;
+        }
+
+        let _ = writeln!(output, "# TYPE {} {}", metric.name, metric.kind.name());
+        let _ = writeln!(output, "# HELP {} {}", metric.name, metric.help);
+        for (labels, value) in samples {
+            let suffix = metric.kind.suffix();
+            let _ = writeln!(output, "{}{suffix}{labels} {value}", metric.name);
+        }
+    }
+
+    output.push_str("# EOF\n");
+
+    output
+}
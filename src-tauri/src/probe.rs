@@ -0,0 +1,204 @@
+use std::env;
+use std::io::Read;
+use std::process::{Child, Command, ExitStatus, Stdio};
+use std::thread::{self, JoinHandle};
+use std::time::{Duration, Instant};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::collectors::{Collector, REGISTRY};
+use crate::types::CoreError;
+
+/// The command-line argument that makes Revelio gather a single section and exit (Eg:
+/// `revelio --probe opengl`).
+pub const PROBE_ARG: &str = "--probe";
+
+/// How long a helper process may take to gather a section before it is killed.
+pub const PROBE_TIMEOUT: Duration = Duration::from_secs(15);
+
+/// How often a running helper process is checked for completion.
+const POLL_INTERVAL: Duration = Duration::from_millis(20);
+
+/// The result of gathering a section in a helper process, written as a single line of JSON to
+/// its stdout.
+#[derive(Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+enum ProbeOutput {
+    /// The section information.
+    Data(Value),
+    /// Why the section could not be gathered.
+    Error(String),
+}
+
+/// Returns the section to gather if the process was started in probe mode.
+pub fn requested_section() -> Option<String> {
+    let mut args = env::args().skip(1);
+
+    match args.next() {
+        Some(arg) if arg == PROBE_ARG => args.next(),
+        _ => None,
+    }
+}
+
+/// Gathers a section in the current process and writes the result to stdout. Called by the
+/// helper process; returns its exit code.
+///
+/// # Arguments
+///
+/// * `section` - The name of the section.
+pub fn run(section: &str) -> i32 {
+    match REGISTRY.get(section) {
+        Ok(collector) => run_collector(collector),
+        Err(e) => respond(ProbeOutput::Error(e.reason()), 1),
+    }
+}
+
+/// Gathers a section with the given collector and writes the result to stdout, as [`run`] does
+/// for the collectors of the registry. Returns the exit code of the helper process.
+///
+/// # Arguments
+///
+/// * `collector` - The collector of the section.
+pub fn run_collector(collector: &dyn Collector) -> i32 {
+    match collector.collect() {
+        Ok(data) => respond(ProbeOutput::Data(data), 0),
+        Err(e) => respond(ProbeOutput::Error(e.reason()), 1),
+    }
+}
+
+/// Writes the result of the helper process to stdout, returning its exit code.
+///
+/// # Arguments
+///
+/// * `output` - The result.
+/// * `code` - The exit code if the result could be written.
+fn respond(output: ProbeOutput, code: i32) -> i32 {
+    match serde_json::to_string(&output) {
+        Ok(line) => {
+            // Drivers may write to stdout as well, so the result goes on its own line.
+            println!("\n{line}");
+            code
+        }
+        Err(e) => {
+            eprintln!("{e}");
+            1
+        }
+    }
+}
+
+/// Reads a stream of the helper process to the end on a separate thread, so that the process
+/// never blocks on a full pipe.
+///
+/// # Arguments
+///
+/// * `stream` - The stream to read.
+fn read_to_end<R>(stream: Option<R>) -> JoinHandle<String>
+where
+    R: Read + Send + 'static,
+{
+    thread::spawn(move || {
+        let mut contents = String::new();
+        if let Some(mut stream) = stream {
+            let _ = stream.read_to_string(&mut contents);
+        }
+
+        contents
+    })
+}
+
+/// Waits for the helper process to exit, killing it once the timeout has elapsed.
+///
+/// Returns `None` if the process was killed.
+///
+/// # Arguments
+///
+/// * `child` - The helper process.
+/// * `timeout` - How long to wait for.
+fn wait_timeout(child: &mut Child, timeout: Duration) -> Result<Option<ExitStatus>, String> {
+    let deadline = Instant::now() + timeout;
+
+    loop {
+        if let Some(status) = child.try_wait().map_err(|e| e.to_string())? {
+            return Ok(Some(status));
+        }
+
+        if Instant::now() >= deadline {
+            let _ = child.kill();
+            let _ = child.wait();
+            return Ok(None);
+        }
+
+        thread::sleep(POLL_INTERVAL);
+    }
+}
+
+/// Describes how the helper process exited without producing a result.
+///
+/// # Arguments
+///
+/// * `status` - The exit status of the process.
+fn describe_exit(status: ExitStatus) -> String {
+    #[cfg(unix)]
+    {
+        use std::os::unix::process::ExitStatusExt;
+
+        if let Some(signal) = status.signal() {
+            return format!("the probe was terminated by signal {signal}");
+        }
+    }
+
+    match status.code() {
+        Some(code) => format!("the probe exited with code {code}"),
+        None => "the probe crashed".into(),
+    }
+}
+
+/// Gathers a section in a helper process, so that a crash or hang while gathering it (Eg: in a
+/// graphics driver) cannot take down the calling process.
+///
+/// The helper is the current executable, started with `--probe <section>`.
+///
+/// # Arguments
+///
+/// * `collector` - The collector of the section.
+/// * `timeout` - How long the helper process may run for.
+pub fn collect(collector: &dyn Collector, timeout: Duration) -> Result<Value, CoreError> {
+    let exe = env::current_exe().map_err(|e| collector.error(e.to_string()))?;
+    let mut child = Command::new(exe)
+        .args([PROBE_ARG, collector.name()])
+        .stdin(Stdio::null())
+        .stdout(Stdio::piped())
+        .stderr(Stdio::piped())
+        .spawn()
+        .map_err(|e| collector.error(format!("failed to start the probe: {e}")))?;
+
+    let stdout = read_to_end(child.stdout.take());
+    let stderr = read_to_end(child.stderr.take());
+    let status = wait_timeout(&mut child, timeout).map_err(|e| collector.error(e))?;
+    let stdout = stdout.join().unwrap_or_default();
+    let stderr = stderr.join().unwrap_or_default();
+
+    let Some(status) = status else {
+        return Err(collector.error(format!("the probe did not finish within {timeout:?}")));
+    };
+
+    let output = stdout
+        .lines()
+        .rev()
+        .find(|line| !line.trim().is_empty())
+        .and_then(|line| serde_json::from_str::<ProbeOutput>(line).ok());
+
+    match output {
+        Some(ProbeOutput::Data(data)) => Ok(data),
+        Some(ProbeOutput::Error(reason)) => Err(collector.error(reason)),
+        None => {
+            let mut reason = describe_exit(status);
+            if let Some(line) = stderr.lines().rev().find(|l| !l.trim().is_empty()) {
+                reason = format!("{reason}: {}", line.trim());
+            }
+
+            Err(collector.error(reason))
+        }
+    }
+}
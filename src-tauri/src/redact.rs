@@ -0,0 +1,246 @@
+use std::fs;
+use std::path::Path;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use sha2::{Digest, Sha256};
+use specta::Type;
+
+use crate::export::ExportFormat;
+use crate::report::SystemReport;
+use crate::state::SectionSnapshot;
+use crate::types::CoreError;
+
+/// Fields that identify the machine or its devices, keyed by section name and field path (`[]`
+/// stands for any array element). Redacted by [`default_rules`].
+const IDENTIFYING_FIELDS: &[(&str, &str)] = &[
+    ("platform", "hostname"),
+    ("vulkan", "devices[].pipelineCacheUuid"),
+    ("disks", "disks[].name"),
+    ("network", "interfaces[].macAddr"),
+    ("network", "interfaces[].ipNetworks[]"),
+    ("usb", "devices[].serialNumber"),
+];
+
+/// The number of bytes of a salted hash that are kept in a redacted value.
+const HASH_LEN: usize = 8;
+
+/// What is done to a redacted value.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize, Type, JsonSchema)]
+#[serde(tag = "action", rename_all = "camelCase")]
+pub enum RedactAction {
+    /// The value is removed (Eg: the field is left out of its object).
+    Drop,
+    /// The value is replaced by a hash salted with a secret unique to the report, so that equal
+    /// values still match within the report but can't be recovered or matched across reports.
+    Hash,
+    /// Only the leading characters of the value are kept.
+    Prefix {
+        /// The number of characters to keep (Eg: `8` keeps the manufacturer part of a MAC
+        /// address).
+        length: usize,
+    },
+}
+
+/// Describes how a field of a section is redacted.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize, Type, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct RedactionRule {
+    /// The section name (Eg: `usb`).
+    pub section: String,
+    /// The path of the field within the section, with `[]` standing for any array element (Eg:
+    /// `devices[].serialNumber`).
+    pub path: String,
+    /// What is done to the field.
+    #[serde(flatten)]
+    pub action: RedactAction,
+}
+
+/// Returns the rules that hash every field that identifies the machine or its devices.
+pub fn default_rules() -> Vec<RedactionRule> {
+    IDENTIFYING_FIELDS
+        .iter()
+        .map(|(section, path)| RedactionRule {
+            section: section.to_string(),
+            path: path.to_string(),
+            action: RedactAction::Hash,
+        })
+        .collect()
+}
+
+/// Loads redaction rules from a JSON or YAML file containing a list of rules.
+///
+/// # Arguments
+///
+/// * `path` - The path of the rules file.
+pub fn load_rules(path: &Path) -> Result<Vec<RedactionRule>, CoreError> {
+    let contents = fs::read_to_string(path).map_err(|e| CoreError::Error(e.into()))?;
+
+    match ExportFormat::from_path(path) {
+        Some(ExportFormat::Yaml) => {
+            serde_yaml::from_str(&contents).map_err(|e| CoreError::Error(e.into()))
+        }
+        _ => serde_json::from_str(&contents).map_err(|e| CoreError::Error(e.into())),
+    }
+}
+
+/// Joins a parent path and a field name.
+///
+/// # Arguments
+///
+/// * `parent` - The parent path.
+/// * `key` - The field name.
+fn join(parent: &str, key: &str) -> String {
+    if parent.is_empty() {
+        key.to_string()
+    } else {
+        format!("{parent}.{key}")
+    }
+}
+
+/// Applies redaction rules to reports and snapshots.
+///
+/// Each redactor has its own random salt, so a single redactor should be used for everything that
+/// is shared together (Eg: both reports of a diff).
+pub struct Redactor {
+    /// The rules to apply.
+    rules: Vec<RedactionRule>,
+    /// The secret the hashes are salted with.
+    salt: [u8; 16],
+}
+
+impl Redactor {
+    /// Creates a redactor with a new random salt.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The rules to apply.
+    pub fn new(rules: Vec<RedactionRule>) -> Result<Self, CoreError> {
+        let mut salt = [0; 16];
+        getrandom::getrandom(&mut salt).map_err(|e| CoreError::Error(e.to_string().into()))?;
+
+        Ok(Self::with_salt(rules, salt))
+    }
+
+    /// Creates a redactor with the given salt.
+    ///
+    /// # Arguments
+    ///
+    /// * `rules` - The rules to apply.
+    /// * `salt` - The secret the hashes are salted with.
+    pub fn with_salt(rules: Vec<RedactionRule>, salt: [u8; 16]) -> Self {
+        Self { rules, salt }
+    }
+
+    /// Redacts the information of a section in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section name.
+    /// * `data` - The section information.
+    pub fn redact_section(&self, section: &str, data: &mut Value) {
+        if self.rules.iter().any(|rule| rule.section == section) {
+            self.redact_value(section, "", data);
+        }
+    }
+
+    /// Redacts a section snapshot in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section name.
+    /// * `snapshot` - The section snapshot.
+    pub fn redact_snapshot(&self, section: &str, snapshot: &mut SectionSnapshot) {
+        self.redact_section(section, &mut snapshot.data);
+    }
+
+    /// Redacts every section of a report in place.
+    ///
+    /// # Arguments
+    ///
+    /// * `report` - The system report.
+    pub fn redact_report(&self, report: &mut SystemReport) {
+        for (name, section) in &mut report.sections {
+            if let Some(data) = &mut section.data {
+                self.redact_section(name, data);
+            }
+        }
+    }
+
+    /// Redacts a value and everything it contains. Returns `false` if the value must be dropped.
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section name.
+    /// * `pattern` - The path of the value, with `[]` in place of array indices.
+    /// * `value` - The value.
+    fn redact_value(&self, section: &str, pattern: &str, value: &mut Value) -> bool {
+        let rule = self
+            .rules
+            .iter()
+            .find(|rule| rule.section == section && rule.path == pattern);
+
+        if let Some(rule) = rule {
+            // Missing values are kept as they are, unless they're dropped.
+            match rule.action {
+                RedactAction::Drop => return false,
+                _ if value.is_null() => {}
+                RedactAction::Hash => *value = Value::String(self.hash(value)),
+                RedactAction::Prefix { length } => *value = Value::String(prefix(value, length)),
+            }
+
+            return true;
+        }
+
+        match value {
+            Value::Object(object) => {
+                object.retain(|key, value| self.redact_value(section, &join(pattern, key), value));
+            }
+            Value::Array(items) => {
+                let pattern = format!("{pattern}[]");
+                items.retain_mut(|item| self.redact_value(section, &pattern, item));
+            }
+            _ => {}
+        }
+
+        true
+    }
+
+    /// Hashes a value with the redactor's salt.
+    ///
+    /// # Arguments
+    ///
+    /// * `value` - The value.
+    fn hash(&self, value: &Value) -> String {
+        let mut hasher = Sha256::new();
+        hasher.update(self.salt);
+        match value {
+            Value::String(s) => hasher.update(s.as_bytes()),
+            v => hasher.update(v.to_string().as_bytes()),
+        }
+
+        hex::encode(&hasher.finalize()[..HASH_LEN])
+    }
+}
+
+/// Keeps the leading characters of a value, marking it as truncated.
+///
+/// # Arguments
+///
+/// * `value` - The value.
+/// * `length` - The number of characters to keep.
+fn prefix(value: &Value, length: usize) -> String {
+    let s = match value {
+        Value::String(s) => s.clone(),
+        v => v.to_string(),
+    };
+
+    if s.chars().count() <= length {
+        return s;
+    }
+
+    let mut prefix: String = s.chars().take(length).collect();
+    prefix.push('…');
+    prefix
+}
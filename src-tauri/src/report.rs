@@ -0,0 +1,177 @@
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::Path;
+use std::time::Duration;
+
+use schemars::JsonSchema;
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+
+use crate::collectors::REGISTRY;
+use crate::config;
+use crate::export::ExportFormat;
+use crate::state::{now_millis, SectionSnapshot, SectionTiming};
+use crate::types::CoreError;
+use crate::upgrade;
+
+/// The version of the [`SystemReport`] document format.
+///
+/// Must be incremented whenever a change to the report or any of the section types could break
+/// consumers of previously saved reports.
+pub const SCHEMA_VERSION: u32 = 2;
+
+/// Describes why a section could not be gathered.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionError {
+    /// The kind of error (same as the `kind` of a serialized [`CoreError`]).
+    pub kind: String,
+    /// The error message.
+    pub message: String,
+}
+
+impl From<&CoreError> for SectionError {
+    fn from(e: &CoreError) -> Self {
+        let kind = serde_json::to_value(e)
+            .ok()
+            .and_then(|v| v.get("kind").and_then(Value::as_str).map(str::to_string))
+            .unwrap_or_else(|| "error".into());
+
+        Self {
+            kind,
+            message: e.to_string(),
+        }
+    }
+}
+
+/// The outcome of gathering a single section of a report.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionReport {
+    /// The section information, if it was gathered successfully.
+    pub data: Option<Value>,
+    /// The time the information was gathered, in milliseconds since the Unix epoch.
+    pub collected_at: Option<u64>,
+    /// The error encountered while gathering the section, if any.
+    pub error: Option<SectionError>,
+}
+
+impl From<Result<SectionSnapshot, CoreError>> for SectionReport {
+    fn from(result: Result<SectionSnapshot, CoreError>) -> Self {
+        match result {
+            Ok(snapshot) => Self {
+                data: Some(snapshot.data),
+                collected_at: Some(snapshot.collected_at),
+                error: None,
+            },
+            Err(e) => Self {
+                data: None,
+                collected_at: None,
+                error: Some(SectionError::from(&e)),
+            },
+        }
+    }
+}
+
+/// A complete, self-describing snapshot of the system's information.
+#[derive(Debug, Clone, Serialize, Deserialize, Type, JsonSchema)]
+#[serde(rename_all = "camelCase")]
+pub struct SystemReport {
+    /// The version of the report format (see [`SCHEMA_VERSION`]).
+    pub schema_version: u32,
+    /// The version of Revelio that generated the report.
+    pub app_version: String,
+    /// The time the report was generated, in milliseconds since the Unix epoch.
+    pub generated_at: u64,
+    /// The gathered sections, keyed by section name.
+    pub sections: BTreeMap<String, SectionReport>,
+}
+
+impl SystemReport {
+    /// Creates a report from the outcomes of gathering each section.
+    ///
+    /// # Arguments
+    ///
+    /// * `sections` - The (section name, outcome) pairs.
+    pub fn from_sections<I>(sections: I) -> Self
+    where
+        I: IntoIterator<Item = (&'static str, Result<SectionSnapshot, CoreError>)>,
+    {
+        Self {
+            schema_version: SCHEMA_VERSION,
+            app_version: env!("CARGO_PKG_VERSION").into(),
+            generated_at: now_millis(),
+            sections: sections
+                .into_iter()
+                .map(|(name, result)| (name.to_string(), SectionReport::from(result)))
+                .collect(),
+        }
+    }
+
+    /// Gathers every section enabled on the current platform, without any caching.
+    pub fn collect() -> Self {
+        Self::collect_timed(None).0
+    }
+
+    /// Gathers every section enabled on the current platform, without any caching, and records
+    /// how long each section took.
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - How long each section may take to be gathered, or `None` to use the
+    ///   configured timeouts (see [`config::Config::timeout`]).
+    pub fn collect_timed(timeout: Option<Duration>) -> (Self, Vec<SectionTiming>) {
+        let config = config::current();
+        let mut sections = Vec::new();
+        let mut timings = Vec::new();
+
+        for collector in REGISTRY.enabled() {
+            let timeout = timeout.unwrap_or_else(|| config.timeout(collector));
+            let (result, timing) = SectionSnapshot::collect_timed(collector, timeout);
+            sections.push((collector.name(), result));
+            timings.push(timing);
+        }
+
+        (Self::from_sections(sections), timings)
+    }
+
+    /// Loads a previously saved report from a JSON or YAML file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path of the report file.
+    pub fn load(path: &Path) -> Result<Self, CoreError> {
+        let contents = fs::read_to_string(path).map_err(|e| CoreError::Error(e.into()))?;
+        let mut report: Self = match ExportFormat::from_path(path) {
+            Some(ExportFormat::Yaml) => {
+                serde_yaml::from_str(&contents).map_err(|e| CoreError::Error(e.into()))?
+            }
+            _ => serde_json::from_str(&contents).map_err(|e| CoreError::Error(e.into()))?,
+        };
+
+        if report.schema_version > SCHEMA_VERSION {
+            return Err(CoreError::Error(
+                format!(
+                    "{} uses report format version {}, but only versions up to {SCHEMA_VERSION} are supported",
+                    path.display(),
+                    report.schema_version
+                )
+                .into(),
+            ));
+        }
+
+        upgrade::upgrade(&mut report);
+
+        Ok(report)
+    }
+
+    /// Returns the information of a section, if it was gathered successfully.
+    ///
+    /// # Arguments
+    ///
+    /// * `name` - The section name.
+    pub fn section(&self, name: &str) -> Option<&Value> {
+        self.sections.get(name).and_then(|s| s.data.as_ref())
+    }
+}
@@ -0,0 +1,124 @@
+use schemars::generate::SchemaSettings;
+use schemars::{json_schema, Schema, SchemaGenerator};
+use serde_json::{json, Map, Value};
+
+use crate::collectors::{Collector, REGISTRY};
+use crate::report::{SectionReport, SystemReport};
+use crate::types::CoreError;
+
+/// The name of the schema of a complete [`SystemReport`].
+pub const REPORT_SCHEMA: &str = "report";
+
+/// Returns the names of all published schemas: the report, followed by every section in display
+/// order, including sections unavailable on the current platform.
+pub fn names() -> impl Iterator<Item = &'static str> {
+    std::iter::once(REPORT_SCHEMA).chain(REGISTRY.iter().map(|c| c.name()))
+}
+
+/// Creates a generator for the schemas of the serialized (output) form of the types.
+fn generator() -> SchemaGenerator {
+    SchemaSettings::draft2020_12()
+        .for_serialize()
+        .into_generator()
+}
+
+/// Turns a subschema into a standalone root schema that embeds the definitions it refers to.
+///
+/// # Arguments
+///
+/// * `generator` - The generator the subschema and its definitions were registered with.
+/// * `title` - The title of the schema.
+/// * `schema` - The subschema.
+fn into_root(mut generator: SchemaGenerator, title: &str, schema: Schema) -> Schema {
+    let mut root = json_schema!({
+        "$schema": generator.settings().meta_schema,
+        "title": title,
+    });
+
+    if let Some(object) = schema.as_object() {
+        for (key, value) in object {
+            root.insert(key.clone(), value.clone());
+        }
+    }
+
+    let definitions = generator.take_definitions(true);
+    if !definitions.is_empty() {
+        root.insert("$defs".into(), Value::Object(definitions));
+    }
+
+    root
+}
+
+/// Returns the schema of the information of a single section.
+///
+/// # Arguments
+///
+/// * `collector` - The collector of the section.
+pub fn section_schema(collector: &dyn Collector) -> Schema {
+    let mut generator = generator();
+    let schema = collector.schema(&mut generator);
+
+    into_root(generator, collector.title(), schema)
+}
+
+/// Returns the schema of a complete report, in which the `data` of each known section is
+/// described by the schema of that section's information.
+pub fn report_schema() -> Schema {
+    let mut generator = generator();
+    let schema = generator.subschema_for::<SystemReport>();
+    generator.subschema_for::<SectionReport>();
+    let section_report = generator
+        .definitions()
+        .get("SectionReport")
+        .cloned()
+        .unwrap_or_else(|| json!({ "type": "object" }));
+
+    let sections: Map<String, Value> = REGISTRY
+        .iter()
+        .map(|collector| {
+            let data = collector.schema(&mut generator);
+            let mut section = section_report.clone();
+            if let Value::Object(section) = &mut section {
+                // The other fields (Eg: `collectedAt`, `error`) keep their description.
+                if let Value::Object(properties) = section
+                    .entry("properties")
+                    .or_insert_with(|| Value::Object(Map::new()))
+                {
+                    properties.insert(
+                        "data".into(),
+                        json!({ "anyOf": [data, { "type": "null" }] }),
+                    );
+                }
+            }
+
+            (collector.name().to_string(), section)
+        })
+        .collect();
+
+    if let Some(Value::Object(report)) = generator.definitions_mut().get_mut("SystemReport") {
+        if let Some(Value::Object(properties)) = report.get_mut("properties") {
+            if let Some(Value::Object(field)) = properties.get_mut("sections") {
+                field.insert("properties".into(), Value::Object(sections));
+            }
+        }
+    }
+
+    into_root(generator, "System report", schema)
+}
+
+/// Returns the schema with the given name (see [`names`]).
+///
+/// # Arguments
+///
+/// * `name` - The name of the schema (Eg: `report`, `cpu`).
+pub fn schema(name: &str) -> Result<Schema, CoreError> {
+    if name == REPORT_SCHEMA {
+        return Ok(report_schema());
+    }
+
+    REGISTRY
+        .iter()
+        .find(|c| c.name() == name)
+        .map(section_schema)
+        .ok_or_else(|| CoreError::UnknownSection(name.into()))
+}
@@ -0,0 +1,289 @@
+use std::collections::HashMap;
+use std::sync::Mutex;
+use std::time::{Duration, Instant, SystemTime, UNIX_EPOCH};
+
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use specta::Type;
+
+use crate::collectors::{self, Collector, REGISTRY};
+use crate::report::SectionError;
+use crate::types::CoreError;
+
+/// The information of a section along with the time it was gathered.
+#[derive(Clone, Serialize, Deserialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionSnapshot {
+    /// The section information.
+    pub data: Value,
+    /// The time the information was gathered, in milliseconds since the Unix epoch.
+    pub collected_at: u64,
+}
+
+impl SectionSnapshot {
+    /// Gathers a section and timestamps the result.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    /// * `timeout` - How long the section may take to be gathered.
+    pub fn collect(
+        collector: &'static dyn Collector,
+        timeout: Duration,
+    ) -> Result<Self, CoreError> {
+        Ok(Self {
+            data: collectors::collect(collector, timeout)?,
+            collected_at: now_millis(),
+        })
+    }
+
+    /// Gathers a section, bypassing any cache, and records how long it took.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    /// * `timeout` - How long the section may take to be gathered.
+    pub fn collect_timed(
+        collector: &'static dyn Collector,
+        timeout: Duration,
+    ) -> (Result<Self, CoreError>, SectionTiming) {
+        let started = Instant::now();
+        let result = Self::collect(collector, timeout);
+        let timing = SectionTiming::new(collector, started, false, result.as_ref().err());
+
+        (result, timing)
+    }
+}
+
+/// How long a request for a section took, and how it ended.
+#[derive(Clone, Serialize, Type)]
+#[serde(rename_all = "camelCase")]
+pub struct SectionTiming {
+    /// The section name.
+    pub section: &'static str,
+    /// The time the request was made, in milliseconds since the Unix epoch.
+    pub requested_at: u64,
+    /// How long the request took, in milliseconds.
+    pub duration_ms: f64,
+    /// Whether the request was served from the cache.
+    pub cache_hit: bool,
+    /// The error the request failed with, if any (Eg: a timeout).
+    pub error: Option<SectionError>,
+}
+
+impl SectionTiming {
+    /// Creates the timing of a finished request.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    /// * `started` - The instant the request was made.
+    /// * `cache_hit` - Whether the request was served from the cache.
+    /// * `error` - The error the request failed with, if any.
+    pub fn new(
+        collector: &dyn Collector,
+        started: Instant,
+        cache_hit: bool,
+        error: Option<&CoreError>,
+    ) -> Self {
+        let elapsed = started.elapsed();
+
+        Self {
+            section: collector.name(),
+            requested_at: now_millis().saturating_sub(elapsed.as_millis() as u64),
+            duration_ms: elapsed.as_secs_f64() * 1000.0,
+            cache_hit,
+            error: error.map(SectionError::from),
+        }
+    }
+}
+
+/// Returns the current time in milliseconds since the Unix epoch.
+#[inline]
+pub fn now_millis() -> u64 {
+    SystemTime::now()
+        .duration_since(UNIX_EPOCH)
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or_default()
+}
+
+/// The cached state of a single section.
+#[derive(Default)]
+struct SectionSlot {
+    /// The last gathered snapshot. The lock is held while the section is being gathered, so
+    /// concurrent requests for the same section wait for the result instead of gathering it twice.
+    snapshot: Mutex<Option<SectionSnapshot>>,
+    /// The time-to-live of the cached snapshot, if any.
+    ttl: Mutex<Option<Duration>>,
+    /// The timeout of the section, if it overrides the collector's default.
+    timeout: Mutex<Option<Duration>>,
+    /// The timing of the last request for the section.
+    timing: Mutex<Option<SectionTiming>>,
+}
+
+impl SectionSlot {
+    /// Checks whether a cached snapshot is still valid.
+    ///
+    /// # Arguments
+    ///
+    /// * `snapshot` - The cached snapshot.
+    fn is_fresh(&self, snapshot: &SectionSnapshot) -> bool {
+        match *self.ttl.lock().unwrap() {
+            Some(ttl) => {
+                now_millis().saturating_sub(snapshot.collected_at) < ttl.as_millis() as u64
+            }
+            None => true,
+        }
+    }
+}
+
+/// Cache of gathered section information.
+///
+/// Every section has its own lock, so a slow collector only blocks requests for its own section.
+/// Sections are cached indefinitely unless a time-to-live (TTL) has been set for them, in which
+/// case they are gathered again once the TTL has elapsed. The timing of the last request for each
+/// section is kept for diagnostics.
+pub struct SectionCache {
+    slots: HashMap<&'static str, SectionSlot>,
+}
+
+impl Default for SectionCache {
+    fn default() -> Self {
+        Self {
+            slots: REGISTRY
+                .iter()
+                .map(|c| (c.name(), SectionSlot::default()))
+                .collect(),
+        }
+    }
+}
+
+impl SectionCache {
+    /// Returns the slot of a section.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    fn slot(&self, collector: &dyn Collector) -> &SectionSlot {
+        // Every registered collector gets a slot when the cache is created.
+        &self.slots[collector.name()]
+    }
+
+    /// Records the timing of a finished request for a section.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    /// * `started` - The instant the request was made.
+    /// * `cache_hit` - Whether the request was served from the cache.
+    /// * `result` - The outcome of the request.
+    fn record(
+        &self,
+        collector: &dyn Collector,
+        started: Instant,
+        cache_hit: bool,
+        result: &Result<SectionSnapshot, CoreError>,
+    ) {
+        let timing = SectionTiming::new(collector, started, cache_hit, result.as_ref().err());
+        *self.slot(collector).timing.lock().unwrap() = Some(timing);
+    }
+
+    /// Returns the cached snapshot of a section, gathering it if it is missing or expired.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    pub fn get_or_collect(
+        &self,
+        collector: &'static dyn Collector,
+    ) -> Result<SectionSnapshot, CoreError> {
+        let started = Instant::now();
+        let slot = self.slot(collector);
+        let mut cached = slot.snapshot.lock().unwrap();
+
+        if let Some(snapshot) = cached.as_ref() {
+            if slot.is_fresh(snapshot) {
+                let result = Ok(snapshot.clone());
+                self.record(collector, started, true, &result);
+                return result;
+            }
+        }
+
+        let result = SectionSnapshot::collect(collector, self.timeout(collector));
+        if let Ok(snapshot) = &result {
+            *cached = Some(snapshot.clone());
+        }
+        self.record(collector, started, false, &result);
+
+        result
+    }
+
+    /// Gathers a section again, replacing any cached snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    pub fn refresh(&self, collector: &'static dyn Collector) -> Result<SectionSnapshot, CoreError> {
+        let started = Instant::now();
+        let mut cached = self.slot(collector).snapshot.lock().unwrap();
+
+        let result = SectionSnapshot::collect(collector, self.timeout(collector));
+        if let Ok(snapshot) = &result {
+            *cached = Some(snapshot.clone());
+        }
+        self.record(collector, started, false, &result);
+
+        result
+    }
+
+    /// Removes the cached snapshot of a section, so that it is gathered again on the next request.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    pub fn invalidate(&self, collector: &dyn Collector) {
+        *self.slot(collector).snapshot.lock().unwrap() = None;
+    }
+
+    /// Sets or clears the time-to-live of a section's cached snapshot.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    /// * `ttl` - The time-to-live, or `None` to cache the section indefinitely.
+    pub fn set_ttl(&self, collector: &dyn Collector, ttl: Option<Duration>) {
+        *self.slot(collector).ttl.lock().unwrap() = ttl;
+    }
+
+    /// Returns how long a section may take to be gathered.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    pub fn timeout(&self, collector: &dyn Collector) -> Duration {
+        self.slot(collector)
+            .timeout
+            .lock()
+            .unwrap()
+            .unwrap_or_else(|| collector.timeout())
+    }
+
+    /// Sets or clears the timeout of a section.
+    ///
+    /// # Arguments
+    ///
+    /// * `collector` - The section's collector.
+    /// * `timeout` - The timeout, or `None` to use the collector's default.
+    pub fn set_timeout(&self, collector: &dyn Collector, timeout: Option<Duration>) {
+        *self.slot(collector).timeout.lock().unwrap() = timeout;
+    }
+
+    /// Returns the timing of the last request for each section that has been requested, in
+    /// display order.
+    pub fn timings(&self) -> Vec<SectionTiming> {
+        REGISTRY
+            .iter()
+            .filter_map(|c| self.slot(c).timing.lock().unwrap().clone())
+            .collect()
+    }
+}
@@ -0,0 +1,178 @@
+use serde_json::{json, Value};
+
+use crate::report::{SystemReport, SCHEMA_VERSION};
+
+/// Converts a formatted value of an older report format into its current form. Returns `None` if
+/// the value isn't recognized, in which case it is left as is.
+type Upgrade = fn(&str) -> Option<Value>;
+
+/// The fields that were serialized as formatted strings in version 1 of the report format, keyed
+/// by section name, array and field.
+const V1_FORMATTED_FIELDS: &[(&str, &str, &str, Upgrade)] = &[
+    ("vulkan", "devices", "deviceType", v1_vulkan_device_type),
+    ("metal", "devices", "location", v1_metal_location),
+    (
+        "metal",
+        "devices",
+        "maxThreadsPerThreadgroup",
+        v1_metal_size,
+    ),
+    ("metal", "devices", "argumentBuffersSupport", v1_metal_tier),
+    ("metal", "devices", "readWriteTextureSupport", v1_metal_tier),
+    ("displays", "displays", "dimensions", v1_dimensions),
+    ("displays", "displays", "position", v1_position),
+    ("disks", "disks", "kind", v1_disk_kind),
+    ("usb", "devices", "speed", v1_usb_speed),
+];
+
+/// Parses display dimensions formatted as `<width>x<height>`.
+fn v1_dimensions(s: &str) -> Option<Value> {
+    let (width, height) = s.split_once('x')?;
+    let width: u32 = width.parse().ok()?;
+    let height: u32 = height.parse().ok()?;
+
+    Some(json!({ "width": width, "height": height }))
+}
+
+/// Parses a display position formatted as `(<x>, <y>)`.
+fn v1_position(s: &str) -> Option<Value> {
+    let (x, y) = s.strip_prefix('(')?.strip_suffix(')')?.split_once(", ")?;
+    let x: i32 = x.parse().ok()?;
+    let y: i32 = y.parse().ok()?;
+
+    Some(json!({ "x": x, "y": y }))
+}
+
+/// Parses a disk kind formatted as prose (Eg: `Solid State Drive (SSD)`).
+fn v1_disk_kind(s: &str) -> Option<Value> {
+    match s {
+        "Hard Disk Drive (HDD)" => Some(json!("hdd")),
+        "Solid State Drive (SSD)" => Some(json!("ssd")),
+        _ => {
+            let n: isize = s
+                .strip_prefix("Unknown(")?
+                .strip_suffix(')')?
+                .parse()
+                .ok()?;
+            Some(json!({ "unknown": n }))
+        }
+    }
+}
+
+/// Parses a USB speed formatted as prose (Eg: `High speed (480 Mbit)`).
+fn v1_usb_speed(s: &str) -> Option<Value> {
+    let (class, mbps) = match s {
+        "Low speed (1.5 Mbit)" => ("low", Some(1.5)),
+        "Full speed (12 Mbit)" => ("full", Some(12.0)),
+        "High speed (480 Mbit)" => ("high", Some(480.0)),
+        "Super speed (5,000 Mbit)" => ("super", Some(5000.0)),
+        "Super speed (10,000 Mbit)" => ("superPlus", Some(10000.0)),
+        "Unknown" => ("unknown", None),
+        _ => return None,
+    };
+
+    Some(json!({ "class": class, "mbps": mbps }))
+}
+
+/// Parses a Vulkan device type formatted as its Vulkan constant name (Eg:
+/// `VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU`).
+fn v1_vulkan_device_type(s: &str) -> Option<Value> {
+    let device_type = match s {
+        "VK_PHYSICAL_DEVICE_TYPE_OTHER" => "other",
+        "VK_PHYSICAL_DEVICE_TYPE_INTEGRATED_GPU" => "integratedGpu",
+        "VK_PHYSICAL_DEVICE_TYPE_DISCRETE_GPU" => "discreteGpu",
+        "VK_PHYSICAL_DEVICE_TYPE_VIRTUAL_GPU" => "virtualGpu",
+        "VK_PHYSICAL_DEVICE_TYPE_CPU" => "cpu",
+        "Unknown" => return Some(Value::Null),
+        _ => return None,
+    };
+
+    Some(json!(device_type))
+}
+
+/// Parses a Metal device location formatted as prose (Eg: `Built-in`).
+fn v1_metal_location(s: &str) -> Option<Value> {
+    let location = match s {
+        "Built-in" => "builtIn",
+        "Slot" => "slot",
+        "External" => "external",
+        "Unspecified" => "unspecified",
+        _ => return None,
+    };
+
+    Some(json!(location))
+}
+
+/// Parses a Metal size formatted as its product along with its dimensions (Eg:
+/// `1,024 (depth: 1 width: 1024 height: 1)`).
+fn v1_metal_size(s: &str) -> Option<Value> {
+    let (_, dimensions) = s.strip_suffix(')')?.split_once('(')?;
+    let mut size = json!({});
+
+    let mut words = dimensions.split_whitespace();
+    while let (Some(name), Some(value)) = (words.next(), words.next()) {
+        let value: u64 = value.parse().ok()?;
+        size[name.strip_suffix(':')?] = json!(value);
+    }
+
+    ["width", "height", "depth"]
+        .iter()
+        .all(|name| size.get(name).is_some())
+        .then_some(size)
+}
+
+/// Parses a Metal support tier formatted as prose (Eg: `Tier 1`).
+fn v1_metal_tier(s: &str) -> Option<Value> {
+    let tier = match s {
+        "Tier None" => "tierNone",
+        "Tier 1" => "tier1",
+        "Tier 2" => "tier2",
+        _ => return None,
+    };
+
+    Some(json!(tier))
+}
+
+/// Converts the sections of a report in version 1 of the report format, whose structured values
+/// were serialized as formatted strings.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+fn upgrade_v1(report: &mut SystemReport) {
+    for (section, array, field, upgrade) in V1_FORMATTED_FIELDS {
+        let Some(data) = report
+            .sections
+            .get_mut(*section)
+            .and_then(|s| s.data.as_mut())
+        else {
+            continue;
+        };
+        let Some(Value::Array(items)) = data.get_mut(*array) else {
+            continue;
+        };
+
+        for item in items {
+            let Some(value) = item.get_mut(*field) else {
+                continue;
+            };
+            if let Some(upgraded) = value.as_str().and_then(upgrade) {
+                *value = upgraded;
+            }
+        }
+    }
+}
+
+/// Converts a report saved in an older version of the report format to the current one, in
+/// place.
+///
+/// # Arguments
+///
+/// * `report` - The system report.
+pub fn upgrade(report: &mut SystemReport) {
+    if report.schema_version < 2 {
+        upgrade_v1(report);
+    }
+
+    report.schema_version = SCHEMA_VERSION;
+}
@@ -1,8 +0,0 @@
-#[cfg(target_os = "windows")]
-pub mod directx;
-
-#[cfg(target_os = "macos")]
-pub mod metal;
-pub mod opengl;
-#[cfg(not(all(target_os = "macos", target_arch = "aarch64")))]
-pub mod vulkan;
@@ -1,7 +0,0 @@
-pub mod cpu;
-pub mod disks;
-pub mod displays;
-pub mod gpu;
-pub mod network;
-pub mod platform;
-pub mod usb;
@@ -0,0 +1,126 @@
+//! Checks the routing, authentication and error responses of the HTTP agent.
+
+use std::io::{Read, Write};
+use std::net::{SocketAddr, TcpStream};
+use std::thread;
+
+use revelio_lib::agent::server::AgentServer;
+use revelio_lib::agent::AgentInfo;
+use revelio_lib::state::{SectionCache, SectionSnapshot};
+use serde_json::Value;
+
+/// Starts an agent on a free port of the loopback interface, returning its address.
+///
+/// # Arguments
+///
+/// * `token` - The bearer token requests must carry, if any.
+fn start(token: Option<&str>) -> SocketAddr {
+    let server = AgentServer::bind(
+        "127.0.0.1:0",
+        SectionCache::default(),
+        token.map(String::from),
+        None,
+    )
+    .unwrap();
+    let addr = server.local_addr().unwrap();
+    thread::spawn(move || server.run());
+
+    addr
+}
+
+/// Makes a request to an agent, returning the status code and the body.
+///
+/// # Arguments
+///
+/// * `addr` - The address of the agent.
+/// * `method` - The request method.
+/// * `path` - The request path.
+/// * `token` - The bearer token to send, if any.
+fn request(addr: SocketAddr, method: &str, path: &str, token: Option<&str>) -> (u16, String) {
+    let mut stream = TcpStream::connect(addr).unwrap();
+    let authorization = token
+        .map(|token| format!("Authorization: Bearer {token}\r\n"))
+        .unwrap_or_default();
+    write!(
+        stream,
+        "{method} {path} HTTP/1.0\r\nHost: {addr}\r\n{authorization}Content-Length: 0\r\n\r\n"
+    )
+    .unwrap();
+
+    let mut response = String::new();
+    stream.read_to_string(&mut response).unwrap();
+    let (head, body) = response.split_once("\r\n\r\n").unwrap();
+    let status = head.split(' ').nth(1).unwrap().parse().unwrap();
+
+    (status, body.to_string())
+}
+
+#[test]
+fn agent_describes_itself() {
+    let addr = start(None);
+    let (status, body) = request(addr, "GET", "/v1/agent", None);
+
+    assert_eq!(status, 200);
+    let info: AgentInfo = serde_json::from_str(&body).unwrap();
+    assert_eq!(info.app_version, env!("CARGO_PKG_VERSION"));
+    assert!(info.sections.iter().any(|section| section == "cpu"));
+}
+
+#[test]
+fn sections_are_served() {
+    let addr = start(None);
+    let (status, body) = request(addr, "GET", "/v1/cpu", None);
+
+    assert_eq!(status, 200);
+    let snapshot: SectionSnapshot = serde_json::from_str(&body).unwrap();
+    assert!(snapshot.data.is_object());
+
+    let (status, _) = request(addr, "GET", "/v1/cpu?refresh=true", None);
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn unknown_sections_are_not_found() {
+    let addr = start(None);
+    let (status, body) = request(addr, "GET", "/v1/floppy", None);
+
+    assert_eq!(status, 404);
+    let error: Value = serde_json::from_str(&body).unwrap();
+    assert_eq!(error["kind"], "unknownSection");
+
+    let (status, _) = request(addr, "GET", "/v2/cpu", None);
+    assert_eq!(status, 404);
+}
+
+#[test]
+fn only_get_is_allowed() {
+    let addr = start(None);
+    let (status, _) = request(addr, "POST", "/v1/cpu", None);
+
+    assert_eq!(status, 405);
+}
+
+#[test]
+fn token_is_required() {
+    let addr = start(Some("secret"));
+
+    let (status, _) = request(addr, "GET", "/v1/agent", None);
+    assert_eq!(status, 401);
+
+    let (status, _) = request(addr, "GET", "/v1/agent", Some("guess"));
+    assert_eq!(status, 401);
+
+    let (status, _) = request(addr, "GET", "/v1/agent", Some("secret"));
+    assert_eq!(status, 200);
+}
+
+#[test]
+fn metrics_are_scraped() {
+    let addr = start(None);
+    let (status, body) = request(addr, "GET", "/metrics", None);
+
+    assert_eq!(status, 200);
+    assert!(body.contains("# TYPE revelio_section_up gauge\n"));
+    assert!(body.contains("revelio_section_up{section=\"cpu\"} "));
+    assert!(body.ends_with("# EOF\n"));
+}
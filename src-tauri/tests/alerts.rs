@@ -0,0 +1,132 @@
+//! Checks the evaluation of alert rules against the information of sections.
+
+use revelio_lib::alerts::{AlertEvent, Alerts, METRICS};
+use revelio_lib::config::AlertRule;
+use revelio_lib::types::CoreError;
+use serde_json::json;
+
+/// Returns an alert rule.
+///
+/// # Arguments
+///
+/// * `name` - The name of the rule.
+/// * `when` - The query that raises the alert while it matches, if any.
+/// * `unless` - The query that raises the alert while it doesn't match, if any.
+fn rule(name: &str, when: Option<&str>, unless: Option<&str>) -> AlertRule {
+    AlertRule {
+        name: name.into(),
+        when: when.map(String::from),
+        unless: unless.map(String::from),
+        message: Some(format!("{name}!")),
+    }
+}
+
+/// Returns the names of alert events, prefixed with `+` when they fire and `-` when they're
+/// resolved.
+///
+/// # Arguments
+///
+/// * `events` - The alert events.
+fn names(events: Vec<AlertEvent>) -> Vec<String> {
+    events
+        .into_iter()
+        .map(|event| format!("{}{}", if event.firing { '+' } else { '-' }, event.name))
+        .collect()
+}
+
+/// Returns the information of the disks section with a single disk.
+///
+/// # Arguments
+///
+/// * `available_space` - The available space of the disk, out of 1000 bytes.
+fn disks(available_space: u64) -> serde_json::Value {
+    json!({
+        "count": 1,
+        "disks": [{ "name": "/dev/sda1", "totalSpace": 1000, "availableSpace": available_space }],
+    })
+}
+
+#[test]
+fn alerts_fire_and_resolve_once() {
+    let mut alerts = Alerts::new(&[rule(
+        "Disk almost full",
+        Some("disks.disks[].availablePercent < 10"),
+        None,
+    )])
+    .unwrap();
+
+    assert!(alerts.update("disks", &disks(500)).is_empty());
+    assert_eq!(
+        names(alerts.update("disks", &disks(50))),
+        ["+Disk almost full"]
+    );
+    assert!(alerts.update("disks", &disks(40)).is_empty());
+    assert_eq!(
+        names(alerts.update("disks", &disks(900))),
+        ["-Disk almost full"]
+    );
+
+    // Other sections don't evaluate the rule.
+    assert!(alerts.update("usb", &json!({})).is_empty());
+}
+
+#[test]
+fn missing_devices_raise_alerts_once_gathered() {
+    let key = "usb.devices[].vendorId = 0x1050 and usb.devices[].productId = 0x0407";
+    let mut alerts = Alerts::new(&[rule("Key unplugged", None, Some(key))]).unwrap();
+    let usb = |product_id: u16| {
+        json!({
+            "totalDevices": 1,
+            "devices": [{ "vendorId": 0x1050, "productId": product_id }],
+        })
+    };
+
+    assert!(alerts.update("usb", &usb(0x0407)).is_empty());
+    let events = alerts.update("usb", &usb(0x0010));
+    assert_eq!(events[0].message, "Key unplugged!");
+    assert_eq!(names(events), ["+Key unplugged"]);
+}
+
+#[test]
+fn rules_about_several_sections_wait_for_all_of_them() {
+    let mut alerts = Alerts::new(&[
+        rule(
+            "Offline",
+            Some("network.interfaces[].itfName = eth0 and network.interfaces[].ipNetworkCount = 0"),
+            None,
+        ),
+        rule(
+            "Hot and full",
+            Some("metrics.temperatures[].temperature > 90 and disks.disks[].usedPercent > 90"),
+            None,
+        ),
+    ])
+    .unwrap();
+
+    let network = json!({
+        "totalInterfaces": 1,
+        "interfaces": [{ "itfName": "eth0", "ipNetworks": [] }],
+    });
+    assert_eq!(names(alerts.update("network", &network)), ["+Offline"]);
+
+    let metrics = json!({ "temperatures": [{ "label": "Package id 0", "temperature": 95.0 }] });
+    assert!(alerts.update(METRICS, &metrics).is_empty());
+    assert_eq!(names(alerts.update("disks", &disks(50))), ["+Hot and full"]);
+}
+
+#[test]
+fn invalid_rules_are_rejected() {
+    assert!(Alerts::new(&[rule("Neither", None, None)]).is_err());
+    assert!(Alerts::new(&[rule(
+        "Both",
+        Some("cpu.brand ~ intel"),
+        Some("cpu.brand ~ amd")
+    )])
+    .is_err());
+    assert!(Alerts::new(&[rule("Empty", Some(""), None)]).is_err());
+    assert!(Alerts::new(&[rule("Broken", Some("cpu.brand"), None)]).is_err());
+    assert!(matches!(
+        Alerts::new(&[rule("Floppy", Some("floppy.drives[].count > 0"), None)]),
+        Err(CoreError::UnknownSection(section)) if &*section == "floppy"
+    ));
+}
@@ -0,0 +1,28 @@
+//! Checks that the TypeScript bindings in `src/bindings.ts` match the commands, events and types
+//! of the Rust core.
+//!
+//! The bindings are exported whenever the app runs in a debug build. To regenerate them without
+//! running the app, run this test with `UPDATE_BINDINGS=1`.
+
+use std::env;
+use std::fs;
+
+use revelio_lib::{bindings_language, specta_builder, BINDINGS_PATH};
+
+#[test]
+fn bindings_are_up_to_date() {
+    let expected = specta_builder()
+        .export_str(bindings_language())
+        .expect("failed to render TypeScript bindings");
+
+    if env::var_os("UPDATE_BINDINGS").is_some() {
+        fs::write(BINDINGS_PATH, &expected).expect("failed to write TypeScript bindings");
+        return;
+    }
+
+    let actual = fs::read_to_string(BINDINGS_PATH).unwrap_or_default();
+    assert!(
+        actual == expected,
+        "{BINDINGS_PATH} is out of date; regenerate it by running `UPDATE_BINDINGS=1 cargo test --test bindings`"
+    );
+}
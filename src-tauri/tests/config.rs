@@ -0,0 +1,84 @@
+//! Checks the loading and saving of the configuration file, using the files in `tests/config`.
+
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use revelio_lib::collectors::REGISTRY;
+use revelio_lib::config::{self, Config, LogLevel};
+use revelio_lib::export::ExportFormat;
+use revelio_lib::types::CoreError;
+
+/// Returns the path of a configuration file in `tests/config`.
+///
+/// # Arguments
+///
+/// * `name` - The file name.
+fn fixture(name: &str) -> PathBuf {
+    Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/config")
+        .join(name)
+}
+
+#[test]
+fn settings_are_loaded() {
+    let config = Config::load(&fixture("config.toml")).unwrap();
+
+    assert!(!config.is_enabled("opengl"));
+    assert!(config.is_enabled("vulkan"));
+    assert_eq!(config.section("usb").ttl, Some(30));
+    let usb = REGISTRY.iter().find(|c| c.name() == "usb").unwrap();
+    assert_eq!(config.timeout(usb), Duration::from_secs(5));
+    assert!(config.redaction.enabled);
+    assert_eq!(config.export.format, Some(ExportFormat::Html));
+    assert_eq!(config.log.level, Some(LogLevel::Info));
+    assert_eq!(config.log_level(), log::LevelFilter::Info);
+    assert_eq!(config.history.interval, 30);
+    assert_eq!(config.history.raw, 24);
+    assert_eq!(
+        config.history_path(),
+        Some(PathBuf::from("/var/lib/revelio/history.sqlite"))
+    );
+    assert_eq!(config.alerts[0].name, "Disk almost full");
+    assert_eq!(config.alerts[0].unless, None);
+}
+
+#[test]
+fn missing_files_use_the_defaults() {
+    let config = Config::load(&fixture("missing.toml")).unwrap();
+
+    assert_eq!(config, Config::default());
+    assert!(REGISTRY.iter().all(|c| config.is_enabled(c.name())));
+}
+
+#[test]
+fn unknown_sections_are_rejected() {
+    let error = Config::load(&fixture("unknown.toml")).unwrap_err();
+
+    assert!(matches!(error, CoreError::UnknownSection(section) if &*section == "floppy"));
+}
+
+#[test]
+fn settings_are_saved() {
+    let path = std::env::temp_dir()
+        .join(format!("revelio-config-{}", std::process::id()))
+        .join("config.toml");
+    let config = Config::load(&fixture("config.toml")).unwrap();
+
+    config.save(&path).unwrap();
+    assert_eq!(Config::load(&path).unwrap(), config);
+
+    std::fs::remove_dir_all(path.parent().unwrap()).unwrap();
+}
+
+#[test]
+fn disabled_sections_are_skipped() {
+    config::set(Config::load(&fixture("config.toml")).unwrap());
+
+    assert!(REGISTRY.enabled().all(|c| c.name() != "opengl"));
+    assert!(matches!(
+        REGISTRY.get("opengl"),
+        Err(CoreError::DisabledSection(_))
+    ));
+
+    config::set(Config::default());
+}
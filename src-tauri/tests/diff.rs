@@ -0,0 +1,183 @@
+//! Checks the comparison of two reports, in particular how the elements of arrays of devices are
+//! matched between them.
+
+use revelio_lib::diff::{ChangeKind, ReportDiff};
+use revelio_lib::report::{SystemReport, SCHEMA_VERSION};
+use serde_json::{json, Value};
+
+/// Returns a report with the given sections.
+///
+/// # Arguments
+///
+/// * `generated_at` - The time the report was generated.
+/// * `sections` - The sections, keyed by section name.
+fn report(generated_at: u64, sections: Value) -> SystemReport {
+    serde_json::from_value(json!({
+        "schemaVersion": SCHEMA_VERSION,
+        "appVersion": "0.0.0",
+        "generatedAt": generated_at,
+        "sections": sections
+    }))
+    .unwrap()
+}
+
+/// Returns a report with the given USB devices.
+///
+/// # Arguments
+///
+/// * `generated_at` - The time the report was generated.
+/// * `devices` - The USB devices.
+fn usb_report(generated_at: u64, devices: Vec<Value>) -> SystemReport {
+    report(
+        generated_at,
+        json!({
+            "usb": {
+                "data": { "totalDevices": devices.len(), "devices": devices },
+                "collectedAt": generated_at
+            }
+        }),
+    )
+}
+
+/// Returns a USB device.
+///
+/// # Arguments
+///
+/// * `index` - The enumeration index of the device.
+/// * `product_id` - The product ID of the device.
+/// * `serial_number` - The serial number of the device, if any.
+fn usb_device(index: usize, product_id: u16, serial_number: Option<&str>) -> Value {
+    json!({
+        "index": index,
+        "vendorId": 0x1050,
+        "productId": product_id,
+        "serialNumber": serial_number,
+        "productString": "YubiKey"
+    })
+}
+
+/// Returns a disk.
+///
+/// # Arguments
+///
+/// * `mount_point` - The mount point of the disk.
+/// * `available_space` - The available space of the disk, in bytes.
+fn disk(mount_point: &str, available_space: u64) -> Value {
+    json!({
+        "name": "/dev/sda1",
+        "mountPoint": mount_point,
+        "totalSpace": 1000,
+        "availableSpace": available_space
+    })
+}
+
+#[test]
+fn usb_devices_are_matched_by_identity() {
+    let old = usb_report(
+        1,
+        vec![usb_device(0, 1, Some("A")), usb_device(1, 2, Some("B"))],
+    );
+    // Unplugging B and plugging C in shifts the enumeration order of A.
+    let new = usb_report(
+        2,
+        vec![usb_device(0, 3, Some("C")), usb_device(1, 1, Some("A"))],
+    );
+
+    let diff = ReportDiff::new(&old, &new);
+    assert_eq!(diff.old_generated_at, 1);
+    assert_eq!(diff.new_generated_at, 2);
+
+    let changes = &diff.sections["usb"].changes;
+    let paths: Vec<_> = changes
+        .iter()
+        .map(|change| (change.kind, change.path.as_str()))
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            (ChangeKind::Removed, "devices[4176:2:B]"),
+            (ChangeKind::Added, "devices[4176:3:C]"),
+        ]
+    );
+    assert_eq!(changes[0].old.as_ref().unwrap()["serialNumber"], "B");
+    assert_eq!(changes[1].new.as_ref().unwrap()["serialNumber"], "C");
+}
+
+#[test]
+fn disks_are_matched_by_mount_point() {
+    let disks = |disks: Vec<Value>| {
+        json!({
+            "disks": {
+                "data": { "count": disks.len(), "disks": disks },
+                "collectedAt": 0
+            }
+        })
+    };
+    let old = report(0, disks(vec![disk("/", 500), disk("/home", 100)]));
+    let new = report(0, disks(vec![disk("/home", 50), disk("/", 500)]));
+
+    let changes = &ReportDiff::new(&old, &new).sections["disks"].changes;
+    assert_eq!(changes.len(), 1);
+    assert_eq!(changes[0].kind, ChangeKind::Changed);
+    assert_eq!(changes[0].path, "disks[/home].availableSpace");
+    assert_eq!(changes[0].old, Some(json!(100)));
+    assert_eq!(changes[0].new, Some(json!(50)));
+}
+
+#[test]
+fn duplicate_keys_are_told_apart() {
+    let old = usb_report(0, vec![usb_device(0, 1, None), usb_device(1, 1, None)]);
+    let new = usb_report(
+        0,
+        vec![
+            usb_device(0, 1, None),
+            usb_device(1, 1, None),
+            usb_device(2, 1, None),
+        ],
+    );
+
+    let changes = &ReportDiff::new(&old, &new).sections["usb"].changes;
+    let paths: Vec<_> = changes
+        .iter()
+        .map(|change| (change.kind, change.path.as_str()))
+        .collect();
+    assert_eq!(
+        paths,
+        [
+            (ChangeKind::Changed, "totalDevices"),
+            (ChangeKind::Added, "devices[4176:1:-#3]"),
+        ]
+    );
+}
+
+#[test]
+fn only_the_enumeration_order_changing_is_not_a_change() {
+    let old = usb_report(0, vec![usb_device(0, 1, Some("A")), usb_device(1, 2, None)]);
+    let new = usb_report(0, vec![usb_device(0, 2, None), usb_device(1, 1, Some("A"))]);
+
+    assert!(ReportDiff::new(&old, &new).is_empty());
+}
+
+#[test]
+fn sections_without_data_are_skipped() {
+    let old = usb_report(0, vec![usb_device(0, 1, Some("A"))]);
+    let new = report(
+        0,
+        json!({
+            "usb": {
+                "error": { "kind": "error", "message": "access denied" }
+            }
+        }),
+    );
+
+    let diff = ReportDiff::new(&old, &new);
+    let usb = &diff.sections["usb"];
+    assert!(usb.changes.is_empty());
+    assert_eq!(
+        usb.skipped.as_deref(),
+        Some("Not available in the new report: access denied")
+    );
+
+    // Sections that failed in both reports are the same.
+    assert!(ReportDiff::new(&new, &new).is_empty());
+}
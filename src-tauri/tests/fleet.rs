@@ -0,0 +1,88 @@
+//! Checks the indexing and querying of the reports of a fleet, using the reports in `tests/fleet`.
+
+use std::path::Path;
+
+use revelio_lib::fleet::{Fleet, Query};
+
+fn fleet() -> Fleet {
+    Fleet::load(&Path::new(env!("CARGO_MANIFEST_DIR")).join("tests/fleet")).unwrap()
+}
+
+/// Returns the hostnames of the hosts matching a query.
+///
+/// # Arguments
+///
+/// * `query` - The query.
+fn hostnames(query: &str) -> Vec<String> {
+    fleet()
+        .query(&Query::parse(query).unwrap())
+        .into_iter()
+        .map(|host| host.hostname)
+        .collect()
+}
+
+#[test]
+fn reports_are_indexed_by_hostname() {
+    let fleet = fleet();
+    let summary = fleet.summary();
+
+    assert_eq!(summary.hosts, 2);
+    assert_eq!(summary.skipped.len(), 1);
+    assert!(summary.skipped[0].path.ends_with("broken.json"));
+
+    // The older report of ws-01 is superseded by the newer one.
+    let hosts = fleet.query(&Query::default());
+    assert_eq!(hosts[0].hostname, "ws-01");
+    assert!(hosts[0].path.ends_with("ws-01.json"));
+    assert_eq!(hosts[0].kernel.as_deref(), Some("6.8.0-45-generic"));
+}
+
+#[test]
+fn fields_are_compared() {
+    assert_eq!(hostnames("platform.graphicsPlatform = wayland"), ["ws-01"]);
+    assert_eq!(hostnames("platform.graphicsPlatform != Wayland"), ["ws-02"]);
+    assert_eq!(hostnames("vulkan.devices[].deviceName ~ radeon"), ["ws-02"]);
+    assert_eq!(hostnames(""), ["ws-01", "ws-02"]);
+}
+
+#[test]
+fn versions_are_compared_by_part() {
+    assert_eq!(
+        hostnames("vulkan.devices[].driverVersion >= 535.183"),
+        ["ws-01", "ws-02"]
+    );
+    assert_eq!(
+        hostnames("vulkan.devices[].driverVersion > 550.9"),
+        ["ws-02"]
+    );
+}
+
+#[test]
+fn conditions_hold_for_the_same_element() {
+    // ws-02 has an NVIDIA device with a recent driver, and an AMD device with an older one.
+    assert_eq!(
+        hostnames("vulkan.devices[].vendorId = 0x10de and vulkan.devices[].driverVersion < 540"),
+        ["ws-01"]
+    );
+    assert_eq!(
+        hostnames("vulkan.devices[].vendorId = 0x1002 AND vulkan.devices[].driverVersion < 540"),
+        ["ws-02"]
+    );
+}
+
+#[test]
+fn derived_fields_are_queried() {
+    assert_eq!(
+        hostnames("disks.disks[].usedPercent > 90"),
+        ["ws-01", "ws-02"]
+    );
+    assert_eq!(hostnames("disks.disks[].usedPercent > 96"), ["ws-02"]);
+    assert_eq!(hostnames("disks.disks[].availablePercent < 5"), ["ws-02"]);
+}
+
+#[test]
+fn invalid_queries_are_rejected() {
+    assert!(Query::parse("platform.kernel").is_err());
+    assert!(Query::parse("vulkan..vendorId = 1").is_err());
+    assert!(Query::parse("platform.kernel =").is_err());
+}
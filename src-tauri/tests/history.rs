@@ -0,0 +1,154 @@
+//! Checks the recording, downsampling and querying of the metrics history, using a database in
+//! the temporary directory.
+
+use std::path::PathBuf;
+
+use revelio_lib::config::HistoryConfig;
+use revelio_lib::history::{History, HistoryPoint};
+use revelio_lib::monitor::{CoreSample, DiskSample, MemorySample, MetricsSample};
+
+const MINUTE: u64 = 60 * 1000;
+const HOUR: u64 = 60 * MINUTE;
+/// A time on an hour boundary, in milliseconds since the Unix epoch.
+const START: u64 = 1_760_000_400_000;
+
+/// Returns the path of a fresh database, unique to the test.
+///
+/// # Arguments
+///
+/// * `name` - The name of the test.
+fn database(name: &str) -> PathBuf {
+    let path = std::env::temp_dir()
+        .join(format!("revelio-history-{}", std::process::id()))
+        .join(format!("{name}.sqlite"));
+    let _ = std::fs::remove_file(&path);
+
+    path
+}
+
+/// Returns a sample with two cores at a usage and a disk with some space available.
+///
+/// # Arguments
+///
+/// * `sampled_at` - The time of the sample, in milliseconds since the Unix epoch.
+/// * `usage` - The usage of both cores, in percent.
+/// * `available_space` - The available space of the disk, in bytes.
+fn sample(sampled_at: u64, usage: f32, available_space: u64) -> MetricsSample {
+    let core = |name: &str| CoreSample {
+        name: name.into(),
+        usage,
+        frequency: 3_000_000_000,
+    };
+
+    MetricsSample {
+        sampled_at,
+        cores: vec![core("cpu0"), core("cpu1")],
+        memory: MemorySample {
+            total_memory: 16,
+            used_memory: 8,
+            available_memory: 8,
+            total_swap: 0,
+            used_swap: 0,
+        },
+        interfaces: Vec::new(),
+        disks: vec![DiskSample {
+            name: "/dev/sda1".into(),
+            mount_point: "/".into(),
+            total_space: 1000,
+            available_space,
+            read_bytes: None,
+            written_bytes: None,
+            read_rate: None,
+            write_rate: None,
+        }],
+        temperatures: Vec::new(),
+    }
+}
+
+#[test]
+fn samples_are_queried_by_metric_and_range() {
+    let path = database("query");
+    let mut history = History::open(&path).unwrap();
+    for i in 0..3 {
+        history
+            .record(&sample(START + i * 10_000, 10.0 * i as f32, 250))
+            .unwrap();
+    }
+
+    let series = history
+        .query("cpu.usage", None, START + 10_000, START + HOUR)
+        .unwrap();
+    assert_eq!(series.len(), 1);
+    assert_eq!(series[0].unit, "percent");
+    assert_eq!(
+        series[0].points,
+        [
+            HistoryPoint {
+                time: START + 10_000,
+                value: 10.0
+            },
+            HistoryPoint {
+                time: START + 20_000,
+                value: 20.0
+            },
+        ]
+    );
+
+    let series = History::open_read_only(&path)
+        .unwrap()
+        .query("disk.used", Some("/"), START, START + HOUR)
+        .unwrap();
+    assert_eq!(series[0].subject, "/");
+    assert_eq!(series[0].points[0].value, 75.0);
+
+    assert!(history.query("floppy.usage", None, 0, u64::MAX).is_err());
+}
+
+#[test]
+fn old_samples_are_averaged_then_deleted() {
+    let path = database("downsample");
+    let mut history = History::open(&path).unwrap();
+    let config = HistoryConfig::default();
+
+    // Two samples in the first minute, and one in the second.
+    history.record(&sample(START, 10.0, 500)).unwrap();
+    history.record(&sample(START + 30_000, 20.0, 500)).unwrap();
+    history.record(&sample(START + MINUTE, 60.0, 500)).unwrap();
+
+    // A day later, both minutes are averaged.
+    history
+        .downsample(&config, START + config.raw * HOUR + 2 * MINUTE)
+        .unwrap();
+    let points = &history
+        .query("cpu.usage", None, START, START + HOUR)
+        .unwrap()[0]
+        .points;
+    assert_eq!(points.len(), 2);
+    assert_eq!(points[0].value, 15.0);
+    assert_eq!(points[1].value, 60.0);
+
+    // A week later, the hour is averaged over the three samples rather than the two minutes.
+    history
+        .downsample(&config, START + config.minutes * 24 * HOUR + HOUR)
+        .unwrap();
+    let points = &history
+        .query("cpu.usage", None, START, START + HOUR)
+        .unwrap()[0]
+        .points;
+    assert_eq!(
+        points,
+        &[HistoryPoint {
+            time: START,
+            value: 30.0
+        }]
+    );
+
+    // Once past the retention, nothing is left.
+    history
+        .downsample(&config, START + config.hours * 24 * HOUR + HOUR)
+        .unwrap();
+    assert!(history
+        .query("cpu.usage", None, 0, u64::MAX)
+        .unwrap()
+        .is_empty());
+}
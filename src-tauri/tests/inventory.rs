@@ -0,0 +1,212 @@
+//! Checks the comparison of the hardware inventory with the one saved by the last run, using a
+//! file in the temporary directory.
+
+use std::path::PathBuf;
+
+use revelio_lib::diff::ChangeKind;
+use revelio_lib::inventory::{self, InventoryFile};
+use revelio_lib::report::{SectionReport, SystemReport, SCHEMA_VERSION};
+use serde_json::{json, Value};
+
+/// Returns the path of a fresh inventory file, unique to the test.
+///
+/// # Arguments
+///
+/// * `name` - The name of the test.
+fn inventory_file(name: &str) -> PathBuf {
+    let path = std::env::temp_dir()
+        .join(format!("revelio-inventory-{}", std::process::id()))
+        .join(format!("{name}.json"));
+    let _ = std::fs::remove_file(&path);
+
+    path
+}
+
+/// Returns a report with a disk, some USB devices and the kernel version, and a failed Vulkan
+/// section.
+///
+/// # Arguments
+///
+/// * `kernel` - The kernel version.
+/// * `available_space` - The available space of the disk, in bytes.
+/// * `usb` - The USB devices.
+fn report(kernel: &str, available_space: u64, usb: Value) -> SystemReport {
+    serde_json::from_value(json!({
+        "schemaVersion": SCHEMA_VERSION,
+        "appVersion": "0.0.0",
+        "generatedAt": 0,
+        "sections": {
+            "platform": {
+                "data": { "platform": "Linux", "hostname": "lab-01", "kernel": kernel },
+                "collectedAt": 0
+            },
+            "disks": {
+                "data": {
+                    "count": 1,
+                    "disks": [{
+                        "name": "/dev/sda1",
+                        "mountPoint": "/",
+                        "totalSpace": 1000,
+                        "availableSpace": available_space
+                    }]
+                },
+                "collectedAt": 0
+            },
+            "network": {
+                "data": { "totalInterfaces": 0, "interfaces": [] },
+                "collectedAt": 0
+            },
+            "usb": {
+                "data": { "totalDevices": usb.as_array().unwrap().len(), "devices": usb },
+                "collectedAt": 0
+            },
+            "vulkan": {
+                "error": { "kind": "vulkanInfoError", "message": "no driver" }
+            }
+        }
+    }))
+    .unwrap()
+}
+
+/// Returns a USB device.
+///
+/// # Arguments
+///
+/// * `product_id` - The product ID of the device.
+/// * `serial_number` - The serial number of the device.
+fn usb_device(product_id: u16, serial_number: &str) -> Value {
+    json!({
+        "vendorId": 0x1050,
+        "productId": product_id,
+        "serialNumber": serial_number,
+        "productString": "YubiKey",
+        "busNumber": 1,
+        "speed": { "class": "full", "mbps": 12.0 }
+    })
+}
+
+#[test]
+fn inventory_keeps_identifying_fields() {
+    let inventory = inventory::inventory(&report("6.8.0", 500, json!([usb_device(1, "A")])));
+
+    assert!(!inventory.sections.contains_key("network"));
+    assert_eq!(
+        inventory.section("platform").unwrap(),
+        &json!({ "platform": "Linux", "kernel": "6.8.0" })
+    );
+    assert_eq!(
+        inventory.section("disks").unwrap(),
+        &json!({ "disks": [{ "name": "/dev/sda1", "mountPoint": "/", "totalSpace": 1000 }] })
+    );
+    assert_eq!(
+        inventory.section("usb").unwrap()["devices"][0],
+        json!({
+            "vendorId": 0x1050,
+            "productId": 1,
+            "serialNumber": "A",
+            "productString": "YubiKey"
+        })
+    );
+    assert!(inventory.sections["vulkan"].error.is_some());
+}
+
+#[test]
+fn changes_are_reported_since_the_last_run() {
+    let path = inventory_file("changes");
+
+    let mut file = InventoryFile::open(&path).unwrap();
+    let usb = json!([usb_device(1, "A"), usb_device(2, "B")]);
+    assert!(file.check(&report("6.8.0", 500, usb)).unwrap().is_none());
+
+    // The next run only sees changes to the hardware, not to the available space.
+    let mut file = InventoryFile::open(&path).unwrap();
+    let diff = file
+        .check(&report("6.8.0", 100, json!([usb_device(1, "A")])))
+        .unwrap()
+        .unwrap();
+    assert_eq!(diff.sections.keys().collect::<Vec<_>>(), ["usb"]);
+    let removed = &diff.sections["usb"].changes[0];
+    assert_eq!(removed.kind, ChangeKind::Removed);
+    assert_eq!(removed.old.as_ref().unwrap()["serialNumber"], "B");
+
+    // Checking again compares with the last run rather than the previous check.
+    let diff = file
+        .check(&report("6.11.0", 100, json!([usb_device(1, "A")])))
+        .unwrap()
+        .unwrap();
+    assert_eq!(
+        diff.sections.keys().collect::<Vec<_>>(),
+        ["platform", "usb"]
+    );
+
+    let mut file = InventoryFile::open(&path).unwrap();
+    assert!(file
+        .check(&report("6.11.0", 100, json!([usb_device(1, "A")])))
+        .unwrap()
+        .unwrap()
+        .is_empty());
+}
+
+#[test]
+fn failed_sections_keep_their_last_inventory() {
+    let path = inventory_file("failed");
+    let usb = || json!([usb_device(1, "A")]);
+
+    let mut with_vulkan = report("6.8.0", 500, usb());
+    with_vulkan.sections.insert(
+        "vulkan".into(),
+        SectionReport {
+            data: Some(json!({
+                "devices": [{ "pipelineCacheUuid": "10de-0", "deviceName": "RTX 3070" }]
+            })),
+            collected_at: Some(0),
+            error: None,
+        },
+    );
+    InventoryFile::open(&path)
+        .unwrap()
+        .check(&with_vulkan)
+        .unwrap();
+
+    // The failure is reported, but the next run still compares with the saved devices.
+    let diff = InventoryFile::open(&path)
+        .unwrap()
+        .check(&report("6.8.0", 500, usb()))
+        .unwrap()
+        .unwrap();
+    assert!(diff.sections["vulkan"].skipped.is_some());
+
+    let diff = InventoryFile::open(&path)
+        .unwrap()
+        .check(&with_vulkan)
+        .unwrap()
+        .unwrap();
+    assert!(diff.is_empty());
+}
+
+#[test]
+fn missing_sections_keep_their_last_inventory() {
+    let path = inventory_file("missing");
+    let usb = || json!([usb_device(1, "A")]);
+
+    InventoryFile::open(&path)
+        .unwrap()
+        .check(&report("6.8.0", 500, usb()))
+        .unwrap();
+
+    // Turning the USB section off doesn't report its devices as removed...
+    let mut without_usb = report("6.8.0", 500, usb());
+    without_usb.sections.remove("usb");
+    let mut file = InventoryFile::open(&path).unwrap();
+    assert!(file.check(&without_usb).unwrap().unwrap().is_empty());
+
+    // ...nor as added once it's turned back on, by this check or the next run.
+    let diff = file.check(&report("6.8.0", 500, usb())).unwrap().unwrap();
+    assert!(!diff.sections.contains_key("usb"));
+    let diff = InventoryFile::open(&path)
+        .unwrap()
+        .check(&report("6.8.0", 500, usb()))
+        .unwrap()
+        .unwrap();
+    assert!(!diff.sections.contains_key("usb"));
+}
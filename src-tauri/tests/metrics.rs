@@ -0,0 +1,125 @@
+//! Checks the metrics derived from a report, as exposed to Prometheus by the agent.
+
+use revelio_lib::metrics;
+use revelio_lib::report::SystemReport;
+use revelio_lib::state::SectionSnapshot;
+use revelio_lib::types::CoreError;
+use serde_json::{json, Value};
+
+/// Creates a successfully gathered section.
+///
+/// # Arguments
+///
+/// * `data` - The section information.
+fn gathered(data: Value) -> Result<SectionSnapshot, CoreError> {
+    Ok(SectionSnapshot {
+        data,
+        collected_at: 0,
+    })
+}
+
+/// A report of a machine with a disk, two network interfaces and a Vulkan device, whose USB
+/// section failed to be gathered.
+fn report() -> SystemReport {
+    SystemReport::from_sections([
+        (
+            "disks",
+            gathered(json!({
+                "count": 1,
+                "disks": [{
+                    "name": "/dev/nvme0n1p2",
+                    "fileSystem": "ext4",
+                    "mountPoint": "/",
+                    "totalSpace": 511_000_000_000_u64,
+                    "availableSpace": 123_000_000_000_u64,
+                    "kind": "ssd",
+                }],
+            })),
+        ),
+        (
+            "network",
+            gathered(json!({
+                "totalInterfaces": 2,
+                "interfaces": [
+                    { "itfName": "lo", "receivedBytes": 100, "transmittedBytes": 100 },
+                    { "itfName": "wlp2s0", "receivedBytes": 2048, "transmittedBytes": 512 },
+                ],
+            })),
+        ),
+        (
+            "cpu",
+            gathered(json!({
+                "cores": [
+                    { "id": 0, "frequency": 4_200_000_000_u64 },
+                    { "id": 1, "frequency": 3_600_000_000_u64 },
+                ],
+            })),
+        ),
+        (
+            "platform",
+            gathered(json!({
+                "platform": "Linux",
+                "kernel": "6.8.0-45-generic",
+                "osArch": "x86_64",
+                "id": "ubuntu",
+                "versionId": "24.04",
+            })),
+        ),
+        (
+            "vulkan",
+            gathered(json!({
+                "totalDevices": 1,
+                "devices": [{
+                    "index": 0,
+                    "deviceName": "AMD Radeon \"RX\" 7800",
+                    "apiVersion": "1.3.274",
+                    "driverVersion": "2.0.294",
+                }],
+            })),
+        ),
+        ("usb", Err(CoreError::USBInfoError("access denied".into()))),
+    ])
+}
+
+#[test]
+fn gauges_are_labelled_per_element() {
+    let output = metrics::render(&report());
+
+    assert!(output.contains("# TYPE revelio_disk_total_bytes gauge\n"));
+    assert!(output.contains(
+        "revelio_disk_total_bytes{mount_point=\"/\",name=\"/dev/nvme0n1p2\",file_system=\"ext4\"} 511000000000\n"
+    ));
+    assert!(output.contains(
+        "revelio_disk_available_bytes{mount_point=\"/\",name=\"/dev/nvme0n1p2\",file_system=\"ext4\"} 123000000000\n"
+    ));
+    assert!(output.contains("revelio_cpu_core_frequency_hertz{core=\"1\"} 3600000000\n"));
+    assert!(output.contains("revelio_network_interfaces 2\n"));
+}
+
+#[test]
+fn counters_and_info_metrics_are_suffixed() {
+    let output = metrics::render(&report());
+
+    assert!(output.contains("# TYPE revelio_network_received_bytes counter\n"));
+    assert!(output.contains("revelio_network_received_bytes_total{interface=\"wlp2s0\"} 2048\n"));
+    assert!(output.contains("revelio_network_transmitted_bytes_total{interface=\"wlp2s0\"} 512\n"));
+
+    assert!(output.contains("# TYPE revelio_platform info\n"));
+    assert!(output.contains(
+        "revelio_platform_info{platform=\"Linux\",kernel=\"6.8.0-45-generic\",os_arch=\"x86_64\",id=\"ubuntu\",version_id=\"24.04\"} 1\n"
+    ));
+    assert!(output.contains(
+        "revelio_vulkan_device_info{index=\"0\",device_name=\"AMD Radeon \\\"RX\\\" 7800\",api_version=\"1.3.274\",driver_version=\"2.0.294\"} 1\n"
+    ));
+}
+
+#[test]
+fn failed_sections_are_down() {
+    let output = metrics::render(&report());
+
+    assert!(output.contains("revelio_section_up{section=\"disks\"} 1\n"));
+    assert!(output.contains("revelio_section_up{section=\"usb\"} 0\n"));
+    assert!(!output.contains("revelio_usb_devices"));
+    assert!(!output.contains("revelio_displays"));
+    assert!(output.ends_with("# EOF\n"));
+}
@@ -0,0 +1,147 @@
+//! Checks that sections gathered in a helper process are returned to the calling process, and that
+//! failures and hangs of the helper are reported as section errors.
+//!
+//! The helper is this test binary, started with `--probe <section>`, so it runs without the
+//! standard test harness (see `main`).
+
+use std::env;
+use std::fs;
+use std::process;
+use std::thread;
+use std::time::{Duration, Instant};
+
+use revelio_lib::collectors::{self, Collector};
+use revelio_lib::probe;
+use revelio_lib::types::CoreError;
+use schemars::{Schema, SchemaGenerator};
+use serde_json::{json, Value};
+
+/// The environment variable holding the path of the file the `flaky` section creates on its first
+/// attempt.
+const MARKER_VAR: &str = "REVELIO_PROBE_MARKER";
+
+/// An isolated section, gathered by this test binary in probe mode:
+///
+/// * `echo` prints to stdout, as drivers may, then returns a fixed value.
+/// * `hang` doesn't finish within any reasonable timeout.
+/// * `flaky` fails on its first attempt and succeeds on the next ones.
+/// * `broken` exits without writing a result, as a crashing helper would.
+struct TestProbe(&'static str);
+
+impl Collector for TestProbe {
+    fn name(&self) -> &'static str {
+        self.0
+    }
+
+    fn title(&self) -> &'static str {
+        self.0
+    }
+
+    fn is_isolated(&self) -> bool {
+        true
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::OpenGLInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        match self.0 {
+            "echo" => {
+                println!("driver noise");
+                Ok(json!({ "answer": 42 }))
+            }
+            "hang" => {
+                thread::sleep(Duration::from_secs(60));
+                Ok(Value::Null)
+            }
+            "flaky" => {
+                let marker = env::var(MARKER_VAR).expect("the marker path is set");
+                if fs::metadata(&marker).is_ok() {
+                    return Ok(json!({ "attempt": "retry" }));
+                }
+
+                fs::write(&marker, "").map_err(|e| self.error(e.to_string()))?;
+                Err(self.error("the first attempt fails".into()))
+            }
+            // Exits without writing a result.
+            _ => process::exit(3),
+        }
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<Value>()
+    }
+}
+
+static ECHO: TestProbe = TestProbe("echo");
+static HANG: TestProbe = TestProbe("hang");
+static FLAKY: TestProbe = TestProbe("flaky");
+static BROKEN: TestProbe = TestProbe("broken");
+
+fn probe_result_is_returned() {
+    let data = collectors::collect(&ECHO, Duration::from_secs(5)).unwrap();
+
+    assert_eq!(data, json!({ "answer": 42 }));
+}
+
+fn failed_probe_is_a_section_error() {
+    let e = collectors::collect(&BROKEN, Duration::from_secs(5)).unwrap_err();
+
+    assert!(matches!(e, CoreError::OpenGLInfoError(_)), "{e}");
+    assert!(
+        e.reason().starts_with("the probe exited with code 3"),
+        "{e}"
+    );
+}
+
+fn hanging_probe_is_killed() {
+    let started = Instant::now();
+    let e = collectors::collect(&HANG, Duration::from_millis(500)).unwrap_err();
+
+    assert!(matches!(e, CoreError::OpenGLInfoError(_)), "{e}");
+    assert!(
+        e.reason().starts_with("the probe did not finish within"),
+        "{e}"
+    );
+    assert!(started.elapsed() < Duration::from_secs(10));
+}
+
+fn retry_starts_a_new_probe() {
+    let marker = env::temp_dir().join(format!("revelio-probe-{}", process::id()));
+    let _ = fs::remove_file(&marker);
+    env::set_var(MARKER_VAR, &marker);
+
+    let e = collectors::collect(&FLAKY, Duration::from_secs(5)).unwrap_err();
+    assert_eq!(e.reason(), "the first attempt fails");
+
+    let data = collectors::collect(&FLAKY, Duration::from_secs(5)).unwrap();
+    assert_eq!(data, json!({ "attempt": "retry" }));
+
+    let _ = fs::remove_file(&marker);
+}
+
+/// Runs the tests in order, or gathers a section when started as a helper process.
+fn main() {
+    if let Some(section) = probe::requested_section() {
+        let collector = [&ECHO, &HANG, &FLAKY, &BROKEN]
+            .into_iter()
+            .find(|probe| probe.0 == section)
+            .unwrap_or(&BROKEN);
+        process::exit(probe::run_collector(collector));
+    }
+
+    let tests: &[(&str, fn())] = &[
+        ("probe_result_is_returned", probe_result_is_returned),
+        (
+            "failed_probe_is_a_section_error",
+            failed_probe_is_a_section_error,
+        ),
+        ("hanging_probe_is_killed", hanging_probe_is_killed),
+        ("retry_starts_a_new_probe", retry_starts_a_new_probe),
+    ];
+    for (name, test) in tests {
+        test();
+        println!("test {name} ... ok");
+    }
+}
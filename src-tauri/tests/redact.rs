@@ -0,0 +1,142 @@
+//! Checks the redaction of identifying values in reports.
+
+use revelio_lib::redact::{self, RedactAction, RedactionRule, Redactor};
+use revelio_lib::report::SystemReport;
+use serde_json::{json, Value};
+
+fn report() -> SystemReport {
+    serde_json::from_value(json!({
+        "schemaVersion": 1,
+        "appVersion": "0.0.0",
+        "generatedAt": 0,
+        "sections": {
+            "platform": {
+                "data": { "hostname": "fedora-server", "name": "Fedora Linux" },
+                "collectedAt": 0,
+                "error": null
+            },
+            "network": {
+                "data": {
+                    "totalInterfaces": 2,
+                    "interfaces": [
+                        {
+                            "itfName": "eth0",
+                            "macAddr": "b4:2e:99:3c:4d:5e",
+                            "ipNetworks": ["192.168.1.20/24", "fe80::1/64"]
+                        },
+                        {
+                            "itfName": "br0",
+                            "macAddr": "b4:2e:99:3c:4d:5e",
+                            "ipNetworks": []
+                        }
+                    ]
+                },
+                "collectedAt": 0,
+                "error": null
+            },
+            "usb": {
+                "data": {
+                    "devices": [
+                        { "productString": "Keyboard", "serialNumber": "0123456789" },
+                        { "productString": "Hub", "serialNumber": null }
+                    ]
+                },
+                "collectedAt": 0,
+                "error": null
+            }
+        }
+    }))
+    .unwrap()
+}
+
+fn rule(section: &str, path: &str, action: RedactAction) -> RedactionRule {
+    RedactionRule {
+        section: section.into(),
+        path: path.into(),
+        action,
+    }
+}
+
+#[test]
+fn default_rules_hash_identifying_values() {
+    let mut report = report();
+    Redactor::with_salt(redact::default_rules(), [1; 16]).redact_report(&mut report);
+
+    let platform = report.section("platform").unwrap();
+    assert_ne!(platform["hostname"], "fedora-server");
+    assert_eq!(platform["name"], "Fedora Linux");
+
+    let interfaces = &report.section("network").unwrap()["interfaces"];
+    assert_ne!(interfaces[0]["macAddr"], "b4:2e:99:3c:4d:5e");
+    assert_eq!(interfaces[0]["itfName"], "eth0");
+    assert_ne!(interfaces[0]["ipNetworks"][0], "192.168.1.20/24");
+
+    // The same device still correlates within the report.
+    assert_eq!(interfaces[0]["macAddr"], interfaces[1]["macAddr"]);
+
+    let devices = &report.section("usb").unwrap()["devices"];
+    assert_ne!(devices[0]["serialNumber"], "0123456789");
+    assert_eq!(devices[1]["serialNumber"], Value::Null);
+}
+
+#[test]
+fn hashes_depend_on_the_salt() {
+    let mut first = report();
+    let mut second = report();
+    Redactor::with_salt(redact::default_rules(), [1; 16]).redact_report(&mut first);
+    Redactor::with_salt(redact::default_rules(), [2; 16]).redact_report(&mut second);
+
+    assert_ne!(
+        first.section("platform").unwrap()["hostname"],
+        second.section("platform").unwrap()["hostname"]
+    );
+}
+
+#[test]
+fn drop_and_prefix() {
+    let mut report = report();
+    let rules = vec![
+        rule(
+            "network",
+            "interfaces[].macAddr",
+            RedactAction::Prefix { length: 8 },
+        ),
+        rule("network", "interfaces[].ipNetworks[]", RedactAction::Drop),
+        rule("usb", "devices[].serialNumber", RedactAction::Drop),
+    ];
+    Redactor::with_salt(rules, [0; 16]).redact_report(&mut report);
+
+    let interfaces = &report.section("network").unwrap()["interfaces"];
+    assert_eq!(interfaces[0]["macAddr"], "b4:2e:99…");
+    assert_eq!(interfaces[0]["ipNetworks"], json!([]));
+
+    let devices = report.section("usb").unwrap()["devices"]
+        .as_array()
+        .unwrap();
+    assert!(devices.iter().all(|d| d.get("serialNumber").is_none()));
+    assert_eq!(devices[0]["productString"], "Keyboard");
+
+    // Sections without rules are left untouched.
+    assert_eq!(
+        report.section("platform").unwrap()["hostname"],
+        "fedora-server"
+    );
+}
+
+#[test]
+fn rules_round_trip_through_yaml() {
+    let yaml = "- section: network\n  path: interfaces[].macAddr\n  action: prefix\n  length: 8\n- section: usb\n  path: devices[].serialNumber\n  action: drop\n";
+    let rules: Vec<RedactionRule> = serde_yaml::from_str(yaml).unwrap();
+
+    assert_eq!(
+        rules,
+        vec![
+            rule(
+                "network",
+                "interfaces[].macAddr",
+                RedactAction::Prefix { length: 8 }
+            ),
+            rule("usb", "devices[].serialNumber", RedactAction::Drop),
+        ]
+    );
+}
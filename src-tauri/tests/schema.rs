@@ -0,0 +1,92 @@
+//! Checks the published JSON Schemas of the section information types and of the full report.
+
+use revelio_lib::collectors::REGISTRY;
+use revelio_lib::schema::{self, REPORT_SCHEMA};
+use serde_json::Value;
+
+/// Collects every `$ref` in a schema.
+fn refs<'a>(value: &'a Value, out: &mut Vec<&'a str>) {
+    match value {
+        Value::Object(object) => {
+            for (key, value) in object {
+                match (key.as_str(), value) {
+                    ("$ref", Value::String(r)) => out.push(r),
+                    _ => refs(value, out),
+                }
+            }
+        }
+        Value::Array(values) => values.iter().for_each(|v| refs(v, out)),
+        _ => {}
+    }
+}
+
+#[test]
+fn every_schema_is_self_contained() {
+    for name in schema::names() {
+        let schema = schema::schema(name).unwrap().to_value();
+
+        assert_eq!(
+            schema["$schema"], "https://json-schema.org/draft/2020-12/schema",
+            "{name}"
+        );
+
+        let mut found = Vec::new();
+        refs(&schema, &mut found);
+        assert!(!found.is_empty(), "{name} has no root reference");
+
+        for r in found {
+            assert!(
+                schema.pointer(r.trim_start_matches('#')).is_some(),
+                "{name}: unresolved reference {r}"
+            );
+        }
+    }
+}
+
+#[test]
+fn unknown_schema_is_an_error() {
+    assert!(schema::schema("bluetooth").is_err());
+}
+
+#[test]
+fn report_schema_describes_every_section() {
+    let schema = schema::schema(REPORT_SCHEMA).unwrap().to_value();
+    let sections = &schema["$defs"]["SystemReport"]["properties"]["sections"]["properties"];
+
+    for collector in REGISTRY.iter() {
+        let properties = &sections[collector.name()]["properties"];
+        assert!(
+            properties["data"]["anyOf"].is_array(),
+            "{} is not described",
+            collector.name()
+        );
+        assert!(properties["collectedAt"].is_object());
+        assert!(properties["error"].is_object());
+    }
+}
+
+#[test]
+fn structured_values_are_described() {
+    let displays = schema::schema("displays").unwrap().to_value();
+    let dimensions = &displays["$defs"]["Dimensions"]["properties"];
+    assert_eq!(dimensions["width"]["type"], "integer");
+    assert_eq!(dimensions["height"]["type"], "integer");
+    assert_eq!(
+        displays["$defs"]["Position"]["properties"]["x"]["type"],
+        "integer"
+    );
+
+    let disks = schema::schema("disks").unwrap().to_value();
+    assert!(disks["$defs"]["DiskKind"].is_object());
+
+    let usb = schema::schema("usb").unwrap().to_value();
+    // Documented variants are described one by one, each with its own value.
+    let classes = usb["$defs"]["USBSpeedClass"]["oneOf"].as_array().unwrap();
+    assert!(classes
+        .iter()
+        .any(|class| class["const"] == "high" && class["description"].is_string()));
+    assert_eq!(
+        usb["$defs"]["USBSpeed"]["properties"]["mbps"]["type"],
+        Value::from(vec!["number", "null"])
+    );
+}
@@ -0,0 +1,70 @@
+//! Checks that sections which take too long to be gathered fail with a section error, and that
+//! their timings are recorded.
+
+use std::thread;
+use std::time::Duration;
+
+use revelio_lib::collectors::{self, Collector};
+use revelio_lib::state::SectionSnapshot;
+use revelio_lib::types::CoreError;
+use schemars::{Schema, SchemaGenerator};
+use serde_json::Value;
+
+/// A section that takes a second to be gathered.
+struct SlowCollector;
+
+impl Collector for SlowCollector {
+    fn name(&self) -> &'static str {
+        "slow"
+    }
+
+    fn title(&self) -> &'static str {
+        "Slow"
+    }
+
+    fn error(&self, reason: String) -> CoreError {
+        CoreError::CPUInfoError(reason.into())
+    }
+
+    fn collect(&self) -> Result<Value, CoreError> {
+        thread::sleep(Duration::from_secs(1));
+        Ok(Value::Null)
+    }
+
+    fn schema(&self, generator: &mut SchemaGenerator) -> Schema {
+        generator.subschema_for::<Value>()
+    }
+}
+
+#[test]
+fn slow_section_times_out() {
+    let e = collectors::collect(&SlowCollector, Duration::from_millis(50)).unwrap_err();
+
+    assert!(matches!(e, CoreError::CPUInfoError(_)), "{e}");
+    assert!(
+        e.reason()
+            .starts_with("the collector did not finish within"),
+        "{e}"
+    );
+}
+
+#[test]
+fn timing_records_the_error() {
+    let (result, timing) =
+        SectionSnapshot::collect_timed(&SlowCollector, Duration::from_millis(50));
+
+    assert!(result.is_err());
+    assert_eq!(timing.section, "slow");
+    assert!(!timing.cache_hit);
+    assert!(timing.duration_ms < 1000.0, "{}", timing.duration_ms);
+    assert_eq!(timing.error.unwrap().kind, "cPUInfoError");
+}
+
+#[test]
+fn timing_of_a_finished_section() {
+    let (result, timing) = SectionSnapshot::collect_timed(&SlowCollector, Duration::from_secs(5));
+
+    assert!(result.is_ok());
+    assert!(timing.duration_ms >= 1000.0, "{}", timing.duration_ms);
+    assert!(timing.error.is_none());
+}
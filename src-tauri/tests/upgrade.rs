@@ -0,0 +1,52 @@
+//! Checks that reports saved in older versions of the report format are upgraded when loaded.
+
+use std::path::Path;
+
+use revelio_lib::report::{SystemReport, SCHEMA_VERSION};
+use serde_json::json;
+
+/// Loads a report from `tests/reports`.
+fn load(name: &str) -> SystemReport {
+    let path = Path::new(env!("CARGO_MANIFEST_DIR"))
+        .join("tests/reports")
+        .join(name);
+    SystemReport::load(&path).unwrap()
+}
+
+#[test]
+fn v1_report_is_upgraded() {
+    let report = load("v1.json");
+    assert_eq!(report.schema_version, SCHEMA_VERSION);
+
+    let disks = &report.section("disks").unwrap()["disks"];
+    assert_eq!(disks[0]["kind"], json!("ssd"));
+    assert_eq!(disks[1]["kind"], json!({ "unknown": -1 }));
+
+    let display = &report.section("displays").unwrap()["displays"][0];
+    assert_eq!(
+        display["dimensions"],
+        json!({ "width": 2560, "height": 1440 })
+    );
+    assert_eq!(display["position"], json!({ "x": -2560, "y": 0 }));
+
+    let metal = &report.section("metal").unwrap()["devices"][0];
+    assert_eq!(metal["location"], json!("builtIn"));
+    assert_eq!(
+        metal["maxThreadsPerThreadgroup"],
+        json!({ "width": 1024, "height": 1, "depth": 1 })
+    );
+    assert_eq!(metal["argumentBuffersSupport"], json!("tier2"));
+    assert_eq!(metal["readWriteTextureSupport"], json!("tierNone"));
+
+    let usb = &report.section("usb").unwrap()["devices"];
+    assert_eq!(
+        usb[0]["speed"],
+        json!({ "class": "superPlus", "mbps": 10000.0 })
+    );
+    assert_eq!(usb[1]["speed"], json!({ "class": "unknown", "mbps": null }));
+    assert_eq!(usb[2]["speed"], json!(null));
+
+    let vulkan = &report.section("vulkan").unwrap()["devices"];
+    assert_eq!(vulkan[0]["deviceType"], json!("cpu"));
+    assert_eq!(vulkan[1]["deviceType"], json!(null));
+}
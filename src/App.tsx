@@ -16,11 +16,23 @@ import Storage from "./views/Storage";
 import Network from "./views/Network";
 import USB from "./views/USB";
 import Platform from "./views/Platform";
-import { commands } from "./bindings";
+import Diagnostics from "./views/Diagnostics.tsx";
+import Fleet from "./views/Fleet.tsx";
+import Monitor from "./views/Monitor.tsx";
+import Changes from "./views/Changes.tsx";
+import { commands } from "./api";
 import { useEffect, useRef, useState } from "react";
 import Displays from "./views/Displays.tsx";
-import { MdScreenshotMonitor } from "react-icons/md";
+import {
+  MdDevices,
+  MdHistory,
+  MdScreenshotMonitor,
+  MdShowChart,
+  MdTimer,
+} from "react-icons/md";
 import ThemeSwitcher from "./components/ThemeSwitcher.tsx";
+import ExportMenu from "./components/ExportMenu.tsx";
+import RemoteMenu from "./components/RemoteMenu.tsx";
 import { FaUsb } from "react-icons/fa6";
 
 function App() {
@@ -42,6 +54,25 @@ function App() {
       }
     });
 
+    // Error messages and exported reports follow the language of the webview.
+    commands.setLocale(navigator.language).catch((error) => {
+      console.error(error);
+    });
+
+    // Sections whose collectors open a window (in a helper process) are only gathered when their view is opened.
+    commands
+      .getSections()
+      .then((sections) =>
+        commands.prefetchSections(
+          sections.filter(
+            (section) => section != "opengl" && section != "displays",
+          ),
+        ),
+      )
+      .catch((error) => {
+        console.error(error);
+      });
+
     commands
       .getAppVersion()
       .then((version) => {
@@ -146,6 +177,50 @@ function App() {
                 </div>
               }
             />
+
+            <Tab
+              key="/monitor"
+              href="/monitor"
+              title={
+                <div className="flex items-center space-x-2">
+                  <MdShowChart />
+                  <span>Monitor</span>
+                </div>
+              }
+            />
+
+            <Tab
+              key="/changes"
+              href="/changes"
+              title={
+                <div className="flex items-center space-x-2">
+                  <MdHistory />
+                  <span>Changes</span>
+                </div>
+              }
+            />
+
+            <Tab
+              key="/diagnostics"
+              href="/diagnostics"
+              title={
+                <div className="flex items-center space-x-2">
+                  <MdTimer />
+                  <span>Diagnostics</span>
+                </div>
+              }
+            />
+
+            <Tab
+              key="/fleet"
+              href="/fleet"
+              title={
+                <div className="flex items-center space-x-2">
+                  <MdDevices />
+                  <span>Fleet</span>
+                </div>
+              }
+            />
           </Tabs>
 
           <Spacer x={8} />
@@ -158,8 +233,16 @@ function App() {
             <Route path="/network" element={<Network />} />
             <Route path="/usb" element={<USB />} />
             <Route path="/platform" element={<Platform />} />
+            <Route path="/monitor" element={<Monitor />} />
+            <Route path="/changes" element={<Changes />} />
+            <Route path="/diagnostics" element={<Diagnostics />} />
+            <Route path="/fleet" element={<Fleet />} />
           </Routes>
 
+          <RemoteMenu />
+
+          <ExportMenu />
+
           <ThemeSwitcher />
 
           <p className="fixed bottom-0 text-xs m-2">v{appVersion}</p>
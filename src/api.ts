@@ -0,0 +1,152 @@
+import {
+  commands as generated,
+  CpuInfo,
+  DirectXInfo,
+  DisksInfo,
+  DisplaysInfo,
+  MetalInfo,
+  NetworksInfo,
+  OpenGLInfo,
+  PlatformInfo,
+  SectionSnapshot,
+  SECTIONS,
+  USBInfo,
+  VulkanInfo,
+} from "./bindings";
+
+// The commands, events and types generated from the Rust core; see `src/bindings.ts`.
+export * from "./bindings";
+
+export type OsType = "Windows" | "MacIntel" | "MacSilicon" | "Linux";
+
+/**
+ * The name of an information section in the core's collector registry.
+ */
+export type Section = (typeof SECTIONS)[number];
+
+/**
+ * The information of a section along with the time it was gathered.
+ */
+export type Snapshot<T> = Omit<SectionSnapshot, "data"> & {
+  /** The section information */
+  data: T;
+};
+
+/**
+ * Retrieves the information of a section, typed as the section's information type.
+ *
+ * @async
+ * @param {Section} section The section to retrieve.
+ * @returns {Promise<Snapshot<T>>} Resolves to the section information.
+ * @throws {CoreError} If gathering the section fails.
+ */
+async function getSectionInfo<T>(section: Section): Promise<Snapshot<T>> {
+  return (await generated.getSectionInfo(section)) as Snapshot<T>;
+}
+
+/**
+ * Gathers the information of a section again, bypassing the cache.
+ *
+ * @async
+ * @param {Section} section The section to gather.
+ * @returns {Promise<Snapshot<T>>} Resolves to the fresh section information.
+ * @throws {CoreError} If gathering the section fails.
+ */
+async function refreshSectionInfo<T>(section: Section): Promise<Snapshot<T>> {
+  return (await generated.refreshSectionInfo(section)) as Snapshot<T>;
+}
+
+/**
+ * Bindings to Tauri commands from the Rust core.
+ *
+ * Extends the generated commands with typed getters for the information of each section.
+ */
+export const commands = {
+  ...generated,
+
+  /**
+   * Returns the operating system type of the inspected system (the current one, or the remote agent's).
+   */
+  getOsType: generated.getOsType as () => Promise<OsType>,
+
+  /**
+   * Returns the names of the sections available on the inspected system's platform.
+   */
+  getSections: generated.getSections as () => Promise<Section[]>,
+
+  /**
+   * Retrieves platform information from the system.
+   */
+  getPlatformInfo: () => getSectionInfo<PlatformInfo>("platform"),
+
+  /**
+   * Retrieves CPU information from the system.
+   */
+  getCpuInfo: () => getSectionInfo<CpuInfo>("cpu"),
+
+  /**
+   * Retrieves Vulkan information from the system.
+   */
+  getVulkanInfo: () => getSectionInfo<VulkanInfo>("vulkan"),
+
+  /**
+   * Retrieves the OpenGL information from the system.
+   *
+   * The information is gathered in a helper process, so a crashing or hanging driver rejects with an `openGLInfoError`
+   * instead of taking down the application.
+   */
+  getOpenGLInfo: () => getSectionInfo<OpenGLInfo>("opengl"),
+
+  /**
+   * Retrieves DirectX information from the system.
+   */
+  getDirectXInfo: () => getSectionInfo<DirectXInfo>("directx"),
+
+  /**
+   * Retrieves the Metal information from the system.
+   */
+  getMetalInfo: () => getSectionInfo<MetalInfo>("metal"),
+
+  /**
+   * Retrieves information about the displays connected to the system.
+   *
+   * The information is gathered in a helper process, so a crash while enumerating the displays rejects with an error
+   * instead of taking down the application.
+   */
+  getDisplaysInfo: () => getSectionInfo<DisplaysInfo>("displays"),
+
+  /**
+   * Gathers displays information from the system again, bypassing the cache.
+   */
+  refreshDisplaysInfo: () => refreshSectionInfo<DisplaysInfo>("displays"),
+
+  /**
+   * Retrieves disk(s) information from the system.
+   */
+  getDisksInfo: () => getSectionInfo<DisksInfo>("disks"),
+
+  /**
+   * Gathers disk(s) information from the system again, bypassing the cache.
+   */
+  refreshDisksInfo: () => refreshSectionInfo<DisksInfo>("disks"),
+
+  /**
+   * Retrieves network(s) information from the system.
+   */
+  getNetworksInfo: () => getSectionInfo<NetworksInfo>("network"),
+
+  /**
+   * Gathers network(s) information from the system again, bypassing the cache.
+   */
+  refreshNetworksInfo: () => refreshSectionInfo<NetworksInfo>("network"),
+
+  /**
+   * Retrieves information about the USB devices detected on the system.
+   */
+  getUSBInfo: () => getSectionInfo<USBInfo>("usb"),
+
+  /**
+   * Gathers USB information from the system again, bypassing the cache.
+   */
+  refreshUSBInfo: () => refreshSectionInfo<USBInfo>("usb"),
+};
@@ -1,584 +1,1502 @@
-import { invoke } from "@tauri-apps/api/core";
+// @ts-nocheck
+// This file was generated by [tauri-specta](https://github.com/oscartbeaumont/tauri-specta). Do not edit this file manually.
 
+/** user-defined commands **/
+
+
+export const commands = {
+async isReleaseProfile() : Promise<boolean> {
+    return await TAURI_INVOKE("is_release_profile");
+},
+/**
+ * Returns the type of the inspected system's operating system.
+ */
+async getOsType() : Promise<string> {
+    return await TAURI_INVOKE("get_os_type");
+},
+async getAppVersion() : Promise<string> {
+    return await TAURI_INVOKE("get_app_version");
+},
+/**
+ * Returns the locales the backend's labels and error messages can be resolved in.
+ */
+async getLocales() : Promise<string[]> {
+    return await TAURI_INVOKE("get_locales");
+},
+/**
+ * Returns the locale the backend's labels and error messages are resolved in.
+ */
+async getLocale() : Promise<string> {
+    return await TAURI_INVOKE("get_locale");
+},
+/**
+ * Sets the locale the backend's labels and error messages are resolved in (Eg: `de-DE`). Returns
+ * the supported locale that was picked, which falls back to English.
+ */
+async setLocale(locale: string) : Promise<string> {
+    return await TAURI_INVOKE("set_locale", { locale });
+},
+/**
+ * Returns the names of the sections enabled on the inspected system's platform.
+ */
+async getSections() : Promise<string[]> {
+    return await TAURI_INVOKE("get_sections");
+},
+async getSectionInfo(section: string) : Promise<SectionSnapshot> {
+    return await TAURI_INVOKE("get_section_info", { section });
+},
+/**
+ * Gathers a section again, replacing its cached snapshot.
+ * 
+ * This one command takes the section name, instead of one `refresh_<section>` command per
+ * section, so that sections added to the registry need no new command. The typed per-section
+ * wrappers (Eg: `refreshDisksInfo`) are in `src/api.ts`.
+ */
+async refreshSectionInfo(section: string) : Promise<SectionSnapshot> {
+    return await TAURI_INVOKE("refresh_section_info", { section });
+},
+async invalidateSectionInfo(section: string) : Promise<null> {
+    return await TAURI_INVOKE("invalidate_section_info", { section });
+},
+/**
+ * Sets how long a section's information is cached for, or caches it indefinitely if `ttl_secs`
+ * is `None`.
+ */
+async setSectionTtl(section: string, ttlSecs: number | null) : Promise<null> {
+    return await TAURI_INVOKE("set_section_ttl", { section, ttlSecs });
+},
+/**
+ * Sets how long a section may take to be gathered before it fails, or restores the section's
+ * default timeout if `timeout_secs` is `None`.
+ */
+async setSectionTimeout(section: string, timeoutSecs: number | null) : Promise<null> {
+    return await TAURI_INVOKE("set_section_timeout", { section, timeoutSecs });
+},
+/**
+ * Returns how long the last request for each section took, whether it was served from the cache
+ * and the error it failed with, if any.
+ */
+async getDiagnostics() : Promise<SectionTiming[]> {
+    return await TAURI_INVOKE("get_diagnostics");
+},
+/**
+ * Gathers the given sections concurrently in the background, emitting a `section-collected`
+ * event as soon as each one is ready. Subsequent requests for those sections are served from the
+ * cache, or wait for the in-flight collection to finish.
+ */
+async prefetchSections(sections: string[]) : Promise<null> {
+    return await TAURI_INVOKE("prefetch_sections", { sections });
+},
+/**
+ * Returns the redaction rules of the configuration, which default to hashing every value
+ * identifying the machine or its devices.
+ */
+async getDefaultRedactionRules() : Promise<RedactionRule[]> {
+    return await TAURI_INVOKE("get_default_redaction_rules");
+},
+/**
+ * Gathers every section into a report, redacted with the given rules, if any.
+ */
+async getSystemReport(redaction: RedactionRule[] | null) : Promise<SystemReport> {
+    return await TAURI_INVOKE("get_system_report", { redaction });
+},
+/**
+ * Exports a report of every section to a file, redacted with the given rules, if any. If no path
+ * is given, the user is asked to choose one, starting in the configured export directory.
+ * Returns the path of the written file, or `None` if the user cancelled.
+ */
+async exportSystemReport(format: ExportFormat, path: string | null, redaction: RedactionRule[] | null) : Promise<string | null> {
+    return await TAURI_INVOKE("export_system_report", { format, path, redaction });
+},
+/**
+ * Compares a previously saved report against another one, or against the current system if no
+ * new report is given.
+ */
+async diffSystemReports(oldReport: string, newReport: string | null) : Promise<ReportDiff> {
+    return await TAURI_INVOKE("diff_system_reports", { oldReport, newReport });
+},
+/**
+ * Points the app at a remote agent (see `revelio-cli serve`), or back at the current system if
+ * `url` is `None`. Returns the description of the agent, which is queried to check that it can
+ * be reached.
+ */
+async setRemoteAgent(url: string | null, token: string | null) : Promise<AgentInfo | null> {
+    return await TAURI_INVOKE("set_remote_agent", { url, token });
+},
+/**
+ * Returns the URL of the remote agent the app is pointed at, if any.
+ */
+async getRemoteAgent() : Promise<string | null> {
+    return await TAURI_INVOKE("get_remote_agent");
+},
+/**
+ * Loads the reports in a directory into the fleet view, replacing any previously loaded fleet. If
+ * no directory is given, the user is asked to choose one. Returns the description of the fleet,
+ * or `None` if the user cancelled.
+ */
+async loadFleet(dir: string | null) : Promise<FleetSummary | null> {
+    return await TAURI_INVOKE("load_fleet", { dir });
+},
+/**
+ * Returns the hosts of the loaded fleet that match a query (Eg: `platform.graphicsPlatform =
+ * Wayland`), or every host if the query is empty.
+ */
+async queryFleet(query: string) : Promise<FleetHost[]> {
+    return await TAURI_INVOKE("query_fleet", { query });
+},
+/**
+ * Returns the configuration in effect.
+ */
+async getConfig() : Promise<Config> {
+    return await TAURI_INVOKE("get_config");
+},
+/**
+ * Saves the configuration to the configuration file and puts it into effect. Section timeouts
+ * and time-to-lives set since launch are replaced by the configured ones, and the logging
+ * settings take effect on the next launch.
+ */
+async setConfig(config: Config) : Promise<null> {
+    return await TAURI_INVOKE("set_config", { config });
+},
+/**
+ * Starts sampling the changing values of the current system (CPU usage and frequencies, memory
+ * usage, network traffic and disk space and I/O) in the background, emitting a `metrics-sampled`
+ * event every `interval_ms` milliseconds, or every second if omitted. Any previous monitoring is
+ * stopped first.
+ */
+async startMonitoring(intervalMs: number | null) : Promise<null> {
+    return await TAURI_INVOKE("start_monitoring", { intervalMs });
+},
+/**
+ * Stops sampling the changing values of the system, if monitoring.
+ */
+async stopMonitoring() : Promise<void> {
+    await TAURI_INVOKE("stop_monitoring");
+},
+/**
+ * Returns the metrics recorded in the metrics history (see `revelio-cli record`).
+ */
+async getHistoryMetrics() : Promise<HistoryMetric[]> {
+    return await TAURI_INVOKE("get_history_metrics");
+},
+/**
+ * Returns the values of a metric (Eg: `disk.used`) recorded in the metrics history from `from`
+ * until `to`, or until now if omitted, in milliseconds since the Unix epoch. There is a series
+ * per subject (Eg: per mount point), unless only the values of `subject` are asked for.
+ */
+async queryHistory(metric: string, subject: string | null, from: number, to: number | null) : Promise<MetricSeries[]> {
+    return await TAURI_INVOKE("query_history", { metric, subject, from, to });
+},
+/**
+ * Returns the changes to the hardware inventory of the current system (USB devices, disks,
+ * displays, Vulkan devices, CPU, kernel and distribution) since the last run, as found by the
+ * latest check. Returns `None` if no check has completed yet, or if no inventory was saved by
+ * the last run.
+ */
+async getInventoryChanges() : Promise<ReportDiff | null> {
+    return await TAURI_INVOKE("get_inventory_changes");
+},
+/**
+ * Compares the hardware inventory of the current system with the one saved by the last run, as
+ * done on launch, and saves it in its place. Returns `None` if no inventory was saved by the last
+ * run.
+ */
+async checkInventory() : Promise<ReportDiff | null> {
+    return await TAURI_INVOKE("check_inventory");
+}
+}
+
+/** user-defined events **/
+
+
+export const events = __makeEvents__<{
+inventoryChecked: InventoryChecked,
+metricsSampled: MetricsSampled,
+sectionCollected: SectionCollected
+}>({
+inventoryChecked: "inventory-checked",
+metricsSampled: "metrics-sampled",
+sectionCollected: "section-collected"
+})
+
+/** user-defined constants **/
+
+export const SECTIONS = ["platform","cpu","vulkan","opengl","directx","metal","displays","disks","network","usb"] as const;
+
+/** user-defined types **/
+
+/**
+ * Describes an agent and the system it runs on. Served at `/v1/agent`.
+ */
+export type AgentInfo = { 
+/**
+ * The version of Revelio the agent runs.
+ */
+appVersion: string; 
+/**
+ * The type of the agent's operating system (Eg: `Linux`).
+ */
+osType: string; 
+/**
+ * The sections enabled on the agent's platform.
+ */
+sections: string[] }
+/**
+ * A rule that raises an alert while the information of the system matches, or doesn't match, a
+ * query (see [`crate::fleet::Query`]). Exactly one of `when` and `unless` is given.
+ * 
+ * Queries are about the sections gathered by the app, and about the changing values sampled
+ * while monitoring under `metrics` (Eg: `metrics.temperatures[].temperature > 90`).
+ */
+export type AlertRule = { 
+/**
+ * The name of the alert, which titles its notification (Eg: `Disk almost full`).
+ */
+name: string; 
+/**
+ * The query that raises the alert while it matches (Eg:
+ * `disks.disks[].availablePercent < 10`).
+ */
+when: string | null; 
+/**
+ * The query that raises the alert while it doesn't match (Eg: `usb.devices[].vendorId =
+ * 0x1050 and usb.devices[].productId = 0x0407`).
+ */
+unless: string | null; 
+/**
+ * The body of the alert's notification. Describes the query if omitted.
+ */
+message: string | null }
+/**
+ * Contains information of a single CPU cache.
+ */
+export type Cache = { 
+/**
+ * The cache size in bytes.
+ */
+size: number; 
+/**
+ * The number of ways of associativity.
+ */
+associativity: number; 
+/**
+ * The number of sets.
+ */
+sets: number; 
+/**
+ * The number of partitions.
+ */
+partitions: number; 
+/**
+ * The line size in bytes.
+ */
+lineSize: number; 
+/**
+ * The cache flags (Eg: unified, inclusive).
+ */
+flags: number }
+/**
+ * Contains information of the CPU's cache hierarchy.
+ */
+export type CacheInfo = { l1i?: Cache | null; l1d?: Cache | null; l2?: Cache | null; l3?: Cache | null; l4?: Cache | null }
+/**
+ * A single difference within a section.
+ */
+export type Change = { 
+/**
+ * The kind of difference.
+ */
+kind: ChangeKind; 
+/**
+ * The path of the value within the section (Eg: `disks[/home].availableSpace`), or
+ * an empty string for the whole section.
+ */
+path: string; 
+/**
+ * The value in the old report.
+ */
+old?: JsonValue | null; 
+/**
+ * The value in the new report.
+ */
+new?: JsonValue | null }
+/**
+ * The kind of difference between two reports.
+ */
+export type ChangeKind = 
+/**
+ * The value is only present in the new report.
+ */
+"added" | 
+/**
+ * The value is only present in the old report.
+ */
+"removed" | 
+/**
+ * The value is present in both reports, but differs.
+ */
+"changed"
+/**
+ * Represents the chip type of a Mac (Intel/Apple Silicon).
+ */
+export type ChipType = "x86_64" | "Apple Silicon"
+/**
+ * The persistent configuration of the app and the command-line interface, read from
+ * `revelio/config.toml` in the platform's configuration directory (Eg: `~/.config` on Linux).
+ * 
+ * ```toml
+ * [sections.opengl]
+ * enabled = false
+ * 
+ * [sections.usb]
+ * ttl = 30
+ * 
+ * [redaction]
+ * enabled = true
+ * 
+ * [log]
+ * level = "info"
+ * 
+ * [history]
+ * interval = 30
+ * 
+ * [[alerts]]
+ * name = "Disk almost full"
+ * when = "disks.disks[].availablePercent < 10"
+ * ```
+ */
+export type Config = { 
+/**
+ * The settings of each section, by section name. Sections that are omitted are enabled and
+ * use their defaults.
+ */
+sections: Partial<{ [key in string]: SectionConfig }>; 
+/**
+ * The redaction settings.
+ */
+redaction: RedactionConfig; 
+/**
+ * The export settings.
+ */
+export: ExportConfig; 
+/**
+ * The logging settings.
+ */
+log: LogConfig; 
+/**
+ * The metrics history settings.
+ */
+history: HistoryConfig; 
+/**
+ * The alert rules, evaluated by the app whenever a section is gathered or the system is
+ * sampled.
+ */
+alerts: AlertRule[] }
+export type Core = { id: number; processorsCount: number; cpuId: number; frequency: number; processors: Processor[] }
+/**
+ * An error from the core library, as seen by the frontend.
+ */
+export type CoreError = { kind: "cPUInfoError"; message: string } | { kind: "directXInfoError"; message: string } | { kind: "metalInfoError"; message: string } | { kind: "vulkanInfoError"; message: string } | { kind: "openGLInfoError"; message: string } | { kind: "uSBInfoError"; message: string } | { kind: "unknownSection"; message: string } | { kind: "unsupportedSection"; message: string } | { kind: "disabledSection"; message: string } | { kind: "error"; message: string }
+/**
+ * The usage of a logical CPU core.
+ */
+export type CoreSample = { 
+/**
+ * The name of the core (Eg: `cpu0`).
+ */
+name: string; 
+/**
+ * The usage of the core since the previous sample, in percent.
+ */
+usage: number; 
+/**
+ * The current frequency of the core, in hertz.
+ */
+frequency: number }
+/**
+ * Contains information of the system's CPU.
+ */
+export type CpuInfo = { brand: string; arch: string; opModes: string; vendorId: string; cacheInfo: CacheInfo; processorsCount: number; coreCount: number; threadsPerCore: number; cores: Core[]; 
+/**
+ * The fields that could not be gathered.
+ */
+warnings: FieldWarning[] }
+/**
+ * Represents a display's dimensions (width x height), in physical pixels.
+ */
+export type Dimensions = { 
+/**
+ * Display width.
+ */
+width: number; 
+/**
+ * Display height.
+ */
+height: number }
+/**
+ * Contains information of a DirectX device.
+ */
+export type DirectXDevice = { 
+/**
+ * Device index; useful for unique identification in mapping functions, but otherwise not bound to the device itself.
+ */
+index: number; 
+/**
+ * The name of the device.
+ */
+deviceName: string; 
+/**
+ * The device's vendor ID.
+ */
+vendorId: number; 
+/**
+ * The device ID.
+ */
+deviceId: number; 
+/**
+ * The subsystem ID.
+ */
+subSysId: number; 
+/**
+ * The device revision.
+ */
+revision: number; 
+/**
+ * Dedicated video memory in bytes.
+ */
+dedicatedVideoMemory: number; 
+/**
+ * Dedicated system memory in bytes.
+ */
+dedicatedSystemMemory: number; 
+/**
+ * Shared system memory in bytes.
+ */
+sharedSystemMemory: number }
+/**
+ * Contains information about DirectX on the system.
+ */
+export type DirectXInfo = { 
+/**
+ * The total number of DirectX capable devices.
+ */
+totalDevices: number; 
+/**
+ * The list of DirectX capable devices identified on the system.
+ */
+devices: DirectXDevice[] }
+/**
+ * Represents an individual disk on the system.
+ */
+export type Disk = { 
+/**
+ * The name of the disk.
+ */
+name: string; 
+/**
+ * The file-system of the disk (Eg: `EXT4`, `NTFS`, etc.).
+ */
+fileSystem: string; 
+/**
+ * The mount point of the disk.
+ */
+mountPoint: string; 
+/**
+ * The total space/size of the disk.
+ */
+totalSpace: number; 
+/**
+ * The currently available space on the disk.
+ */
+availableSpace: number; 
+/**
+ * The kind of disk (HDD, SSD or unknown).
+ */
+kind: DiskKind }
+/**
+ * The kind of a disk.
+ */
+export type DiskKind = 
+/**
+ * A hard disk drive.
+ */
+"hdd" | 
+/**
+ * A solid state drive.
+ */
+"ssd" | 
+/**
+ * An unknown kind of disk, along with the raw value reported by the system.
+ */
+{ unknown: number }
+/**
+ * The space and I/O of a disk.
+ */
+export type DiskSample = { 
+/**
+ * The name of the disk (Eg: `/dev/nvme0n1p2`).
+ */
+name: string; 
+/**
+ * The mount point of the disk.
+ */
+mountPoint: string; 
+/**
+ * The total space of the disk, in bytes.
+ */
+totalSpace: number; 
+/**
+ * The available space of the disk, in bytes.
+ */
+availableSpace: number; 
+/**
+ * The number of bytes read from the disk since boot (Linux only).
+ */
+readBytes: number | null; 
+/**
+ * The number of bytes written to the disk since boot (Linux only).
+ */
+writtenBytes: number | null; 
+/**
+ * The number of bytes read per second since the previous sample (Linux only).
+ */
+readRate: number | null; 
+/**
+ * The number of bytes written per second since the previous sample (Linux only).
+ */
+writeRate: number | null }
+/**
+ * Contains information of all the disks identified on the system.
+ */
+export type DisksInfo = { 
+/**
+ * The total number of disks.
+ */
+count: number; 
+/**
+ * The information of each disk.
+ */
+disks: Disk[] }
+/**
+ * Represents an individual display attached to the system.
+ */
+export type Display = { 
+/**
+ * The name of the display.
+ */
+name: string; 
+/**
+ * The display dimensions (width x height).
+ */
+dimensions: Dimensions; 
+/**
+ * The scale factor of the display.
+ */
+scaleFactor: number; 
+/**
+ * The top-left corner position of the monitor relative to the larger full screen area (x, y).
+ */
+position: Position; 
+/**
+ * The display's refresh rate in MHz.
+ */
+refreshRate: number | null }
+/**
+ * Contains information of the displays connected to the system.
+ */
+export type DisplaysInfo = { 
+/**
+ * The total number of displays.
+ */
+totalDisplays: number; 
+/**
+ * The list of the displays.
+ */
+displays: Display[] }
+/**
+ * The export settings.
+ */
+export type ExportConfig = { 
+/**
+ * The format reports are exported in when none is given and it can't be inferred from the
+ * file extension. Defaults to JSON.
+ */
+format: ExportFormat | null; 
+/**
+ * The directory the app suggests exporting reports to.
+ */
+dir: string | null }
+/**
+ * The file formats a system report can be exported to.
+ */
+export type ExportFormat = 
+/**
+ * Pretty-printed JSON.
+ */
+"json" | 
+/**
+ * YAML.
+ */
+"yaml" | 
+/**
+ * A Markdown summary with a table per section.
+ */
+"markdown" | 
+/**
+ * A self-contained HTML page.
+ */
+"html"
+/**
+ * Describes a field of a section that could not be gathered, while the rest of the section was.
+ * 
+ * The field itself keeps a placeholder value (Eg: `Unknown`) or is `null`, so that consumers
+ * which don't look at the warnings still get a complete section.
+ */
+export type FieldWarning = { 
+/**
+ * The path of the field within the section, using the serialized field names and array
+ * indices (Eg: `graphicsPlatform`, `devices[0].layers[2].layerName`).
+ */
+field: string; 
+/**
+ * The message key of the reason, which doesn't depend on the locale (Eg:
+ * `warning-env-not-set`).
+ */
+key: string; 
+/**
+ * Why the field is unavailable, in the locale that was current when it was gathered.
+ */
+reason: string }
+/**
+ * Describes a host of a fleet.
+ */
+export type FleetHost = { 
+/**
+ * The hostname, or the name of the report file if the platform section is missing.
+ */
+hostname: string; 
+/**
+ * The path of the host's report.
+ */
+path: string; 
+/**
+ * The time the report was generated, in milliseconds since the Unix epoch.
+ */
+generatedAt: number; 
+/**
+ * The host's platform (Eg: `Linux`), if known.
+ */
+platform: string | null; 
+/**
+ * The host's kernel version, if known.
+ */
+kernel: string | null }
+/**
+ * Describes a loaded fleet.
+ */
+export type FleetSummary = { 
+/**
+ * The directory the reports were loaded from.
+ */
+dir: string; 
+/**
+ * The number of hosts.
+ */
+hosts: number; 
+/**
+ * The report files that could not be loaded.
+ */
+skipped: SkippedReport[] }
+/**
+ * Represents the current Linux graphics platform (X11/Wayland).
+ */
+export type GraphicsPlatform = "X11" | "Wayland" | "Unknown"
+/**
+ * The settings of the metrics history recorded by `revelio-cli record`. Samples are kept as
+ * recorded for a while, then averaged per minute and later per hour, so that the history stays
+ * small while covering a long time.
+ */
+export type HistoryConfig = { 
+/**
+ * How often metrics are recorded, in seconds.
+ */
+interval: number; 
+/**
+ * How long samples are kept as recorded, in hours, before being averaged per minute.
+ */
+raw: number; 
+/**
+ * How long per-minute averages are kept, in days, before being averaged per hour.
+ */
+minutes: number; 
+/**
+ * How long per-hour averages are kept, in days, before being deleted.
+ */
+hours: number; 
+/**
+ * The SQLite database the history is stored in. Defaults to `revelio/history.sqlite` in the
+ * platform's local data directory.
+ */
+file: string | null }
+/**
+ * A metric that is recorded.
+ */
+export type HistoryMetric = { 
+/**
+ * The name of the metric (Eg: `disk.used`).
+ */
+name: string; 
+/**
+ * The unit of the metric's values (Eg: `percent`, `bytes/s`).
+ */
+unit: string }
+/**
+ * A recorded value of a metric.
+ */
+export type HistoryPoint = { 
+/**
+ * The time of the value, in milliseconds since the Unix epoch. For averages, this is the
+ * start of the minute or hour they cover.
+ */
+time: number; 
+/**
+ * The value, or the average of the values recorded in the minute or hour.
+ */
+value: number }
+/**
+ * The traffic of a network interface.
+ */
+export type InterfaceSample = { 
+/**
+ * The name of the interface.
+ */
+itfName: string; 
+/**
+ * The number of bytes received on the interface since it was brought up.
+ */
+receivedBytes: number; 
+/**
+ * The number of bytes transmitted on the interface since it was brought up.
+ */
+transmittedBytes: number; 
+/**
+ * The number of bytes received per second since the previous sample.
+ */
+receivedRate: number; 
+/**
+ * The number of bytes transmitted per second since the previous sample.
+ */
+transmittedRate: number }
+/**
+ * Payload of the `inventory-checked` event, emitted whenever the hardware inventory has been
+ * compared with the one saved by the last run. Holds the changes, or `None` on the first run.
+ */
+export type InventoryChecked = ReportDiff | null
+export type JsonValue = null | boolean | number | string | JsonValue[] | Partial<{ [key in string]: JsonValue }>
+/**
+ * Contains information about the current Linux distribution.
+ */
+export type LinuxInfo = { 
+/**
+ * An identifier that describes the distribution's release.
+ */
+id: string; 
+/**
+ * Identifier of the original upstream OS that this distribution is derived from.
+ */
+idLike: string; 
+/**
+ * The name of this release, without the version string.
+ */
+name: string; 
+/**
+ * The name of this release, with the version string.
+ */
+prettyName: string; 
+/**
+ * The version of this OS release.
+ */
+version: string; 
+/**
+ * The version of this OS release, along with additional details about the release.
+ */
+versionId: string; 
+/**
+ * The codename of this version.
+ */
+versionCodename: string; 
+/**
+ * The current graphics platform (X11/Wayland).
+ */
+graphicsPlatform: GraphicsPlatform; 
+/**
+ * The current Desktop environment, if known.
+ */
+desktop: string | null; 
+/**
+ * The current user's shell, if known.
+ */
+shell: string | null; 
+/**
+ * The bug report URL.
+ */
+bugReportUrl: string; 
+/**
+ * The support URL.
+ */
+supportUrl: string; 
+/**
+ * The home URL.
+ */
+homeUrl: string; 
+/**
+ * The privacy policy URL.
+ */
+privacyPolicyUrl: string; 
+/**
+ * The documentation URL.
+ */
+documentationUrl: string }
+/**
+ * The logging settings of the app. They take effect on the next launch.
+ */
+export type LogConfig = { 
+/**
+ * The most verbose level logged. Defaults to `trace` in debug builds and `warn` otherwise.
+ */
+level: LogLevel | null; 
+/**
+ * A file to write logs to in addition to stderr. Its extension is always `.log`.
+ */
+file: string | null }
+/**
+ * The most verbose level of the messages that are logged.
+ */
+export type LogLevel = "off" | "error" | "warn" | "info" | "debug" | "trace"
+/**
+ * Contains information about the current macOS installation.
+ */
+export type MacOSInfo = { 
+/**
+ * The macOS version, if known.
+ */
+macOSVersion: string | null; 
+/**
+ * The macOS chip type.
+ */
+chipType: ChipType; 
+/**
+ * The current user's shell, if known.
+ */
+shell: string | null }
+/**
+ * The usage of the memory and swap space.
+ */
+export type MemorySample = { 
+/**
+ * The total amount of memory, in bytes.
+ */
+totalMemory: number; 
+/**
+ * The amount of memory in use, in bytes.
+ */
+usedMemory: number; 
+/**
+ * The amount of memory that can be allocated without swapping, in bytes.
+ */
+availableMemory: number; 
+/**
+ * The total amount of swap space, in bytes.
+ */
+totalSwap: number; 
+/**
+ * The amount of swap space in use, in bytes.
+ */
+usedSwap: number }
+/**
+ * The argument buffers support tier of a Metal device.
+ */
+export type MetalArgumentBuffersTier = "tier1" | "tier2"
+/**
+ * Contains information of a Metal counter set.
+ */
+export type MetalCounterSet = { name: string }
+/**
+ * Contains information of a Metal device.
+ */
+export type MetalDevice = { index: number; deviceName: string; registryId: number; location: MetalDeviceLocation; locationNumber: number; maxThreadgroupMemoryLength: number; maxThreadsPerThreadgroup: MetalSize; isLowPower: boolean; isHeadless: boolean; isRemovable: boolean; supportsRaytracing: boolean; hasUnifiedMemory: boolean; recommendedMaxWorkingSetSize: number; maxTransferRate: number; supportsBarycentricCoordinates: boolean; supportsFunctionPointers: boolean; supportsDynamicLibraries: boolean; argumentBuffersSupport: MetalArgumentBuffersTier; readWriteTextureSupport: MetalReadWriteTextureTier; supports32bitFloatFiltering: boolean; supports32bitMSAA: boolean; supportsQueryTextureLOD: boolean; supportsBCTextureCompression: boolean; supportsPullModelInterpolation: boolean; maxArgumentBufferSamplerCount: number; currentAllocatedSize: number; maxBufferLength: number; counterSets: MetalCounterSet[] }
+/**
+ * The location of a Metal device relative to the host system.
+ */
+export type MetalDeviceLocation = "builtIn" | "slot" | "external" | "unspecified"
+/**
+ * Contains information about the Metal capable devices identified on the system.
+ */
+export type MetalInfo = { totalDevices: number; devices: MetalDevice[] }
+/**
+ * The read-write texture support tier of a Metal device.
+ */
+export type MetalReadWriteTextureTier = "tierNone" | "tier1" | "tier2"
+/**
+ * The dimensions of a Metal grid or threadgroup.
+ */
+export type MetalSize = { width: number; height: number; depth: number }
+/**
+ * The recorded values of a metric for a single subject, oldest first.
+ */
+export type MetricSeries = { 
+/**
+ * The name of the metric (Eg: `disk.used`).
+ */
+metric: string; 
+/**
+ * What the values are of (Eg: a mount point or network interface), or empty for metrics of
+ * the whole system.
+ */
+subject: string; 
+/**
+ * The unit of the values (Eg: `percent`).
+ */
+unit: string; 
+/**
+ * The values.
+ */
+points: HistoryPoint[] }
+/**
+ * The values of the system that change over time, sampled at a single point in time.
+ */
+export type MetricsSample = { 
+/**
+ * The time the sample was taken, in milliseconds since the Unix epoch.
+ */
+sampledAt: number; 
+/**
+ * The usage of each logical CPU core.
+ */
+cores: CoreSample[]; 
+/**
+ * The usage of the memory and swap space.
+ */
+memory: MemorySample; 
+/**
+ * The traffic of each network interface.
+ */
+interfaces: InterfaceSample[]; 
+/**
+ * The space and I/O of each disk.
+ */
+disks: DiskSample[]; 
+/**
+ * The temperature of each hardware sensor that can be read.
+ */
+temperatures: TemperatureSample[] }
+/**
+ * Payload of the `metrics-sampled` event, emitted at every interval while monitoring.
+ */
+export type MetricsSampled = MetricsSample
+/**
+ * Represents an individual network interface on the system.
+ */
+export type NetworkInterface = { 
+/**
+ * The name of the interface.
+ */
+itfName: string; 
+/**
+ * The MAC address of the interface.
+ */
+macAddr: string; 
+/**
+ * The IP networks belonging to the interface.
+ */
+ipNetworks: string[]; 
 /**
- * Bindings to Tauri commands from the Rust core.
+ * The number of bytes received on the interface since it was brought up.
  */
-export const commands = {
-  isReleaseProfile,
-  getOsType,
-  getCpuInfo,
-  getVulkanInfo,
-  getOpenGLInfo,
-  getDirectXInfo,
-  getMetalInfo,
-  getDisplaysInfo,
-  getDisksInfo,
-  getNetworksInfo,
-  getUSBInfo,
-  getPlatformInfo,
-  getAppVersion,
-};
-
-export type OsType = "Windows" | "MacIntel" | "MacSilicon" | "Linux";
-
+receivedBytes: number; 
 /**
- * Contains information of a Vulkan device layer.
+ * The number of bytes transmitted on the interface since it was brought up.
  */
-export type VulkanDeviceLayer = {
-  /** The layer's name */
-  layerName: string;
-  /** The layer's Vulkan version */
-  vulkanVersion: string;
-  /** The layer's version */
-  layerVersion: string;
-  /** The layer's description */
-  description: String;
-};
-
+transmittedBytes: number }
 /**
- * Contains information of a Vulkan device.
+ * Contains information of the networks and network interfaces on the system.
  */
-export type VulkanDevice = {
-  /** Device index; useful for unique identification in mapping functions, but otherwise not bound to the device itself */
-  index: number;
-  /** The name of the device */
-  deviceName: string;
-  /** The device's vendor ID */
-  vendorId: number;
-  /** The layer's description */
-  deviceId: number;
-  /** The layer's description */
-  deviceType: string;
-  /** The layer's description */
-  apiVersion: string;
-  /** The layer's description */
-  driverVersion: string;
-  /** The layer's description */
-  pipelineCacheUuid: string;
-  /** The layer's description */
-  layers: VulkanDeviceLayer[];
-};
-
+export type NetworksInfo = { 
 /**
- * Contains information about Vulkan on the system.
+ * The total number of network interfaces.
  */
-export type VulkanInfo = {
-  /** The total number of Vulkan-enabled devices */
-  totalDevices: number;
-  /** The list of Vulkan-enabled devices identified on the system */
-  devices: VulkanDevice[];
-};
-
+totalInterfaces: number; 
+/**
+ * The list of network interfaces.
+ */
+interfaces: NetworkInterface[] }
 /**
  * Contains information about OpenGL on the system.
  */
-export type OpenGLInfo = {
-  /** The OpenGL device vendor */
-  vendor: string;
-  /** The OpenGL renderer */
-  renderer: string;
-  /** The OpenGL version */
-  version: string;
-  /** The current free video memory on the OpenGL device */
-  freeVideoMem: number;
-};
-
+export type OpenGLInfo = { 
 /**
- * Contains information of a DirectX device.
+ * The OpenGL device vendor.
  */
-export type DirectXDevice = {
-  /** Device index; useful for unique identification in mapping functions, but otherwise not bound to the device itself */
-  index: number;
-  /** The name of the device */
-  deviceName: string;
-  /** The device's vendor ID */
-  vendorId: number;
-  /** The device ID */
-  deviceId: number;
-  /** The subsystem ID */
-  subSysId: number;
-  /** The device revision */
-  revision: number;
-  /** Dedicated video memory in bytes */
-  dedicatedVideoMemory: number;
-  /** Dedicated system memory in bytes */
-  dedicatedSystemMemory: number;
-  /** Shared system memory in bytes */
-  sharedSystemMemory: number;
-};
-
+vendor: string; 
 /**
- * Contains information about DirectX on the system.
+ * The OpenGL renderer.
  */
-export type DirectXInfo = {
-  /** The total number of DirectX capable devices */
-  totalDevices: number;
-  /** The list of DirectX capable devices identified on the system */
-  devices: DirectXDevice[];
-};
-
+renderer: string; 
 /**
- * Contains information of a Metal counter set.
+ * The OpenGL version.
  */
-export type MetalCounterSet = {
-  name: string;
-};
-
+version: string; 
 /**
- * Contains information of a Metal device.
+ * The current free video memory on the OpenGL device.
  */
-export type MetalDevice = {
-  index: number;
-  deviceName: string;
-  registryId: number;
-  location: string;
-  locationNumber: number;
-  maxThreadgroupMemoryLength: number;
-  maxThreadsPerThreadgroup: number;
-  isLowPower: boolean;
-  isHeadless: boolean;
-  isRemovable: boolean;
-  supportsRaytracing: boolean;
-  hasUnifiedMemory: boolean;
-  recommendedMaxWorkingSetSize: number;
-  maxTransferRate: number;
-  supportsBarycentricCoordinates: boolean;
-  supportsFunctionPointers: boolean;
-  supportsDynamicLibraries: boolean;
-  argumentBuffersSupport: string;
-  readWriteTextureSupport: string;
-  supports32bitFloatFiltering: boolean;
-  supports32bitMSAA: boolean;
-  supportsQueryTextureLOD: boolean;
-  supportsBCTextureCompression: boolean;
-  supportsPullModelInterpolation: boolean;
-  maxArgumentBufferSamplerCount: number;
-  currentAllocatedSize: number;
-  maxBufferLength: number;
-  counterSets: MetalCounterSet[];
-};
-
+freeVideoMem: number | null }
 /**
- * Contains information about the Metal capable devices identified on the system.
+ * Represents the current platform.
  */
-export type MetalInfo = {
-  totalDevices: number;
-  devices: MetalDevice[];
-};
-
+export type Platform = "Windows" | "macOS" | "Linux" | "Unknown"
 /**
- * Represents an individual disk on the system.
+ * Contains information of the current platform.
  */
-export type Disk = {
-  /** The name of the disk */
-  name: string;
-  /** The file-system of the disk (Eg: `EXT4`, `NTFS`, etc.) */
-  fileSystem: string;
-  /** The mount point of the disk */
-  mountPoint: string;
-  /** The total space/size of the disk */
-  totalSpace: number;
-  /** The currently available space on the disk */
-  availableSpace: number;
-  /** The kind of disk */
-  kind: string;
-};
-
+export type PlatformInfo = 
 /**
- * Contains information of all the disks identified on the system.
+ * Information specific to the current platform.
  */
-export type DisksInfo = {
-  /** The total number of disks */
-  count: number;
-  /** The information of each disk */
-  disks: Disk[];
-};
-
-export type Cache = {
-  size: number;
-  associativity: number;
-  sets: number;
-  partitions: number;
-  lineSize: number;
-  flags: number;
-};
-
-export type CacheInfo = {
-  l1i?: Cache;
-  l1d?: Cache;
-  l2?: Cache;
-  l3?: Cache;
-  l4?: Cache;
-};
-
-export type Processor = {
-  smtId: number;
-  windowsGroupId?: number;
-  windowsProcessorId?: number;
-  apicId: number;
-};
-
+(WindowsInfo | MacOSInfo | LinuxInfo) & { 
+/**
+ * The current platform (Windows/macOS/Linux).
+ */
+platform: Platform; 
+/**
+ * The hostname of the system.
+ */
+hostname: string; 
+/**
+ * The OS' architecture.
+ */
+osArch: string; 
+/**
+ * The current kernel version.
+ */
+kernel: string; 
+/**
+ * The fields that could not be gathered.
+ */
+warnings: FieldWarning[] }
+/**
+ * Represents the position coordinates of the top-left corner of the monitor relative to the larger full screen area (x, y).
+ */
+export type Position = { 
+/**
+ * The x coordinate.
+ */
+x: number; 
+/**
+ * The y coordinate.
+ */
+y: number }
 /**
  * Contains information of a single logical processor.
  */
-export type Core = {
-  id: number;
-  processorsCount: number;
-  cpuId: number;
-  frequency: number;
-  processors: Processor[];
-};
-
+export type Processor = { smtId: number; windowsGroupId: number | null; windowsProcessorId: number | null; apicId: number }
 /**
- * Contains information of the system's CPU/Processor.
- */
-export type CpuInfo = {
-  brand: string;
-  arch: string;
-  opModes: string;
-  vendorId: string;
-  cacheInfo: CacheInfo;
-  processorsCount: number;
-  coreCount: number;
-  threadsPerCore: number;
-  cores: Core[];
-};
-
+ * The redaction settings.
+ */
+export type RedactionConfig = { 
 /**
- * Represents an individual display attached to the system.
+ * Whether reports are redacted unless asked otherwise.
  */
-export type Display = {
-  /** The name of the display */
-  name: string;
-  /** The display dimensions (width x height) */
-  dimensions: string;
-  /** The scale factor of the display */
-  scaleFactor: number;
-  /** The top-left corner position of the monitor relative to the larger full screen area (x, y) */
-  position: string;
-  /** The display's refresh rate in MHz */
-  refreshRate?: number;
-};
-
+enabled: boolean; 
 /**
- * Contains information of the displays connected to the system.
+ * A JSON or YAML file of redaction rules to use instead of the default ones.
  */
-export type DisplaysInfo = {
-  /** The total number of displays */
-  totalDisplays: number;
-  /** The list of displays */
-  displays: Display[];
-};
-
+rules: string | null }
 /**
- * Represents an individual network interface on the system.
+ * Describes how a field of a section is redacted.
  */
-export type NetworkInterface = {
-  /** The name of the interface */
-  itfName: string;
-  /** The MAC address of the interface */
-  macAddr: string;
-  /** The IP networks belonging to the interface */
-  ipNetworks: string[];
-};
-
+export type RedactionRule = 
 /**
- * Contains information of the networks and network interfaces on the system.
+ * What is done to the field.
  */
-export type NetworksInfo = {
-  /** The total number of network interfaces */
-  totalInterfaces: number;
-  /** The list of network interfaces */
-  interfaces: NetworkInterface[];
-};
-
+(
 /**
- * Represents an interface belonging to a USB device.
+ * The value is removed (Eg: the field is left out of its object).
  */
-export type USBInterface = {
-  interfaceNumber: number;
-  class: number;
-  subclass: number;
-  protocol: number;
-  interfaceString?: string;
-};
-
+{ action: "drop" } | 
+/**
+ * The value is replaced by a hash salted with a secret unique to the report, so that equal
+ * values still match within the report but can't be recovered or matched across reports.
+ */
+{ action: "hash" } | 
+/**
+ * Only the leading characters of the value are kept.
+ */
+{ action: "prefix"; length: number }) & { 
+/**
+ * The section name (Eg: `usb`).
+ */
+section: string; 
+/**
+ * The path of the field within the section, with `[]` standing for any array element (Eg:
+ * `devices[].serialNumber`).
+ */
+path: string }
+/**
+ * The differences between two system reports.
+ */
+export type ReportDiff = { 
+/**
+ * The time the old report was generated, in milliseconds since the Unix epoch.
+ */
+oldGeneratedAt: number; 
+/**
+ * The time the new report was generated, in milliseconds since the Unix epoch.
+ */
+newGeneratedAt: number; 
+/**
+ * The sections that differ, keyed by section name.
+ */
+sections: Partial<{ [key in string]: SectionDiff }> }
+/**
+ * Payload of the `section-collected` event, emitted whenever a prefetched section is ready.
+ */
+export type SectionCollected = { section: string; snapshot: SectionSnapshot }
+/**
+ * The settings of a single section.
+ */
+export type SectionConfig = { 
+/**
+ * Whether the section is gathered. Disabled sections are left out of reports, and requests
+ * for them fail (Eg: to skip OpenGL on drivers known to crash).
+ */
+enabled: boolean; 
+/**
+ * How long the section's information is cached for by the app and the agent, in seconds. The
+ * information is cached indefinitely if omitted.
+ */
+ttl: number | null; 
+/**
+ * How long the section may take to be gathered, in seconds. Defaults to the section's own
+ * timeout.
+ */
+timeout: number | null }
+/**
+ * The differences within a single section.
+ */
+export type SectionDiff = { 
+/**
+ * The differences, in the order they were found.
+ */
+changes: Change[]; 
+/**
+ * The reason the section could not be compared (Eg: it failed to be gathered in one of the
+ * reports), if any.
+ */
+skipped?: string | null }
+/**
+ * Describes why a section could not be gathered.
+ */
+export type SectionError = { 
+/**
+ * The kind of error (same as the `kind` of a serialized [`CoreError`]).
+ */
+kind: string; 
+/**
+ * The error message.
+ */
+message: string }
+/**
+ * The outcome of gathering a single section of a report.
+ */
+export type SectionReport = { 
+/**
+ * The section information, if it was gathered successfully.
+ */
+data: JsonValue | null; 
+/**
+ * The time the information was gathered, in milliseconds since the Unix epoch.
+ */
+collectedAt: number | null; 
+/**
+ * The error encountered while gathering the section, if any.
+ */
+error: SectionError | null }
+/**
+ * The information of a section along with the time it was gathered.
+ */
+export type SectionSnapshot = { 
+/**
+ * The section information.
+ */
+data: JsonValue; 
+/**
+ * The time the information was gathered, in milliseconds since the Unix epoch.
+ */
+collectedAt: number }
+/**
+ * How long a request for a section took, and how it ended.
+ */
+export type SectionTiming = { 
+/**
+ * The section name.
+ */
+section: string; 
+/**
+ * The time the request was made, in milliseconds since the Unix epoch.
+ */
+requestedAt: number; 
+/**
+ * How long the request took, in milliseconds.
+ */
+durationMs: number; 
+/**
+ * Whether the request was served from the cache.
+ */
+cacheHit: boolean; 
+/**
+ * The error the request failed with, if any (Eg: a timeout).
+ */
+error: SectionError | null }
+/**
+ * A report file that could not be added to a fleet.
+ */
+export type SkippedReport = { 
+/**
+ * The path of the report.
+ */
+path: string; 
+/**
+ * Why the report was skipped.
+ */
+reason: string }
+/**
+ * A complete, self-describing snapshot of the system's information.
+ */
+export type SystemReport = { 
+/**
+ * The version of the report format (see [`SCHEMA_VERSION`]).
+ */
+schemaVersion: number; 
+/**
+ * The version of Revelio that generated the report.
+ */
+appVersion: string; 
+/**
+ * The time the report was generated, in milliseconds since the Unix epoch.
+ */
+generatedAt: number; 
+/**
+ * The gathered sections, keyed by section name.
+ */
+sections: Partial<{ [key in string]: SectionReport }> }
+/**
+ * The temperature of a hardware sensor.
+ */
+export type TemperatureSample = { 
+/**
+ * The label of the sensor (Eg: `coretemp Package id 0`, `nvme Composite`).
+ */
+label: string; 
+/**
+ * The temperature, in degrees Celsius.
+ */
+temperature: number; 
+/**
+ * The temperature the hardware considers critical, in degrees Celsius, if known.
+ */
+critical: number | null }
 /**
  * Represents a USB device on the system.
  */
-export type USBDevice = {
-  index: number;
-  busNumber: number;
-  deviceAddress: number;
-  vendorId: number;
-  productId: number;
-  deviceVersion: number;
-  class: number;
-  subclass: number;
-  protocol: number;
-  speed?: string;
-  manufacturerString?: string;
-  productString?: string;
-  serialNumber?: string;
-  interfaces: USBInterface[];
-};
-
+export type USBDevice = { index: number; busNumber: number; deviceAddress: number; vendorId: number; productId: number; deviceVersion: number; class: number; subclass: number; protocol: number; speed: USBSpeed | null; manufacturerString: string | null; productString: string | null; serialNumber: string | null; interfaces: USBInterface[] }
 /**
  * Contains information about the USB devices on the system.
  */
-export type USBInfo = {
-  totalDevices: number;
-  devices: USBDevice[];
-};
-
+export type USBInfo = { totalDevices: number; devices: USBDevice[] }
 /**
- * Information that's common across all supported platforms.
- */
-export interface PlatformInfoCommon {
-  /** The current platform (Windows/macOS/Linux) */
-  platform: string;
-  /** The hostname of the system */
-  hostname: string;
-  /** The OS' architecture */
-  osArch: string;
-  /** The current kernel version */
-  kernel: string;
-}
-
-type MacOSChipType = "x86_64" | "Apple Silicon";
-
+ * Represents an interface belonging to a USB device.
+ */
+export type USBInterface = { interfaceNumber: number; class: number; subclass: number; protocol: number; interfaceString: string | null }
 /**
- * Contains information about the current macOS installation.
+ * Represents the speed a USB device operates at.
  */
-export interface MacOSInfo extends PlatformInfoCommon {
-  /** The macOS version **/
-  macOSVersion: string;
-  /** The macOS chip type **/
-  chipType: MacOSChipType;
-  /** The current user's shell */
-  shell: string;
-}
-
-type LinuxGraphicsPlatform = "X11" | "Wayland";
-
+export type USBSpeed = { 
 /**
- * Contains information about the current Linux distribution.
+ * The speed class.
  */
-export interface LinuxInfo extends PlatformInfoCommon {
-  /** An identifier that describes the distribution's release */
-  id: string;
-  /** Identifier of the original upstream OS that this distribution is derived from */
-  idLike: string;
-  /** The name of this release, without the version string */
-  name: string;
-  /** The name of this release, with the version string */
-  prettyName: string;
-  /** The version of this OS release */
-  version: string;
-  /** The version of this OS release, along with additional details about the release */
-  versionId: string;
-  /** The codename of this version */
-  versionCodename: string;
-  /** The current graphics platform (X11/Wayland) */
-  graphicsPlatform: LinuxGraphicsPlatform;
-  /** The current Desktop environment */
-  desktop: string;
-  /** The current user's shell */
-  shell: string;
-  /** The bug report URL */
-  bugReportUrl: string;
-  /** The support URL */
-  supportUrl: string;
-  /** The support URL */
-  homeUrl: string;
-  /** The support URL */
-  privacyPolicyUrl: string;
-  /** The documentation URL */
-  documentationUrl: string;
-}
-
+class: USBSpeedClass; 
 /**
- * Contains information of the current platform.
+ * The signalling rate in Mbit/s, if known.
  */
-export type PlatformInfo = PlatformInfoCommon | MacOSInfo | LinuxInfo;
-
+mbps: number | null }
 /**
- * The kind of error from the core library.
+ * The USB speed classes a device can operate at.
  */
-export enum CoreErrorKind {
-  /** Error while retrieving CPU info */
-  CPUInfoError,
-  /** Error while retrieving Vulkan info */
-  VulkanInfoError,
-  /** Generic error */
-  Error,
-}
-
+export type USBSpeedClass = 
 /**
- * Custom error type for errors encountered while gathering system information.
+ * Low speed (1.5 Mbit/s).
  */
-export class CoreError extends Error {
-  public kind: CoreErrorKind;
-
-  constructor(message: string, kind: CoreErrorKind) {
-    super(message);
-    this.kind = kind;
-  }
-}
-
+"low" | 
 /**
- * Checks if the app is running in the release profile.
- *
- * @export
- * @async
- * @returns {Promise<boolean>} Resolves to whether the app is running in the release profile.
+ * Full speed (12 Mbit/s).
  */
-export async function isReleaseProfile(): Promise<boolean> {
-  return await invoke("is_release_profile");
-}
-
+"full" | 
 /**
- * Returns the current operating system type.
- *
- * @export
- * @async
- * @returns {Promise<OsType>} Resolves to the operating system type.
+ * High speed (480 Mbit/s).
  */
-export async function getOsType(): Promise<OsType> {
-  return await invoke("get_os_type");
-}
-
+"high" | 
 /**
- * Retrieves CPU information from the system.
- *
- * @export
- * @async
- * @returns {Promise<CpuInfo>} Resolves to the CPU information.
- * @throws {CoreError} If gathering CPU information fails.
+ * SuperSpeed (5 Gbit/s).
  */
-export async function getCpuInfo(): Promise<CpuInfo> {
-  return await invoke("get_cpu_info");
-}
-
+"super" | 
 /**
- * Retrieves disk(s) information from the system.
- *
- * @export
- * @async
- * @returns {Promise<CpuInfo>} Resolves to the disk(s) information.
- * @throws {CoreError} If gathering disk(s) information fails.
+ * SuperSpeed+ (10 Gbit/s or more).
  */
-export async function getDisksInfo(): Promise<DisksInfo> {
-  return await invoke("get_disks_info");
-}
-
+"superPlus" | 
 /**
- * Retrieves Vulkan information from the system.
- *
- * @export
- * @async
- * @returns {Promise<CpuInfo>} Resolves to the Vulkan information.
- * @throws {CoreError} If gathering Vulkan information fails.
+ * A speed class not known to Revelio.
  */
-export async function getVulkanInfo(): Promise<VulkanInfo> {
-  return await invoke("get_vulkan_info");
-}
-
+"unknown"
 /**
- * Retrieves the OpenGL information from the system.
- *
- * FIXME!: calling this function currently causes the core of the application to freeze and eventually crash. DO NOT USE!!!
- *
- * @export
- * @async
- * @returns {Promise<OpenGLInfo>} Resolves to the OpenGL information.
- * @throws {CoreError} If gathering OpenGL information fails.
- */
-export async function getOpenGLInfo(): Promise<OpenGLInfo> {
-  return await invoke("get_opengl_info");
-}
-
+ * Contains information of a Vulkan device.
+ */
+export type VulkanDevice = { 
 /**
- * Retrieves DirectX information from the system.
- *
- * @export
- * @async
- * @returns {Promise{DirectXInfo}} Resolves to the DirectX information.
- * @throws {CoreError} If gathering DirectX information fails.
+ * Device index; useful for unique identification in mapping functions, but otherwise not bound to the device itself.
  */
-export async function getDirectXInfo(): Promise<DirectXInfo> {
-  return await invoke("get_directx_info");
-}
-
+index: number; 
 /**
- * Retrieves the Metal information from the system.
- *
- * @export
- * @async
- * @returns {Promise<MetalInfo>} Resolves to the Metal information.
- * @throws {CoreError} If gathering Metal information fails.
+ * The name of the device.
  */
-export async function getMetalInfo(): Promise<MetalInfo> {
-  return await invoke("get_metal_info");
-}
-
+deviceName: string; 
 /**
- * Retrieves information about the displays connected to the system.
- *
- * FIXME!: calling this function currently causes the core of the application to freeze and eventually crash. DO NOT USE!!!
- *
- * @export
- * @async
- * @returns {Promise<DisplaysInfo>} Resolves to the displays information.
- * @throws {CoreError} If gathering displays information fails.
- */
-export async function getDisplaysInfo(): Promise<DisplaysInfo> {
-  return await invoke("get_displays_info");
-}
-
+ * The device's vendor ID.
+ */
+vendorId: number; 
 /**
- * Retrieves information about the USB devices detected on the system.
- *
- * @export
- * @async
- * @returns {Promise<USBInfo>} Resolves to the USB information.
- * @throws {CoreError} If gathering USB information fails.
+ * The device ID.
  */
-export async function getUSBInfo(): Promise<USBInfo> {
-  return await invoke("get_usb_info");
-}
-
+deviceId: number; 
 /**
- * Retrieves network(s) information from the system.
- *
- * @export
- * @async
- * @returns {Promise<NetworksInfo>} Resolves to the network(s) information.
+ * The type of device (Integrated, Discrete, Virtual, etc.), if recognized.
  */
-export async function getNetworksInfo(): Promise<NetworksInfo> {
-  return await invoke("get_networks_info");
-}
-
-// FIXME!: handle platform-specific information.
+deviceType: VulkanDeviceType | null; 
 /**
- * Retrieves platform information from the system.
- *
- * @export
- * @async
- * @returns {Promise<PlatformInfo>} Resolves to the platform information.
- * @throws {CoreError} If gathering platform information fails.
+ * The Vulkan API version the device supports.
  */
-export async function getPlatformInfo(): Promise<PlatformInfo> {
-  return await invoke("get_platform_info");
-}
-
+apiVersion: string; 
+/**
+ * The device's driver version.
+ */
+driverVersion: string; 
+/**
+ * The pipeline cache UUID.
+ */
+pipelineCacheUuid: string; 
+/**
+ * The device's Vulkan layers.
+ */
+layers: VulkanDeviceLayer[] }
+/**
+ * Contains information of a Vulkan device layer.
+ */
+export type VulkanDeviceLayer = { 
+/**
+ * The layer's name.
+ */
+layerName: string; 
+/**
+ * The layer's Vulkan version.
+ */
+vulkanVersion: string; 
+/**
+ * The layer's version.
+ */
+layerVersion: number; 
+/**
+ * The layer's description.
+ */
+description: string }
+/**
+ * <https://registry.khronos.org/vulkan/specs/1.3-extensions/man/html/VkPhysicalDeviceType.html>
+ */
+export type VulkanDeviceType = "other" | "integratedGpu" | "discreteGpu" | "virtualGpu" | "cpu"
 /**
- * Returns the app version.
- *
- * @export
- * @async
- * @returns {Promise<string>} Resolves to the app version.
+ * Contains information about Vulkan on the system.
+ */
+export type VulkanInfo = { 
+/**
+ * The total number of Vulkan-enabled devices.
+ */
+totalDevices: number; 
+/**
+ * The list of Vulkan-enabled devices identified on the system.
+ */
+devices: VulkanDevice[]; 
+/**
+ * The fields that could not be gathered.
+ */
+warnings: FieldWarning[] }
+/**
+ * Contains information about the current Windows installation.
  */
-export async function getAppVersion(): Promise<string> {
-  return await invoke("get_app_version");
+export type WindowsInfo = Record<string, never>
+
+/** tauri-specta globals **/
+
+import {
+	invoke as TAURI_INVOKE,
+	Channel as TAURI_CHANNEL,
+} from "@tauri-apps/api/core";
+import * as TAURI_API_EVENT from "@tauri-apps/api/event";
+import { type WebviewWindow as __WebviewWindow__ } from "@tauri-apps/api/webviewWindow";
+
+type __EventObj__<T> = {
+	listen: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.listen<T>>;
+	once: (
+		cb: TAURI_API_EVENT.EventCallback<T>,
+	) => ReturnType<typeof TAURI_API_EVENT.once<T>>;
+	emit: null extends T
+		? (payload?: T) => ReturnType<typeof TAURI_API_EVENT.emit>
+		: (payload: T) => ReturnType<typeof TAURI_API_EVENT.emit>;
+};
+
+export type Result<T, E> =
+	| { status: "ok"; data: T }
+	| { status: "error"; error: E };
+
+function __makeEvents__<T extends Record<string, any>>(
+	mappings: Record<keyof T, string>,
+) {
+	return new Proxy(
+		{} as unknown as {
+			[K in keyof T]: __EventObj__<T[K]> & {
+				(handle: __WebviewWindow__): __EventObj__<T[K]>;
+			};
+		},
+		{
+			get: (_, event) => {
+				const name = mappings[event as keyof T];
+
+				return new Proxy((() => {}) as any, {
+					apply: (_, __, [window]: [__WebviewWindow__]) => ({
+						listen: (arg: any) => window.listen(name, arg),
+						once: (arg: any) => window.once(name, arg),
+						emit: (arg: any) => window.emit(name, arg),
+					}),
+					get: (_, command: keyof __EventObj__<any>) => {
+						switch (command) {
+							case "listen":
+								return (arg: any) => TAURI_API_EVENT.listen(name, arg);
+							case "once":
+								return (arg: any) => TAURI_API_EVENT.once(name, arg);
+							case "emit":
+								return (arg: any) => TAURI_API_EVENT.emit(name, arg);
+						}
+					},
+				});
+			},
+		},
+	);
 }
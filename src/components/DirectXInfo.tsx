@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { DirectXInfo as DXInfo, commands } from "../bindings";
+import { DirectXInfo as DXInfo, commands } from "../api";
 import {
   Spinner,
   Selection,
@@ -122,7 +122,7 @@ function DirectXInfo() {
     commands
       .getDirectXInfo()
       .then((info) => {
-        setDirectXInfo(info);
+        setDirectXInfo(info.data);
         console.debug(info);
       })
       .catch((error) => {
@@ -0,0 +1,69 @@
+import {
+  Button,
+  Dropdown,
+  DropdownItem,
+  DropdownMenu,
+  DropdownTrigger,
+  Switch,
+} from "@nextui-org/react";
+import { Key, useEffect, useState } from "react";
+import { MdSaveAlt } from "react-icons/md";
+import { commands, ExportFormat } from "../api";
+
+function ExportMenu() {
+  const [redact, setRedact] = useState(false);
+
+  useEffect(() => {
+    commands
+      .getConfig()
+      .then((config) => setRedact(config.redaction.enabled))
+      .catch((error) => {
+        console.error(error);
+      });
+  }, []);
+
+  function handleExport(key: Key) {
+    // Identifying values (hostname, serial numbers, addresses, ...) are hashed with a salt unique to the report,
+    // unless the configuration has its own redaction rules.
+    const rules = redact
+      ? commands.getDefaultRedactionRules()
+      : Promise.resolve(null);
+
+    rules
+      .then((redaction) =>
+        commands.exportSystemReport(key as ExportFormat, null, redaction),
+      )
+      .then((path) => {
+        if (path) {
+          console.info(`Exported system report to ${path}`);
+        }
+      })
+      .catch((error) => {
+        console.error(error);
+      });
+  }
+
+  return (
+    <div className="fixed bottom-20 flex flex-col space-y-2">
+      <Dropdown>
+        <DropdownTrigger>
+          <Button size="sm" variant="flat" startContent={<MdSaveAlt />}>
+            Export
+          </Button>
+        </DropdownTrigger>
+        <DropdownMenu aria-label="Export format" onAction={handleExport}>
+          <DropdownItem key="json">JSON</DropdownItem>
+          <DropdownItem key="yaml">YAML</DropdownItem>
+          <DropdownItem key="markdown">Markdown</DropdownItem>
+          <DropdownItem key="html">HTML</DropdownItem>
+        </DropdownMenu>
+      </Dropdown>
+
+      <Switch size="sm" isSelected={redact} onValueChange={setRedact}>
+        Redact
+      </Switch>
+    </div>
+  );
+}
+
+export default ExportMenu;
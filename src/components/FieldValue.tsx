@@ -0,0 +1,30 @@
+import { ReactNode } from "react";
+import { FieldWarning } from "../api";
+
+export interface FieldValueProps {
+  /** The path of the field within the section (Eg: "graphicsPlatform", "devices[0].deviceName") */
+  field: string;
+  /** The warnings reported with the section */
+  warnings: FieldWarning[];
+  /** The value of the field */
+  children: ReactNode;
+}
+
+/**
+ * Displays the value of a field, or the reason it is unavailable if the core could not gather it.
+ */
+function FieldValue(props: FieldValueProps) {
+  const warning = props.warnings.find((w) => w.field === props.field);
+
+  if (!warning) {
+    return <>{props.children}</>;
+  }
+
+  return (
+    <span className="italic text-default-400" title={warning.field}>
+      unavailable: {warning.reason}
+    </span>
+  );
+}
+
+export default FieldValue;
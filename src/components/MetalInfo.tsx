@@ -1,4 +1,4 @@
-import { commands, MetalCounterSet, MetalInfo as MTLInfo } from "../bindings";
+import { commands, MetalCounterSet, MetalInfo as MTLInfo } from "../api";
 import { useEffect, useState } from "react";
 import {
   Card,
@@ -56,7 +56,9 @@ function Device(props: DeviceProps) {
 
                 <TableRow>
                   <TableCell className="font-bold w-1/3">Location</TableCell>
-                  <TableCell className="font-mono">{device.location}</TableCell>
+                  <TableCell className="font-mono">
+                    {utils.formatMetalLocation(device.location)}
+                  </TableCell>
                 </TableRow>
 
                 <TableRow>
@@ -82,7 +84,7 @@ function Device(props: DeviceProps) {
                     Max Threads Per Threadgroup
                   </TableCell>
                   <TableCell className="font-mono">
-                    {device.maxThreadsPerThreadgroup}
+                    {utils.formatMetalSize(device.maxThreadsPerThreadgroup)}
                   </TableCell>
                 </TableRow>
 
@@ -181,7 +183,7 @@ function Device(props: DeviceProps) {
                     Argument Buffers Support
                   </TableCell>
                   <TableCell className="font-mono">
-                    {device.argumentBuffersSupport}
+                    {utils.formatMetalTier(device.argumentBuffersSupport)}
                   </TableCell>
                 </TableRow>
 
@@ -190,7 +192,7 @@ function Device(props: DeviceProps) {
                     Read-Write Texture Support
                   </TableCell>
                   <TableCell className="font-mono">
-                    {device.readWriteTextureSupport}
+                    {utils.formatMetalTier(device.readWriteTextureSupport)}
                   </TableCell>
                 </TableRow>
 
@@ -312,7 +314,7 @@ function MetalInfo() {
     commands
       .getMetalInfo()
       .then((info) => {
-        setMetalInfo(info);
+        setMetalInfo(info.data);
         console.debug(info);
       })
       .catch((error) => {
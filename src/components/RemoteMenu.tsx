@@ -0,0 +1,88 @@
+import {
+  Button,
+  Input,
+  Popover,
+  PopoverContent,
+  PopoverTrigger,
+} from "@nextui-org/react";
+import { useEffect, useState } from "react";
+import { MdLan } from "react-icons/md";
+import { commands } from "../api";
+
+function RemoteMenu() {
+  const [remote, setRemote] = useState<string | null>(null);
+  const [url, setUrl] = useState("");
+  const [token, setToken] = useState("");
+  const [error, setError] = useState<string>();
+
+  useEffect(() => {
+    commands.getRemoteAgent().then((url) => {
+      setRemote(url);
+      setUrl(url ?? "");
+    });
+  }, []);
+
+  function connect(url: string | null) {
+    commands
+      .setRemoteAgent(url, token || null)
+      .then(() => {
+        // Every view is loaded again from the newly inspected system.
+        window.location.reload();
+      })
+      .catch((error) => {
+        console.error(error);
+        setError(error.message ?? String(error));
+      });
+  }
+
+  return (
+    <div className="fixed bottom-40">
+      <Popover placement="right">
+        <PopoverTrigger>
+          <Button
+            size="sm"
+            variant="flat"
+            color={remote ? "secondary" : "default"}
+            startContent={<MdLan />}
+          >
+            {remote ? "Remote" : "Local"}
+          </Button>
+        </PopoverTrigger>
+        <PopoverContent className="w-80 p-4 flex flex-col space-y-2">
+          <Input
+            size="sm"
+            label="Agent URL"
+            placeholder="http://127.0.0.1:7878"
+            value={url}
+            onValueChange={setUrl}
+          />
+          <Input
+            size="sm"
+            type="password"
+            label="Token"
+            value={token}
+            onValueChange={setToken}
+          />
+          {error && <p className="text-xs text-danger">{error}</p>}
+          <div className="flex space-x-2 self-end">
+            {remote && (
+              <Button size="sm" variant="flat" onPress={() => connect(null)}>
+                Disconnect
+              </Button>
+            )}
+            <Button
+              size="sm"
+              color="primary"
+              isDisabled={!url}
+              onPress={() => connect(url)}
+            >
+              Connect
+            </Button>
+          </div>
+        </PopoverContent>
+      </Popover>
+    </div>
+  );
+}
+
+export default RemoteMenu;
@@ -1,9 +1,15 @@
 import { ReactNode, useEffect } from "react";
 import { getCurrentWindow } from "@tauri-apps/api/window";
+import { Button } from "@nextui-org/react";
+import { MdRefresh } from "react-icons/md";
 
 export interface ViewContainerProps {
   title: string;
   children: ReactNode;
+  /** The time the displayed information was gathered, in milliseconds since the Unix epoch */
+  collectedAt?: number;
+  /** Called when the user requests fresh information */
+  onRefresh?: () => void;
 }
 
 function ViewContainer(props: ViewContainerProps) {
@@ -24,6 +30,25 @@ function ViewContainer(props: ViewContainerProps) {
     <div className={"w-full flex-row text-center items-center mb-4 wl-2 mr-2"}>
       <h1 className="font-bold m-4">{props.title}</h1>
 
+      {props.onRefresh && (
+        <div className="flex justify-center items-center space-x-2 mb-4 text-xs">
+          {props.collectedAt && (
+            <span>
+              Last updated: {new Date(props.collectedAt).toLocaleTimeString()}
+            </span>
+          )}
+          <Button
+            size="sm"
+            variant="flat"
+            isIconOnly
+            aria-label="Refresh"
+            onPress={props.onRefresh}
+          >
+            <MdRefresh />
+          </Button>
+        </div>
+      )}
+
       {props.children}
     </div>
   );
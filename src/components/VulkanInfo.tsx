@@ -21,7 +21,13 @@ import {
   Tabs,
 } from "@nextui-org/react";
 import * as utils from "../utils.ts";
-import { commands, VulkanDeviceLayer, VulkanInfo as VkInfo } from "../bindings";
+import {
+  commands,
+  FieldWarning,
+  VulkanDeviceLayer,
+  VulkanInfo as VkInfo,
+} from "../api";
+import FieldValue from "./FieldValue";
 import { useEffect, useState } from "react";
 import { useTheme } from "next-themes";
 
@@ -32,6 +38,9 @@ interface DeviceProps {
 
 interface LayerProps {
   layers: VulkanDeviceLayer[];
+  /** The index of the device the layers belong to */
+  device: number;
+  warnings: FieldWarning[];
 }
 
 function Device(props: DeviceProps) {
@@ -52,7 +61,12 @@ function Device(props: DeviceProps) {
                 <TableRow>
                   <TableCell className="font-bold w-1/3">Device Name</TableCell>
                   <TableCell className="font-mono">
-                    {device.deviceName}
+                    <FieldValue
+                      field={`devices[${device.index}].deviceName`}
+                      warnings={info.warnings}
+                    >
+                      {device.deviceName}
+                    </FieldValue>
                   </TableCell>
                 </TableRow>
 
@@ -69,7 +83,12 @@ function Device(props: DeviceProps) {
                 <TableRow>
                   <TableCell className="font-bold w-1/3">Device Type</TableCell>
                   <TableCell className="font-mono">
-                    {device.deviceType}
+                    <FieldValue
+                      field={`devices[${device.index}].deviceType`}
+                      warnings={info.warnings}
+                    >
+                      {utils.formatVulkanDeviceType(device.deviceType)}
+                    </FieldValue>
                   </TableCell>
                 </TableRow>
 
@@ -107,13 +126,23 @@ function Device(props: DeviceProps) {
 }
 
 function Layers(props: LayerProps) {
-  const { layers } = props;
+  const { layers, device, warnings } = props;
 
   return (
     <Card shadow="none">
       <Accordion fullWidth defaultSelectedKeys="all">
-        {layers.map((layer) => (
-          <AccordionItem title={layer.layerName} className="font-bold text-sm">
+        {layers.map((layer, index) => (
+          <AccordionItem
+            title={
+              <FieldValue
+                field={`devices[${device}].layers[${index}].layerName`}
+                warnings={warnings}
+              >
+                {layer.layerName}
+              </FieldValue>
+            }
+            className="font-bold text-sm"
+          >
             <Table isStriped shadow="none" fullWidth>
               <TableHeader>
                 <TableColumn>Property</TableColumn>
@@ -142,7 +171,12 @@ function Layers(props: LayerProps) {
                 <TableRow>
                   <TableCell className="font-bold w-1/3">Description</TableCell>
                   <TableCell className="font-mono">
-                    {layer.description}
+                    <FieldValue
+                      field={`devices[${device}].layers[${index}].description`}
+                      warnings={warnings}
+                    >
+                      {layer.description}
+                    </FieldValue>
                   </TableCell>
                 </TableRow>
               </TableBody>
@@ -170,7 +204,7 @@ function VulkanInfo() {
     commands
       .getVulkanInfo()
       .then((info) => {
-        setVulkanInfo(info);
+        setVulkanInfo(info.data);
         console.debug(info);
       })
       .catch((error) => {
@@ -282,7 +316,11 @@ function VulkanInfo() {
           titleValue="Layers"
           className="w-full"
         >
-          <Layers layers={getCurrentDeviceLayers()} />
+          <Layers
+            layers={getCurrentDeviceLayers()}
+            device={Number([...currentDevice][0])}
+            warnings={vulkanInfo.warnings}
+          />
         </Tab>
       </Tabs>
     </>
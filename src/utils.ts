@@ -87,6 +87,16 @@ import bash from "./assets/images/bash.svg";
 import zshLight from "./assets/images/light/zsh.svg";
 import zshDark from "./assets/images/dark/zsh.svg";
 
+import type {
+  Dimensions,
+  DiskKind,
+  MetalDeviceLocation,
+  MetalSize,
+  Position,
+  USBSpeed,
+  VulkanDeviceType,
+} from "./api";
+
 /**
  * The logo variant based on the current theme ("light" or "dark").
  */
@@ -569,6 +579,131 @@ export function formatBytesPerSecond(
   return `${value.toFixed(decimals)} ${units[index]}`;
 }
 
+/**
+ * Formats display dimensions as "width x height".
+ *
+ * @export
+ * @param dimensions The display dimensions.
+ * @returns {string} The formatted dimensions.
+ */
+export function formatDimensions(dimensions: Dimensions): string {
+  return `${dimensions.width}x${dimensions.height}`;
+}
+
+/**
+ * Formats a display position as "(x, y)".
+ *
+ * @export
+ * @param position The display position.
+ * @returns {string} The formatted position.
+ */
+export function formatPosition(position: Position): string {
+  return `(${position.x}, ${position.y})`;
+}
+
+/**
+ * Formats the kind of a disk.
+ *
+ * @export
+ * @param kind The disk kind.
+ * @returns {string} The formatted disk kind.
+ */
+export function formatDiskKind(kind: DiskKind): string {
+  switch (kind) {
+    case "hdd":
+      return "Hard Disk Drive (HDD)";
+    case "ssd":
+      return "Solid State Drive (SSD)";
+    default:
+      return `Unknown (${kind.unknown})`;
+  }
+}
+
+const USB_SPEED_CLASSES: Record<USBSpeed["class"], string> = {
+  low: "Low speed",
+  full: "Full speed",
+  high: "High speed",
+  super: "SuperSpeed",
+  superPlus: "SuperSpeed+",
+  unknown: "Unknown",
+};
+
+/**
+ * Formats the speed of a USB device along with its rate.
+ *
+ * @export
+ * @param speed The USB speed, if known.
+ * @returns {string} The formatted speed.
+ */
+export function formatUSBSpeed(speed: USBSpeed | null): string {
+  if (!speed) return "Unknown";
+
+  const label = USB_SPEED_CLASSES[speed.class];
+  return speed.mbps === null ? label : `${label} (${speed.mbps} Mbit/s)`;
+}
+
+const VULKAN_DEVICE_TYPES: Record<VulkanDeviceType, string> = {
+  other: "Other",
+  integratedGpu: "Integrated GPU",
+  discreteGpu: "Discrete GPU",
+  virtualGpu: "Virtual GPU",
+  cpu: "CPU",
+};
+
+/**
+ * Formats the type of a Vulkan device.
+ *
+ * @export
+ * @param deviceType The device type, if recognized.
+ * @returns {string} The formatted device type.
+ */
+export function formatVulkanDeviceType(
+  deviceType: VulkanDeviceType | null,
+): string {
+  return deviceType ? VULKAN_DEVICE_TYPES[deviceType] : "Unknown";
+}
+
+/**
+ * Formats a Metal size as its product along with its dimensions.
+ *
+ * @export
+ * @param size The Metal size.
+ * @returns {string} The formatted size.
+ */
+export function formatMetalSize(size: MetalSize): string {
+  const product = size.width * size.height * size.depth;
+  return `${product.toLocaleString("en")} (width: ${size.width} height: ${size.height} depth: ${size.depth})`;
+}
+
+const METAL_LOCATIONS: Record<MetalDeviceLocation, string> = {
+  builtIn: "Built-in",
+  slot: "Slot",
+  external: "External",
+  unspecified: "Unspecified",
+};
+
+/**
+ * Formats the location of a Metal device.
+ *
+ * @export
+ * @param location The device location.
+ * @returns {string} The formatted location.
+ */
+export function formatMetalLocation(location: MetalDeviceLocation): string {
+  return METAL_LOCATIONS[location];
+}
+
+/**
+ * Formats a Metal support tier (Eg: "tier1" as "Tier 1").
+ *
+ * @export
+ * @param tier The support tier.
+ * @returns {string} The formatted tier.
+ */
+export function formatMetalTier(tier: "tierNone" | "tier1" | "tier2"): string {
+  return tier === "tierNone" ? "Tier None" : `Tier ${tier.slice(4)}`;
+}
+
 // Example usage
 const formatted = formatBytesPerSecond(1500); // Output: "1.46 KB/s"
 console.log(formatted);
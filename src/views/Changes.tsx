@@ -0,0 +1,141 @@
+import { useEffect, useState } from "react";
+import { Change, commands, events, JsonValue, ReportDiff } from "../api";
+import {
+  Card,
+  CardBody,
+  CardHeader,
+  Spacer,
+  Table,
+  TableBody,
+  TableCell,
+  TableColumn,
+  TableHeader,
+  TableRow,
+} from "@nextui-org/react";
+import ViewContainer from "../components/ViewContainer";
+
+const KIND_LABELS: Record<Change["kind"], string> = {
+  added: "Added",
+  removed: "Removed",
+  changed: "Changed",
+};
+
+function formatValue(value: JsonValue | null | undefined): string {
+  if (value === undefined || value === null) {
+    return "";
+  }
+
+  return typeof value === "string" ? value : JSON.stringify(value);
+}
+
+function Changes() {
+  const [diff, setDiff] = useState<ReportDiff | null>(null);
+  const [checking, setChecking] = useState(false);
+  const [error, setError] = useState<string>();
+
+  useEffect(() => {
+    // The check started on launch may still be running.
+    const unlisten = events.inventoryChecked.listen((event) => {
+      setDiff(event.payload);
+    });
+
+    commands
+      .getInventoryChanges()
+      .then(setDiff)
+      .catch((error) => {
+        console.error(error);
+      });
+
+    return () => {
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  function check() {
+    setChecking(true);
+    setError(undefined);
+    commands
+      .checkInventory()
+      .then(setDiff)
+      .catch((error) => {
+        console.error(error);
+        setError(error.message ?? String(error));
+      })
+      .finally(() => setChecking(false));
+  }
+
+  const sections = Object.entries(diff?.sections ?? {});
+
+  return (
+    <ViewContainer
+      title="Changes Since Last Run"
+      collectedAt={diff?.newGeneratedAt}
+      onRefresh={checking ? undefined : check}
+    >
+      {error && <p className="text-sm text-danger">{error}</p>}
+
+      {!diff && (
+        <p className="text-sm">
+          No hardware inventory was saved by a previous run yet.
+        </p>
+      )}
+
+      {diff && sections.length == 0 && (
+        <p className="text-sm">
+          Nothing changed since{" "}
+          {new Date(diff.oldGeneratedAt).toLocaleString()}.
+        </p>
+      )}
+
+      {sections.map(([section, sectionDiff]) => (
+        <div key={section}>
+          <Card shadow="sm">
+            <CardHeader className="font-bold">{section}</CardHeader>
+            <CardBody>
+              {sectionDiff?.skipped && (
+                <p className="text-sm text-warning">{sectionDiff.skipped}</p>
+              )}
+              {sectionDiff && sectionDiff.changes.length > 0 && (
+                <Table
+                  isStriped
+                  shadow="none"
+                  aria-label={`${section} changes`}
+                >
+                  <TableHeader>
+                    <TableColumn>Change</TableColumn>
+                    <TableColumn>Path</TableColumn>
+                    <TableColumn>Last Run</TableColumn>
+                    <TableColumn>Now</TableColumn>
+                  </TableHeader>
+
+                  <TableBody>
+                    {sectionDiff.changes.map((change, index) => (
+                      <TableRow key={index}>
+                        <TableCell className="font-bold">
+                          {KIND_LABELS[change.kind]}
+                        </TableCell>
+                        <TableCell className="font-mono">
+                          {change.path || "(section)"}
+                        </TableCell>
+                        <TableCell className="font-mono">
+                          {formatValue(change.old)}
+                        </TableCell>
+                        <TableCell className="font-mono">
+                          {formatValue(change.new)}
+                        </TableCell>
+                      </TableRow>
+                    ))}
+                  </TableBody>
+                </Table>
+              )}
+            </CardBody>
+          </Card>
+
+          <Spacer y={4} />
+        </div>
+      ))}
+    </ViewContainer>
+  );
+}
+
+export default Changes;
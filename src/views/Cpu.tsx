@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { commands, CpuInfo, Cache, Core } from "../bindings";
+import { commands, CpuInfo, Cache, Core } from "../api";
 import {
   Accordion,
   AccordionItem,
@@ -18,6 +18,7 @@ import {
   TableRow,
 } from "@nextui-org/react";
 import ViewContainer from "../components/ViewContainer";
+import FieldValue from "../components/FieldValue";
 import * as utils from "../utils";
 import { useTheme } from "next-themes";
 
@@ -191,7 +192,7 @@ function Cpu() {
     commands
       .getCpuInfo()
       .then((info) => {
-        setCpuInfo(info);
+        setCpuInfo(info.data);
         console.info("CPU information retrieved successfully.");
       })
       .catch((error) => {
@@ -221,7 +222,11 @@ function Cpu() {
 
             <Spacer />
 
-            <h1 className="font-bold text-lg">{cpuInfo.brand}</h1>
+            <h1 className="font-bold text-lg">
+              <FieldValue field="brand" warnings={cpuInfo.warnings}>
+                {cpuInfo.brand}
+              </FieldValue>
+            </h1>
           </div>
         </CardHeader>
 
@@ -251,7 +256,9 @@ function Cpu() {
 
                     <Spacer x={2} />
 
-                    {cpuInfo.vendorId}
+                    <FieldValue field="vendorId" warnings={cpuInfo.warnings}>
+                      {cpuInfo.vendorId}
+                    </FieldValue>
                   </div>
                 </TableCell>
               </TableRow>
@@ -265,7 +272,11 @@ function Cpu() {
 
               <TableRow>
                 <TableCell className="font-bold w-[35%]">Op-Modes</TableCell>
-                <TableCell className="font-mono">{cpuInfo.opModes}</TableCell>
+                <TableCell className="font-mono">
+                  <FieldValue field="opModes" warnings={cpuInfo.warnings}>
+                    {cpuInfo.opModes}
+                  </FieldValue>
+                </TableCell>
               </TableRow>
 
               <TableRow>
@@ -0,0 +1,82 @@
+import { commands, SectionTiming } from "../api";
+import { useEffect, useState } from "react";
+import {
+  Card,
+  CardBody,
+  Spinner,
+  Table,
+  TableBody,
+  TableCell,
+  TableColumn,
+  TableHeader,
+  TableRow,
+} from "@nextui-org/react";
+import ViewContainer from "../components/ViewContainer.tsx";
+
+function Diagnostics() {
+  const [timings, setTimings] = useState<SectionTiming[]>();
+  const [updatedAt, setUpdatedAt] = useState<number>();
+
+  function refresh() {
+    commands
+      .getDiagnostics()
+      .then((timings) => {
+        console.debug(timings);
+        setTimings(timings);
+        setUpdatedAt(Date.now());
+      })
+      .catch((error) => {
+        console.error(error);
+      });
+  }
+
+  useEffect(refresh, []);
+
+  if (!timings) {
+    return <Spinner label="Loading..." color="primary" />;
+  }
+
+  return (
+    <ViewContainer
+      title="Diagnostics"
+      collectedAt={updatedAt}
+      onRefresh={refresh}
+    >
+      <Card shadow="sm">
+        <CardBody>
+          <Table isStriped shadow="none" aria-label="Section timings">
+            <TableHeader>
+              <TableColumn>Section</TableColumn>
+              <TableColumn>Requested At</TableColumn>
+              <TableColumn>Duration</TableColumn>
+              <TableColumn>Cache Hit</TableColumn>
+              <TableColumn>Error</TableColumn>
+            </TableHeader>
+
+            <TableBody emptyContent="No section has been requested yet.">
+              {timings.map((timing) => (
+                <TableRow key={timing.section}>
+                  <TableCell className="font-bold">{timing.section}</TableCell>
+                  <TableCell className="font-mono">
+                    {new Date(timing.requestedAt).toLocaleTimeString()}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {timing.durationMs.toFixed(1)} ms
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {timing.cacheHit ? "Yes" : "No"}
+                  </TableCell>
+                  <TableCell className="font-mono text-danger">
+                    {timing.error?.message ?? ""}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </CardBody>
+      </Card>
+    </ViewContainer>
+  );
+}
+
+export default Diagnostics;
@@ -1,4 +1,4 @@
-import { commands, DisplaysInfo } from "../bindings";
+import { commands, DisplaysInfo, Snapshot } from "../api";
 import { useEffect, useState } from "react";
 import {
   Card,
@@ -14,15 +14,31 @@ import {
   TableRow,
 } from "@nextui-org/react";
 import ViewContainer from "../components/ViewContainer";
+import * as utils from "../utils";
 
 function Displays() {
   let [displaysInfo, setDisplaysInfo] = useState<DisplaysInfo>();
+  let [collectedAt, setCollectedAt] = useState<number>();
+
+  function handleSnapshot(snapshot: Snapshot<DisplaysInfo>) {
+    setDisplaysInfo(snapshot.data);
+    setCollectedAt(snapshot.collectedAt);
+  }
+
+  function refresh() {
+    commands
+      .refreshDisplaysInfo()
+      .then(handleSnapshot)
+      .catch((error) => {
+        console.error(error);
+      });
+  }
 
   useEffect(() => {
     commands
       .getDisplaysInfo()
       .then((info) => {
-        setDisplaysInfo(info);
+        handleSnapshot(info);
         console.info("Displays information retrieved successfully.");
       })
       .catch((error) => {
@@ -35,7 +51,11 @@ function Displays() {
   }
 
   return (
-    <ViewContainer title="Displays Information">
+    <ViewContainer
+      title="Displays Information"
+      collectedAt={collectedAt}
+      onRefresh={refresh}
+    >
       <p className="text-sm">Total displays: {displaysInfo.totalDisplays}</p>
 
       <Spacer y={4} />
@@ -56,7 +76,9 @@ function Displays() {
                     <TableCell className="font-bold w-1/2">
                       Dimensions
                     </TableCell>
-                    <TableCell>{display.dimensions}</TableCell>
+                    <TableCell>
+                      {utils.formatDimensions(display.dimensions)}
+                    </TableCell>
                   </TableRow>
 
                   <TableRow key={2}>
@@ -68,7 +90,9 @@ function Displays() {
 
                   <TableRow key={3}>
                     <TableCell className="font-bold w-1/2">Position</TableCell>
-                    <TableCell>{display.position}</TableCell>
+                    <TableCell>
+                      {utils.formatPosition(display.position)}
+                    </TableCell>
                   </TableRow>
 
                   <TableRow key={4}>
@@ -0,0 +1,132 @@
+import { useState } from "react";
+import { commands, FleetHost, FleetSummary } from "../api";
+import {
+  Button,
+  Card,
+  CardBody,
+  Input,
+  Spacer,
+  Table,
+  TableBody,
+  TableCell,
+  TableColumn,
+  TableHeader,
+  TableRow,
+} from "@nextui-org/react";
+import { MdFolderOpen, MdSearch } from "react-icons/md";
+import ViewContainer from "../components/ViewContainer";
+
+function Fleet() {
+  const [summary, setSummary] = useState<FleetSummary>();
+  const [query, setQuery] = useState("");
+  const [hosts, setHosts] = useState<FleetHost[]>([]);
+  const [error, setError] = useState<string>();
+
+  function search(query: string) {
+    commands
+      .queryFleet(query)
+      .then((hosts) => {
+        setHosts(hosts);
+        setError(undefined);
+      })
+      .catch((error) => {
+        console.error(error);
+        setError(error.message ?? String(error));
+      });
+  }
+
+  function open() {
+    commands
+      .loadFleet(null)
+      .then((summary) => {
+        if (summary) {
+          console.info(`Loaded ${summary.hosts} hosts from ${summary.dir}`);
+          setSummary(summary);
+          search(query);
+        }
+      })
+      .catch((error) => {
+        console.error(error);
+        setError(error.message ?? String(error));
+      });
+  }
+
+  return (
+    <ViewContainer title="Fleet">
+      <div className="flex items-center space-x-2">
+        <Button variant="flat" startContent={<MdFolderOpen />} onPress={open}>
+          Open Reports
+        </Button>
+
+        <Input
+          isClearable
+          isDisabled={!summary}
+          placeholder="vulkan.devices[].vendorId = 0x10de and vulkan.devices[].driverVersion < 550"
+          startContent={<MdSearch />}
+          value={query}
+          onValueChange={setQuery}
+          onClear={() => {
+            setQuery("");
+            search("");
+          }}
+          onKeyDown={(event) => {
+            if (event.key === "Enter") {
+              search(query);
+            }
+          }}
+        />
+      </div>
+
+      <Spacer y={2} />
+
+      {summary && (
+        <p className="text-sm">
+          {summary.hosts} hosts loaded from {summary.dir}
+          {summary.skipped.length > 0 &&
+            `, ${summary.skipped.length} reports skipped`}
+        </p>
+      )}
+      {error && <p className="text-sm text-danger">{error}</p>}
+
+      <Spacer y={4} />
+
+      <Card shadow="sm">
+        <CardBody>
+          <Table isStriped shadow="none" aria-label="Matching hosts">
+            <TableHeader>
+              <TableColumn>Hostname</TableColumn>
+              <TableColumn>Platform</TableColumn>
+              <TableColumn>Kernel</TableColumn>
+              <TableColumn>Generated At</TableColumn>
+              <TableColumn>Report</TableColumn>
+            </TableHeader>
+
+            <TableBody
+              emptyContent={
+                summary ? "No host matches." : "No reports have been opened."
+              }
+            >
+              {hosts.map((host) => (
+                <TableRow key={host.hostname}>
+                  <TableCell className="font-bold">{host.hostname}</TableCell>
+                  <TableCell>{host.platform ?? "Unknown"}</TableCell>
+                  <TableCell className="font-mono">
+                    {host.kernel ?? "Unknown"}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {new Date(host.generatedAt).toLocaleString()}
+                  </TableCell>
+                  <TableCell className="font-mono text-xs">
+                    {host.path}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </CardBody>
+      </Card>
+    </ViewContainer>
+  );
+}
+
+export default Fleet;
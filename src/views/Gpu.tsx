@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { commands, OsType } from "../bindings";
+import { commands, OsType } from "../api";
 import * as utils from "../utils";
 import { Card, CardBody, Image, Spacer, Tab, Tabs } from "@nextui-org/react";
 import ViewContainer from "../components/ViewContainer";
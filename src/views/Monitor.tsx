@@ -0,0 +1,203 @@
+import { useEffect, useState } from "react";
+import { commands, events, MetricsSample } from "../api";
+import {
+  Card,
+  CardBody,
+  CardHeader,
+  Progress,
+  Spacer,
+  Spinner,
+  Table,
+  TableBody,
+  TableCell,
+  TableColumn,
+  TableHeader,
+  TableRow,
+} from "@nextui-org/react";
+import ViewContainer from "../components/ViewContainer";
+import * as utils from "../utils";
+
+function Monitor() {
+  const [sample, setSample] = useState<MetricsSample>();
+  const [error, setError] = useState<string>();
+
+  useEffect(() => {
+    const unlisten = events.metricsSampled.listen((event) => {
+      setSample(event.payload);
+    });
+
+    commands.startMonitoring(null).catch((error) => {
+      console.error(error);
+      setError(error.message ?? String(error));
+    });
+
+    return () => {
+      commands.stopMonitoring().catch((error) => {
+        console.error(error);
+      });
+      unlisten.then((unlisten) => unlisten());
+    };
+  }, []);
+
+  if (error) {
+    return (
+      <ViewContainer title="Monitor">
+        <p className="text-sm text-danger">{error}</p>
+      </ViewContainer>
+    );
+  }
+
+  if (!sample) {
+    return <Spinner label="Sampling..." color="primary" />;
+  }
+
+  const { memory } = sample;
+
+  return (
+    <ViewContainer title="Monitor" collectedAt={sample.sampledAt}>
+      <Card shadow="sm">
+        <CardHeader className="font-bold">CPU</CardHeader>
+        <CardBody className="space-y-2">
+          {sample.cores.map((core) => (
+            <Progress
+              key={core.name}
+              size="sm"
+              label={`${core.name} (${(core.frequency / 1e9).toFixed(2)} GHz)`}
+              value={core.usage}
+              showValueLabel
+            />
+          ))}
+        </CardBody>
+      </Card>
+
+      <Spacer y={4} />
+
+      <Card shadow="sm">
+        <CardHeader className="font-bold">Memory</CardHeader>
+        <CardBody className="space-y-2">
+          <Progress
+            size="sm"
+            label={`Memory (${utils.formatBytes(memory.usedMemory)} / ${utils.formatBytes(memory.totalMemory)})`}
+            value={memory.usedMemory}
+            maxValue={memory.totalMemory || 1}
+            showValueLabel
+          />
+          <Progress
+            size="sm"
+            label={`Swap (${utils.formatBytes(memory.usedSwap)} / ${utils.formatBytes(memory.totalSwap)})`}
+            value={memory.usedSwap}
+            maxValue={memory.totalSwap || 1}
+            showValueLabel
+          />
+        </CardBody>
+      </Card>
+
+      <Spacer y={4} />
+
+      <Card shadow="sm">
+        <CardBody>
+          <Table isStriped shadow="none" aria-label="Network traffic">
+            <TableHeader>
+              <TableColumn>Interface</TableColumn>
+              <TableColumn>Receiving</TableColumn>
+              <TableColumn>Transmitting</TableColumn>
+              <TableColumn>Received</TableColumn>
+              <TableColumn>Transmitted</TableColumn>
+            </TableHeader>
+
+            <TableBody emptyContent="No network interface was found.">
+              {sample.interfaces.map((itf) => (
+                <TableRow key={itf.itfName}>
+                  <TableCell className="font-bold">{itf.itfName}</TableCell>
+                  <TableCell className="font-mono">
+                    {utils.formatBytesPerSecond(itf.receivedRate)}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {utils.formatBytesPerSecond(itf.transmittedRate)}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {utils.formatBytes(itf.receivedBytes)}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {utils.formatBytes(itf.transmittedBytes)}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </CardBody>
+      </Card>
+
+      <Spacer y={4} />
+
+      <Card shadow="sm">
+        <CardBody>
+          <Table isStriped shadow="none" aria-label="Disk space and I/O">
+            <TableHeader>
+              <TableColumn>Disk</TableColumn>
+              <TableColumn>Mount Point</TableColumn>
+              <TableColumn>Available</TableColumn>
+              <TableColumn>Reading</TableColumn>
+              <TableColumn>Writing</TableColumn>
+            </TableHeader>
+
+            <TableBody emptyContent="No disk was found.">
+              {sample.disks.map((disk) => (
+                <TableRow key={`${disk.name}:${disk.mountPoint}`}>
+                  <TableCell className="font-bold">{disk.name}</TableCell>
+                  <TableCell className="font-mono">{disk.mountPoint}</TableCell>
+                  <TableCell className="font-mono">
+                    {utils.formatBytes(disk.availableSpace)} /{" "}
+                    {utils.formatBytes(disk.totalSpace)}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {disk.readRate === null
+                      ? "Unknown"
+                      : utils.formatBytesPerSecond(disk.readRate)}
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {disk.writeRate === null
+                      ? "Unknown"
+                      : utils.formatBytesPerSecond(disk.writeRate)}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </CardBody>
+      </Card>
+
+      <Spacer y={4} />
+
+      <Card shadow="sm">
+        <CardBody>
+          <Table isStriped shadow="none" aria-label="Temperatures">
+            <TableHeader>
+              <TableColumn>Sensor</TableColumn>
+              <TableColumn>Temperature</TableColumn>
+              <TableColumn>Critical</TableColumn>
+            </TableHeader>
+
+            <TableBody emptyContent="No temperature sensor can be read.">
+              {sample.temperatures.map((sensor) => (
+                <TableRow key={sensor.label}>
+                  <TableCell className="font-bold">{sensor.label}</TableCell>
+                  <TableCell className="font-mono">
+                    {sensor.temperature.toFixed(1)} °C
+                  </TableCell>
+                  <TableCell className="font-mono">
+                    {sensor.critical === null
+                      ? "Unknown"
+                      : `${sensor.critical.toFixed(1)} °C`}
+                  </TableCell>
+                </TableRow>
+              ))}
+            </TableBody>
+          </Table>
+        </CardBody>
+      </Card>
+    </ViewContainer>
+  );
+}
+
+export default Monitor;
@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { commands, NetworksInfo } from "../bindings";
+import { commands, NetworksInfo, Snapshot } from "../api";
 import {
   Card,
   CardBody,
@@ -14,15 +14,31 @@ import {
   TableRow,
 } from "@nextui-org/react";
 import ViewContainer from "../components/ViewContainer";
+import * as utils from "../utils";
 
 function Network() {
   let [networksInfo, setNetworksInfo] = useState<NetworksInfo>();
+  let [collectedAt, setCollectedAt] = useState<number>();
+
+  function handleSnapshot(snapshot: Snapshot<NetworksInfo>) {
+    setNetworksInfo(snapshot.data);
+    setCollectedAt(snapshot.collectedAt);
+  }
+
+  function refresh() {
+    commands
+      .refreshNetworksInfo()
+      .then(handleSnapshot)
+      .catch((error) => {
+        console.error(error);
+      });
+  }
 
   useEffect(() => {
     commands
       .getNetworksInfo()
       .then((info) => {
-        setNetworksInfo(info);
+        handleSnapshot(info);
         console.info("Networks information retrieved successfully.");
       })
       .catch((error) => {
@@ -35,7 +51,11 @@ function Network() {
   }
 
   return (
-    <ViewContainer title="Network Information">
+    <ViewContainer
+      title="Network Information"
+      collectedAt={collectedAt}
+      onRefresh={refresh}
+    >
       <p className="text-sm">
         Total network interfaces on system: {networksInfo.totalInterfaces}
       </p>
@@ -67,6 +87,20 @@ function Network() {
                     </TableCell>
                     <TableCell>{itf.ipNetworks.join(", ")}</TableCell>
                   </TableRow>
+
+                  <TableRow key={3}>
+                    <TableCell className="font-bold w-1/2">Received</TableCell>
+                    <TableCell>{utils.formatBytes(itf.receivedBytes)}</TableCell>
+                  </TableRow>
+
+                  <TableRow key={4}>
+                    <TableCell className="font-bold w-1/2">
+                      Transmitted
+                    </TableCell>
+                    <TableCell>
+                      {utils.formatBytes(itf.transmittedBytes)}
+                    </TableCell>
+                  </TableRow>
                 </TableBody>
               </Table>
             </CardBody>
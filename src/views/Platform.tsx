@@ -5,7 +5,7 @@ import {
   OsType,
   MacOSInfo,
   LinuxInfo,
-} from "../bindings";
+} from "../api";
 import {
   Card,
   CardBody,
@@ -23,6 +23,7 @@ import {
   TableRow,
 } from "@nextui-org/react";
 import ViewContainer from "../components/ViewContainer";
+import FieldValue from "../components/FieldValue";
 import * as utils from "../utils";
 import { useTheme } from "next-themes";
 import { open } from "@tauri-apps/plugin-shell";
@@ -54,7 +55,7 @@ function Platform() {
     commands
       .getPlatformInfo()
       .then((info) => {
-        setPlatformInfo(info);
+        setPlatformInfo(info.data);
         console.info("Platform information retrieved successfully.");
       })
       .catch((error) => {
@@ -96,7 +97,7 @@ function Platform() {
                 <div className="flex items-center justify-center w-full">
                   <Image
                     src={utils.getMacOSLogo(
-                      platformInfo.macOSVersion,
+                      platformInfo.macOSVersion ?? "",
                       currentTheme,
                     )}
                     width={128}
@@ -212,7 +213,9 @@ function Platform() {
                       macOS Version
                     </TableCell>
                     <TableCell className="font-mono">
-                      {platformInfo.macOSVersion}
+                      <FieldValue field="macOSVersion" warnings={platformInfo.warnings}>
+                        {platformInfo.macOSVersion}
+                      </FieldValue>
                     </TableCell>
                   </TableRow>
 
@@ -229,7 +232,7 @@ function Platform() {
                       <div className="flex items-center justify-start">
                         <Image
                           src={utils.getUnixShellLogo(
-                            platformInfo.shell,
+                            platformInfo.shell ?? "",
                             currentTheme,
                           )}
                           width={16}
@@ -239,7 +242,12 @@ function Platform() {
 
                         <Spacer x={2} />
 
-                        {platformInfo.shell}
+                        <FieldValue
+                          field="shell"
+                          warnings={platformInfo.warnings}
+                        >
+                          {platformInfo.shell}
+                        </FieldValue>
                       </div>
                     </TableCell>
                   </TableRow>
@@ -420,7 +428,9 @@ function Platform() {
 
                         <Spacer x={2} />
 
-                        {platformInfo.graphicsPlatform}
+                        <FieldValue field="graphicsPlatform" warnings={platformInfo.warnings}>
+                          {platformInfo.graphicsPlatform}
+                        </FieldValue>
                       </div>
                     </TableCell>
                   </TableRow>
@@ -431,7 +441,7 @@ function Platform() {
                       <div className="flex items-center justify-start">
                         <Image
                           src={utils.getLinuxDesktopLogo(
-                            platformInfo.desktop,
+                            platformInfo.desktop ?? "",
                             currentTheme,
                           )}
                           width={16}
@@ -441,7 +451,9 @@ function Platform() {
 
                         <Spacer x={2} />
 
-                        {platformInfo.desktop}
+                        <FieldValue field="desktop" warnings={platformInfo.warnings}>
+                          {platformInfo.desktop}
+                        </FieldValue>
                       </div>
                     </TableCell>
                   </TableRow>
@@ -452,7 +464,7 @@ function Platform() {
                       <div className="flex items-center justify-start">
                         <Image
                           src={utils.getUnixShellLogo(
-                            platformInfo.shell,
+                            platformInfo.shell ?? "",
                             currentTheme,
                           )}
                           width={16}
@@ -462,7 +474,12 @@ function Platform() {
 
                         <Spacer x={2} />
 
-                        {platformInfo.shell}
+                        <FieldValue
+                          field="shell"
+                          warnings={platformInfo.warnings}
+                        >
+                          {platformInfo.shell}
+                        </FieldValue>
                       </div>
                     </TableCell>
                   </TableRow>
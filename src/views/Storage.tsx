@@ -1,5 +1,5 @@
 import { useEffect, useState } from "react";
-import { commands, DisksInfo } from "../bindings";
+import { commands, DisksInfo, Snapshot } from "../api";
 import {
   Card,
   CardBody,
@@ -18,12 +18,27 @@ import * as utils from "../utils";
 
 function Storage() {
   let [storageInfo, setStorageInfo] = useState<DisksInfo>();
+  let [collectedAt, setCollectedAt] = useState<number>();
+
+  function handleSnapshot(snapshot: Snapshot<DisksInfo>) {
+    setStorageInfo(snapshot.data);
+    setCollectedAt(snapshot.collectedAt);
+  }
+
+  function refresh() {
+    commands
+      .refreshDisksInfo()
+      .then(handleSnapshot)
+      .catch((error) => {
+        console.error(error);
+      });
+  }
 
   useEffect(() => {
     commands
       .getDisksInfo()
       .then((info) => {
-        setStorageInfo(info);
+        handleSnapshot(info);
         console.info("Storage information retrieved successfully.");
       })
       .catch((error) => {
@@ -36,7 +51,11 @@ function Storage() {
   }
 
   return (
-    <ViewContainer title="Storage Information">
+    <ViewContainer
+      title="Storage Information"
+      collectedAt={collectedAt}
+      onRefresh={refresh}
+    >
       <p className="text-sm">Total disks on system: {storageInfo.count}</p>
 
       <Spacer y={4} />
@@ -85,7 +104,7 @@ function Storage() {
 
                   <TableRow key={5}>
                     <TableCell className="font-bold w-1/2">Disk Kind</TableCell>
-                    <TableCell>{disk.kind}</TableCell>
+                    <TableCell>{utils.formatDiskKind(disk.kind)}</TableCell>
                   </TableRow>
                 </TableBody>
               </Table>
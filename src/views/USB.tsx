@@ -1,4 +1,4 @@
-import { commands, USBInfo } from "../bindings";
+import { commands, USBInfo, Snapshot } from "../api";
 import { useEffect, useState } from "react";
 import {
   Accordion,
@@ -15,16 +15,32 @@ import {
   TableRow,
 } from "@nextui-org/react";
 import ViewContainer from "../components/ViewContainer.tsx";
+import * as utils from "../utils";
 
 function USB() {
   const [usbInfo, setUSBInfo] = useState<USBInfo>();
+  const [collectedAt, setCollectedAt] = useState<number>();
+
+  function handleSnapshot(snapshot: Snapshot<USBInfo>) {
+    setUSBInfo(snapshot.data);
+    setCollectedAt(snapshot.collectedAt);
+  }
+
+  function refresh() {
+    commands
+      .refreshUSBInfo()
+      .then(handleSnapshot)
+      .catch((error) => {
+        console.error(error);
+      });
+  }
 
   useEffect(() => {
     commands
       .getUSBInfo()
       .then((info) => {
         console.debug(info);
-        setUSBInfo(info);
+        handleSnapshot(info);
       })
       .catch((error) => {
         console.error(error);
@@ -36,7 +52,11 @@ function USB() {
   }
 
   return (
-    <ViewContainer title="USB Information">
+    <ViewContainer
+      title="USB Information"
+      collectedAt={collectedAt}
+      onRefresh={refresh}
+    >
       <Card shadow="sm">
         <CardBody>
           <Accordion isCompact>
@@ -126,7 +146,7 @@ function USB() {
                     <TableRow>
                       <TableCell className="font-bold w-[35%]">Speed</TableCell>
                       <TableCell className="font-mono">
-                        {device.speed || "Unknown"}
+                        {utils.formatUSBSpeed(device.speed)}
                       </TableCell>
                     </TableRow>
 